@@ -4,6 +4,7 @@ extern crate num_traits;
 
 use num_traits::Float;
 
+use ndarray::convolution::Mode;
 use ndarray::prelude::*;
 
 const SOBEL_X: [[f32; 3]; 3] = [[-1., 0., 1.], [-2., 0., 2.], [-1., 0., 1.]];
@@ -60,4 +61,9 @@ fn main() {
         conv_3x3(&a.view(), &mut res.view_mut(), &SOBEL_X);
     }
     println!("{:2}", res);
+
+    // the same, with the library's correlation (the kernel is not flipped)
+    let valid = a.correlate(&aview2(&SOBEL_X), Mode::Valid);
+    assert_eq!(res.slice(s![1..-1, 1..-1]), valid);
+    println!("{:2}", a.correlate(&aview2(&SOBEL_X), Mode::Same));
 }
@@ -0,0 +1,373 @@
+// Copyright 2019 bluss and ndarray developers.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Convolution and correlation.
+//!
+//! The methods are
+//! [`.convolve()`](../struct.ArrayBase.html#method.convolve),
+//! [`.correlate()`](../struct.ArrayBase.html#method.correlate), their
+//! `_axis` variants that apply a one-dimensional kernel along one axis, and
+//! [`.convolve_separable()`](../struct.ArrayBase.html#method.convolve_separable).
+//! The size of the result is selected by [`Mode`](enum.Mode.html).
+
+use crate::imp_prelude::*;
+use crate::linalg::general_mat_mul;
+use crate::{LinalgScalar, Slice, Zip};
+
+/// Kernels with at least this many elements are applied with im2col and a
+/// matrix product.
+const IM2COL_MIN_KERNEL: usize = 64;
+
+/// The number of windows copied into the im2col matrix at a time.
+const IM2COL_BLOCK: usize = 256;
+
+/// The size of the result of a convolution or correlation.
+///
+/// For an axis of length `n` and a kernel of length `k`:
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Mode {
+    /// The full result, of length `n + k - 1`: every position where the
+    /// kernel overlaps the input, which is padded with zeros.
+    Full,
+    /// The central part of the full result, of the input length `n`.
+    Same,
+    /// Only the positions where the kernel lies within the input, of length
+    /// `n - k + 1` (zero if the kernel is longer than the input).
+    Valid,
+}
+
+impl Mode {
+    /// Return the zero padding before and after an axis of the input for a
+    /// kernel of length `k`.
+    fn padding(self, k: usize) -> (usize, usize) {
+        match self {
+            Mode::Full => (k - 1, k - 1),
+            Mode::Same => (k / 2, (k - 1) / 2),
+            Mode::Valid => (0, 0),
+        }
+    }
+}
+
+/// # Convolution
+///
+/// The kernel has the same dimensionality as the array. Correlation slides
+/// the kernel over the array and sums the products of the overlapping
+/// elements (without conjugating complex kernels); convolution does the same
+/// with the kernel flipped along every axis.
+///
+/// Kernels with many elements are applied with im2col: the windows of the
+/// input are copied into the rows of a matrix, which is multiplied by the
+/// kernel with [`general_mat_mul`](linalg/fn.general_mat_mul.html) (which
+/// uses BLAS if it is enabled).
+impl<A, S, D> ArrayBase<S, D>
+where
+    S: Data<Elem = A>,
+    D: Dimension,
+    A: LinalgScalar,
+{
+    /// Return the convolution of the array with `kernel`.
+    ///
+    /// **Panics** if the kernel has a different number of axes than the
+    /// array, or an axis of length zero.
+    ///
+    /// ```
+    /// use ndarray::arr1;
+    /// use ndarray::convolution::Mode;
+    ///
+    /// let a = arr1(&[1, 2, 3]);
+    /// let k = arr1(&[0, 1, 10]);
+    /// assert_eq!(a.convolve(&k, Mode::Full), arr1(&[0, 1, 12, 23, 30]));
+    /// assert_eq!(a.convolve(&k, Mode::Same), arr1(&[1, 12, 23]));
+    /// assert_eq!(a.convolve(&k, Mode::Valid), arr1(&[12]));
+    /// ```
+    pub fn convolve<S2>(&self, kernel: &ArrayBase<S2, D>, mode: Mode) -> Array<A, D>
+    where
+        S2: Data<Elem = A>,
+    {
+        let (padded, kernel, mut out) = prepare(&self.view(), &flipped(kernel), mode);
+        correlate_valid_into(&padded.view(), &kernel, out.view_mut());
+        out
+    }
+
+    /// Return the correlation of the array with `kernel`.
+    ///
+    /// **Panics** if the kernel has a different number of axes than the
+    /// array, or an axis of length zero.
+    ///
+    /// ```
+    /// use ndarray::arr2;
+    /// use ndarray::convolution::Mode;
+    ///
+    /// let a = arr2(&[[1, 2, 0],
+    ///                [0, 1, 3]]);
+    /// let k = arr2(&[[1, -1]]);
+    /// assert_eq!(a.correlate(&k, Mode::Valid), arr2(&[[-1, 2],
+    ///                                                 [-1, -2]]));
+    /// ```
+    pub fn correlate<S2>(&self, kernel: &ArrayBase<S2, D>, mode: Mode) -> Array<A, D>
+    where
+        S2: Data<Elem = A>,
+    {
+        let (padded, kernel, mut out) = prepare(&self.view(), &kernel.view(), mode);
+        correlate_valid_into(&padded.view(), &kernel, out.view_mut());
+        out
+    }
+
+    /// Return the convolution of each lane along `axis` with the
+    /// one-dimensional `kernel`.
+    ///
+    /// **Panics** if `axis` is out of bounds or the kernel is empty.
+    ///
+    /// ```
+    /// use ndarray::{arr1, arr2, Axis};
+    /// use ndarray::convolution::Mode;
+    ///
+    /// let a = arr2(&[[1., 2., 3.],
+    ///                [4., 5., 6.]]);
+    /// let k = arr1(&[0.5, 0.5]);
+    /// assert_eq!(a.convolve_axis(Axis(1), &k, Mode::Valid),
+    ///            arr2(&[[1.5, 2.5],
+    ///                   [4.5, 5.5]]));
+    /// ```
+    pub fn convolve_axis<S2>(
+        &self,
+        axis: Axis,
+        kernel: &ArrayBase<S2, Ix1>,
+        mode: Mode,
+    ) -> Array<A, D>
+    where
+        S2: Data<Elem = A>,
+    {
+        self.convolve(&kernel_along(self.raw_dim(), axis, kernel), mode)
+    }
+
+    /// Return the correlation of each lane along `axis` with the
+    /// one-dimensional `kernel`.
+    ///
+    /// **Panics** if `axis` is out of bounds or the kernel is empty.
+    pub fn correlate_axis<S2>(
+        &self,
+        axis: Axis,
+        kernel: &ArrayBase<S2, Ix1>,
+        mode: Mode,
+    ) -> Array<A, D>
+    where
+        S2: Data<Elem = A>,
+    {
+        self.correlate(&kernel_along(self.raw_dim(), axis, kernel), mode)
+    }
+
+    /// Return the convolution of the array with the separable kernel that is
+    /// the outer product of `kernels`, one for each axis.
+    ///
+    /// This is the same as the convolution with the full kernel, but only
+    /// applies one-dimensional kernels, which is much faster for large
+    /// kernels such as Gaussian blurs.
+    ///
+    /// **Panics** if the number of kernels is not the number of axes, or if
+    /// a kernel is empty.
+    ///
+    /// ```
+    /// use ndarray::{arr1, Array2};
+    /// use ndarray::convolution::Mode;
+    /// use ndarray::linalg::outer;
+    ///
+    /// let a = Array2::from_shape_fn((5, 6), |(i, j)| (i * j) as f64);
+    /// let k = arr1(&[1., 2., 1.]);
+    /// let full_kernel = outer(&k, &k);
+    /// assert_eq!(a.convolve_separable(&[k.view(), k.view()], Mode::Same),
+    ///            a.convolve(&full_kernel, Mode::Same));
+    /// ```
+    pub fn convolve_separable(&self, kernels: &[ArrayView1<'_, A>], mode: Mode) -> Array<A, D> {
+        assert_eq!(
+            kernels.len(),
+            self.ndim(),
+            "ndarray: convolve_separable needs one kernel per axis"
+        );
+        let mut result = self.to_owned();
+        for (axis, kernel) in kernels.iter().enumerate() {
+            result = result.convolve_axis(Axis(axis), kernel, mode);
+        }
+        result
+    }
+}
+
+/// Return a view of `kernel` flipped along every axis.
+pub(crate) fn flipped<A, S, D>(kernel: &ArrayBase<S, D>) -> ArrayView<'_, A, D>
+where
+    S: Data<Elem = A>,
+    D: Dimension,
+{
+    let mut kernel = kernel.view();
+    for axis in 0..kernel.ndim() {
+        kernel.invert_axis(Axis(axis));
+    }
+    kernel
+}
+
+/// Return the one-dimensional `kernel` as an array with the dimensionality
+/// of `dim`, of length one along every axis except `axis`.
+fn kernel_along<A, S, D>(mut dim: D, axis: Axis, kernel: &ArrayBase<S, Ix1>) -> Array<A, D>
+where
+    S: Data<Elem = A>,
+    D: Dimension,
+    A: Clone,
+{
+    assert!(axis.index() < dim.ndim(), "ndarray: axis out of bounds");
+    for d in dim.slice_mut() {
+        *d = 1;
+    }
+    dim[axis.index()] = kernel.len();
+    Array::from_shape_vec(dim, kernel.to_vec()).unwrap()
+}
+
+/// Return the zero padded input, the kernel and the zeroed output for a
+/// correlation of `a` with `kernel` in `mode`, which is then the valid
+/// correlation of the padded input with the kernel.
+fn prepare<'k, A, D>(
+    a: &ArrayView<'_, A, D>,
+    kernel: &ArrayView<'k, A, D>,
+    mode: Mode,
+) -> (Array<A, D>, ArrayView<'k, A, D>, Array<A, D>)
+where
+    D: Dimension,
+    A: LinalgScalar,
+{
+    assert_eq!(
+        a.ndim(),
+        kernel.ndim(),
+        "ndarray: the kernel must have as many axes as the array"
+    );
+    assert!(
+        kernel.shape().iter().all(|&k| k > 0),
+        "ndarray: the kernel must not be empty"
+    );
+    let mut padded_dim = a.raw_dim();
+    let mut out_dim = a.raw_dim();
+    let mut interior = Vec::with_capacity(a.ndim());
+    for (axis, &k) in kernel.shape().iter().enumerate() {
+        let n = a.shape()[axis];
+        let (before, after) = mode.padding(k);
+        let len = n + before + after;
+        padded_dim[axis] = len;
+        out_dim[axis] = if len < k { 0 } else { len - k + 1 };
+        interior.push(Slice::from(before..before + n));
+    }
+    let padded = if padded_dim == a.raw_dim() {
+        a.to_owned()
+    } else {
+        let mut padded = Array::zeros(padded_dim);
+        let mut view = padded.view_mut();
+        for (axis, &slice) in interior.iter().enumerate() {
+            view.slice_axis_inplace(Axis(axis), slice);
+        }
+        view.assign(a);
+        padded
+    };
+    (padded, kernel.clone(), Array::zeros(out_dim))
+}
+
+/// Return the sum of the products of the elements of `window` and `kernel`.
+fn window_dot<A, D>(window: &ArrayView<'_, A, D>, kernel: &ArrayView<'_, A, D>) -> A
+where
+    D: Dimension,
+    A: LinalgScalar,
+{
+    window
+        .iter()
+        .zip(kernel.iter())
+        .fold(A::zero(), |acc, (&x, &k)| acc + x * k)
+}
+
+/// Return the correlation of `a` with `kernel` in `mode`, computing the
+/// output elements in parallel.
+#[cfg(feature = "rayon")]
+pub(crate) fn par_correlate<A, D>(
+    a: &ArrayView<'_, A, D>,
+    kernel: &ArrayView<'_, A, D>,
+    mode: Mode,
+) -> Array<A, D>
+where
+    D: Dimension,
+    A: LinalgScalar + Send + Sync,
+{
+    let (padded, kernel, mut out) = prepare(a, kernel, mode);
+    if !out.is_empty() {
+        Zip::from(&mut out)
+            .and(padded.windows(kernel.raw_dim()))
+            .par_apply(|out, window| *out = window_dot(&window, &kernel));
+    }
+    out
+}
+
+/// out ← the valid correlation of `padded` with `kernel`.
+fn correlate_valid_into<A, D>(
+    padded: &ArrayView<'_, A, D>,
+    kernel: &ArrayView<'_, A, D>,
+    out: ArrayViewMut<'_, A, D>,
+) where
+    D: Dimension,
+    A: LinalgScalar,
+{
+    if out.is_empty() {
+        return;
+    }
+    if kernel.len() < IM2COL_MIN_KERNEL {
+        Zip::from(out)
+            .and(padded.windows(kernel.raw_dim()))
+            .apply(|out, window| *out = window_dot(&window, kernel));
+    } else {
+        correlate_im2col(padded, kernel, out);
+    }
+}
+
+/// out ← the valid correlation of `padded` with `kernel`, computed by
+/// copying blocks of windows into the rows of a matrix and multiplying it by
+/// the kernel.
+fn correlate_im2col<A, D>(
+    padded: &ArrayView<'_, A, D>,
+    kernel: &ArrayView<'_, A, D>,
+    mut out: ArrayViewMut<'_, A, D>,
+) where
+    D: Dimension,
+    A: LinalgScalar,
+{
+    let k = kernel.len();
+    let window_dim = kernel.raw_dim();
+    let kernel = kernel
+        .iter()
+        .cloned()
+        .collect::<Array1<A>>()
+        .into_shape((k, 1))
+        .unwrap();
+    let mut cols = Array2::zeros((IM2COL_BLOCK, k));
+    let mut products = Array2::zeros((IM2COL_BLOCK, 1));
+    let mut windows = padded.windows(window_dim).into_iter();
+    let mut out = out.iter_mut();
+    loop {
+        let mut rows = 0;
+        for (mut row, window) in cols.outer_iter_mut().zip(&mut windows) {
+            row.iter_mut().zip(window.iter()).for_each(|(r, &x)| *r = x);
+            rows += 1;
+        }
+        if rows == 0 {
+            break;
+        }
+        let mut block = products.slice_mut(s![..rows, ..]);
+        general_mat_mul(
+            A::one(),
+            &cols.slice(s![..rows, ..]),
+            &kernel,
+            A::zero(),
+            &mut block,
+        );
+        for (&x, out) in block.iter().zip(&mut out) {
+            *out = x;
+        }
+    }
+}
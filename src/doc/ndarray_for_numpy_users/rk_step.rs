@@ -172,9 +172,12 @@
 //! # fn main() {}
 //! ```
 //!
+//! For complete solvers with step size control, see the [`ode`] module.
+//!
 //! [f64.mul_add()]: https://doc.rust-lang.org/std/primitive.f64.html#method.mul_add
 //! [.scaled_add()]: ../../../struct.ArrayBase.html#method.scaled_add
 //! [azip!]: ../../../macro.azip.html
+//! [`ode`]: ../../../ode/index.html
 //!
 //! ### SciPy license
 //!
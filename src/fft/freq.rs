@@ -0,0 +1,137 @@
+// Copyright 2019 bluss and ndarray developers.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use crate::imp_prelude::*;
+use crate::{NdFloat, Slice};
+
+/// Return the frequencies of the transform of length `n` of samples spaced
+/// `d` apart, in the order of the transform.
+///
+/// The frequencies are `[0, 1, ..., (n-1)/2, -(n/2), ..., -1] / (d n)`.
+///
+/// ```
+/// use ndarray::arr1;
+/// use ndarray::fft::fftfreq;
+///
+/// assert_eq!(fftfreq(5, 0.1), arr1(&[0., 2., 4., -4., -2.]));
+/// assert_eq!(fftfreq(4, 1.), arr1(&[0., 0.25, -0.5, -0.25]));
+/// ```
+#[allow(clippy::manual_div_ceil)]
+pub fn fftfreq<A: NdFloat>(n: usize, d: A) -> Array1<A> {
+    let scale = A::one() / (d * A::from(n).unwrap());
+    let positive = (n + 1) / 2;
+    Array1::from_shape_fn(n, |k| {
+        let k = if k < positive {
+            A::from(k).unwrap()
+        } else {
+            -A::from(n - k).unwrap()
+        };
+        k * scale
+    })
+}
+
+/// Return the non-negative frequencies of the transform of length `n` of
+/// samples spaced `d` apart, as computed by [`rfft`](fn.rfft.html).
+///
+/// The frequencies are `[0, 1, ..., n/2] / (d n)`.
+///
+/// ```
+/// use ndarray::arr1;
+/// use ndarray::fft::rfftfreq;
+///
+/// assert_eq!(rfftfreq(5, 0.1), arr1(&[0., 2., 4.]));
+/// assert_eq!(rfftfreq(4, 1.), arr1(&[0., 0.25, 0.5]));
+/// ```
+pub fn rfftfreq<A: NdFloat>(n: usize, d: A) -> Array1<A> {
+    let scale = A::one() / (d * A::from(n).unwrap());
+    Array1::from_shape_fn(n / 2 + 1, |k| A::from(k).unwrap() * scale)
+}
+
+/// Return `a` rotated along `axis` so that the element at `start` comes
+/// first.
+fn rotate_axis<A, S, D>(a: &ArrayBase<S, D>, axis: Axis, start: usize) -> Array<A, D>
+where
+    A: Clone,
+    S: Data<Elem = A>,
+    D: Dimension,
+{
+    let n = a.len_of(axis);
+    let mut out = a.to_owned();
+    out.slice_axis_mut(axis, Slice::from(..n - start))
+        .assign(&a.slice_axis(axis, Slice::from(start..)));
+    out.slice_axis_mut(axis, Slice::from(n - start..))
+        .assign(&a.slice_axis(axis, Slice::from(..start)));
+    out
+}
+
+/// Return `a` with the zero frequency moved to the center of every axis.
+///
+/// This rearranges the result of a transform, or the result of
+/// [`fftfreq`](fn.fftfreq.html), in the order of increasing frequency.
+///
+/// ```
+/// use ndarray::arr1;
+/// use ndarray::fft::{fftfreq, fftshift, ifftshift};
+///
+/// let f = fftfreq(5, 1. / 5.);
+/// assert_eq!(fftshift(&f), arr1(&[-2., -1., 0., 1., 2.]));
+/// assert_eq!(ifftshift(&fftshift(&f)), f);
+/// ```
+pub fn fftshift<A, S, D>(a: &ArrayBase<S, D>) -> Array<A, D>
+where
+    A: Clone,
+    S: Data<Elem = A>,
+    D: Dimension,
+{
+    let mut out = a.to_owned();
+    for axis in 0..a.ndim() {
+        out = fftshift_axis(&out, Axis(axis));
+    }
+    out
+}
+
+/// Return `a` with the zero frequency moved to the center of `axis`.
+///
+/// **Panics** if `axis` is out of bounds.
+#[allow(clippy::manual_div_ceil)]
+pub fn fftshift_axis<A, S, D>(a: &ArrayBase<S, D>, axis: Axis) -> Array<A, D>
+where
+    A: Clone,
+    S: Data<Elem = A>,
+    D: Dimension,
+{
+    rotate_axis(a, axis, (a.len_of(axis) + 1) / 2)
+}
+
+/// Return `a` with the center of every axis moved to the start; the inverse
+/// of [`fftshift`](fn.fftshift.html).
+pub fn ifftshift<A, S, D>(a: &ArrayBase<S, D>) -> Array<A, D>
+where
+    A: Clone,
+    S: Data<Elem = A>,
+    D: Dimension,
+{
+    let mut out = a.to_owned();
+    for axis in 0..a.ndim() {
+        out = ifftshift_axis(&out, Axis(axis));
+    }
+    out
+}
+
+/// Return `a` with the center of `axis` moved to the start; the inverse of
+/// [`fftshift_axis`](fn.fftshift_axis.html).
+///
+/// **Panics** if `axis` is out of bounds.
+pub fn ifftshift_axis<A, S, D>(a: &ArrayBase<S, D>, axis: Axis) -> Array<A, D>
+where
+    A: Clone,
+    S: Data<Elem = A>,
+    D: Dimension,
+{
+    rotate_axis(a, axis, a.len_of(axis) / 2)
+}
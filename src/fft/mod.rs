@@ -0,0 +1,198 @@
+// Copyright 2019 bluss and ndarray developers.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Fast Fourier transforms.
+//!
+//! - [`fft`](fn.fft.html) and [`ifft`](fn.ifft.html) transform complex
+//!   arrays along an axis, and [`fft_inplace`](fn.fft_inplace.html) and
+//!   [`ifft_inplace`](fn.ifft_inplace.html) do so without allocating a new
+//!   array.
+//! - [`rfft`](fn.rfft.html) and [`irfft`](fn.irfft.html) transform real
+//!   arrays to the non-negative frequencies and back.
+//! - [`fftn`](fn.fftn.html) and [`ifftn`](fn.ifftn.html) transform along
+//!   every axis.
+//! - [`fftfreq`](fn.fftfreq.html), [`rfftfreq`](fn.rfftfreq.html),
+//!   [`fftshift`](fn.fftshift.html) and [`ifftshift`](fn.ifftshift.html)
+//!   give and arrange the frequencies of a transform.
+//! - [`hann`](fn.hann.html), [`hamming`](fn.hamming.html) and
+//!   [`blackman`](fn.blackman.html) are window functions.
+//!
+//! The forward transform of `x` of length `n` is
+//! `X[k] = Σ x[j] exp(-2πi jk/n)`, and the inverse transform is normalized
+//! by `1/n`. Transforms of any length take *O(n log n)* time; the
+//! [`FftPlan`](struct.FftPlan.html) for each length and element type is
+//! created on first use and cached per thread. The cache keeps the sixteen
+//! most recently used plans; to transform many different lengths
+//! repeatedly, create and keep the plans with `FftPlan::new` instead.
+//!
+//! ```
+//! use ndarray::prelude::*;
+//! use ndarray::fft::{fftfreq, rfft};
+//!
+//! // a cosine of frequency 2 sampled at 8 points
+//! let n = 8;
+//! let x = Array::from_shape_fn(n, |j| (2. * std::f64::consts::PI * 2. * j as f64 / 8.).cos());
+//! let spectrum = rfft(&x, Axis(0));
+//! assert_eq!(spectrum.len(), n / 2 + 1);
+//! let peak = spectrum.iter().map(|c| c.norm()).enumerate()
+//!     .max_by(|a, b| a.1.partial_cmp(&b.1).unwrap()).unwrap().0;
+//! assert_eq!(peak, 2);
+//! assert_eq!(fftfreq(n, 1. / 8.)[peak], 2.);
+//! ```
+
+pub use self::freq::{fftfreq, fftshift, fftshift_axis, ifftshift, ifftshift_axis, rfftfreq};
+pub use self::plan::FftPlan;
+pub use self::window::{blackman, hamming, hann};
+
+use num_complex::Complex;
+
+use crate::imp_prelude::*;
+use crate::{NdFloat, Slice};
+
+use self::plan::cached_plan;
+
+mod freq;
+mod plan;
+mod window;
+
+/// Return the discrete Fourier transform of every lane of `a` along `axis`.
+///
+/// **Panics** if `axis` is out of bounds.
+pub fn fft<A, S, D>(a: &ArrayBase<S, D>, axis: Axis) -> Array<Complex<A>, D>
+where
+    A: NdFloat,
+    S: Data<Elem = Complex<A>>,
+    D: Dimension,
+{
+    let mut out = a.to_owned();
+    fft_inplace(&mut out, axis);
+    out
+}
+
+/// Return the inverse discrete Fourier transform of every lane of `a` along
+/// `axis`, normalized by `1/n`.
+///
+/// **Panics** if `axis` is out of bounds.
+pub fn ifft<A, S, D>(a: &ArrayBase<S, D>, axis: Axis) -> Array<Complex<A>, D>
+where
+    A: NdFloat,
+    S: Data<Elem = Complex<A>>,
+    D: Dimension,
+{
+    let mut out = a.to_owned();
+    ifft_inplace(&mut out, axis);
+    out
+}
+
+/// Replace every lane of `a` along `axis` by its discrete Fourier transform.
+///
+/// **Panics** if `axis` is out of bounds.
+pub fn fft_inplace<A, S, D>(a: &mut ArrayBase<S, D>, axis: Axis)
+where
+    A: NdFloat,
+    S: DataMut<Elem = Complex<A>>,
+    D: Dimension,
+{
+    cached_plan(a.len_of(axis)).forward(a, axis);
+}
+
+/// Replace every lane of `a` along `axis` by its inverse discrete Fourier
+/// transform, normalized by `1/n`.
+///
+/// **Panics** if `axis` is out of bounds.
+pub fn ifft_inplace<A, S, D>(a: &mut ArrayBase<S, D>, axis: Axis)
+where
+    A: NdFloat,
+    S: DataMut<Elem = Complex<A>>,
+    D: Dimension,
+{
+    cached_plan(a.len_of(axis)).inverse(a, axis);
+}
+
+/// Return the discrete Fourier transform of every axis of `a`.
+pub fn fftn<A, S, D>(a: &ArrayBase<S, D>) -> Array<Complex<A>, D>
+where
+    A: NdFloat,
+    S: Data<Elem = Complex<A>>,
+    D: Dimension,
+{
+    let mut out = a.to_owned();
+    for axis in 0..out.ndim() {
+        fft_inplace(&mut out, Axis(axis));
+    }
+    out
+}
+
+/// Return the inverse discrete Fourier transform of every axis of `a`,
+/// normalized by `1/a.len()`.
+pub fn ifftn<A, S, D>(a: &ArrayBase<S, D>) -> Array<Complex<A>, D>
+where
+    A: NdFloat,
+    S: Data<Elem = Complex<A>>,
+    D: Dimension,
+{
+    let mut out = a.to_owned();
+    for axis in 0..out.ndim() {
+        ifft_inplace(&mut out, Axis(axis));
+    }
+    out
+}
+
+/// Return the discrete Fourier transform of every real lane of `a` along
+/// `axis`, for the non-negative frequencies only.
+///
+/// The transform of a real sequence of length `n` is Hermitian, so the
+/// result has length `n/2 + 1` along `axis` and determines the rest.
+///
+/// **Panics** if `axis` is out of bounds or has length zero.
+pub fn rfft<A, S, D>(a: &ArrayBase<S, D>, axis: Axis) -> Array<Complex<A>, D>
+where
+    A: NdFloat,
+    S: Data<Elem = A>,
+    D: Dimension,
+{
+    let n = a.len_of(axis);
+    let mut full = a.mapv(|x| Complex::new(x, A::zero()));
+    fft_inplace(&mut full, axis);
+    full.slice_axis(axis, Slice::from(..n / 2 + 1)).to_owned()
+}
+
+/// Return the real sequences of length `n` whose transforms along `axis`
+/// are the lanes of `a`; the inverse of [`rfft`](fn.rfft.html).
+///
+/// The imaginary parts of the zero frequency, and of frequency `n/2` if `n`
+/// is even, are ignored.
+///
+/// **Panics** if `axis` is out of bounds or its length is not `n/2 + 1`.
+pub fn irfft<A, S, D>(a: &ArrayBase<S, D>, n: usize, axis: Axis) -> Array<A, D>
+where
+    A: NdFloat,
+    S: Data<Elem = Complex<A>>,
+    D: Dimension,
+{
+    let half = a.len_of(axis);
+    assert_eq!(
+        half,
+        n / 2 + 1,
+        "irfft: axis length must be n/2 + 1 for output length n"
+    );
+    let mut dim = a.raw_dim();
+    dim[axis.index()] = n;
+    let mut full = Array::zeros(dim);
+    for (mut lane, half_lane) in full.lanes_mut(axis).into_iter().zip(a.lanes(axis)) {
+        for k in 0..n {
+            lane[k] = if k < half {
+                half_lane[k]
+            } else {
+                half_lane[n - k].conj()
+            };
+        }
+    }
+    ifft_inplace(&mut full, axis);
+    full.mapv(|c: Complex<A>| c.re)
+}
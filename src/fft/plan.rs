@@ -0,0 +1,384 @@
+// Copyright 2019 bluss and ndarray developers.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use std::any::{Any, TypeId};
+use std::cell::RefCell;
+use std::f64::consts::PI;
+use std::rc::Rc;
+
+use num_complex::Complex;
+use num_traits::Zero;
+
+use crate::imp_prelude::*;
+use crate::NdFloat;
+
+/// Lengths whose prime factors are all at most this are transformed with
+/// the mixed-radix algorithm; other lengths use Bluestein's algorithm.
+const MAX_RADIX: usize = 16;
+
+/// A precomputed fast Fourier transform of one length.
+///
+/// A plan holds the factorization and twiddle factors for its length, and
+/// transforms every lane of an array along an axis. Powers of two use the
+/// radix-2 algorithm, lengths with only small prime factors the mixed-radix
+/// algorithm, and other lengths Bluestein's algorithm, so that every length
+/// takes *O(n log n)* time.
+///
+/// The functions of the [`fft`](index.html) module create plans as needed
+/// and keep them in a per-thread cache; a plan is only needed explicitly to
+/// reuse it across threads or to avoid the cache lookup.
+///
+/// ```
+/// use ndarray::arr1;
+/// use ndarray::fft::FftPlan;
+/// use ndarray::Axis;
+/// use num_complex::Complex;
+///
+/// let plan = FftPlan::<f64>::new(4);
+/// let mut a = arr1(&[1., 2., 3., 4.]).mapv(|x| Complex::new(x, 0.));
+/// plan.forward(&mut a, Axis(0));
+/// assert_eq!(a[0], Complex::new(10., 0.));
+/// assert_eq!(a[1], Complex::new(-2., 2.));
+/// plan.inverse(&mut a, Axis(0));
+/// assert!((a[3] - Complex::new(4., 0.)).norm() < 1e-15);
+/// ```
+#[derive(Clone, Debug)]
+pub struct FftPlan<A> {
+    len: usize,
+    algorithm: Algorithm<A>,
+}
+
+#[derive(Clone, Debug)]
+enum Algorithm<A> {
+    /// Lengths zero and one, for which the transform is the identity.
+    Identity,
+    /// In place, after a bit-reversal permutation; twiddles are
+    /// `exp(-2πik/n)` for `k < n/2`.
+    Radix2 { twiddles: Vec<Complex<A>> },
+    /// Recursive decimation in time, out of place; twiddles are
+    /// `exp(-2πik/n)` for `k < n`.
+    MixedRadix {
+        factors: Vec<usize>,
+        twiddles: Vec<Complex<A>>,
+    },
+    /// The transform as a convolution with a chirp, computed with a
+    /// power-of-two transform of length `inner.len`.
+    Bluestein {
+        chirp: Vec<Complex<A>>,
+        /// The transform of the convolution kernel, divided by `inner.len`.
+        kernel: Vec<Complex<A>>,
+        inner: Box<FftPlan<A>>,
+    },
+}
+
+/// Return `exp(-2πi k/n)`, with the angle computed in double precision.
+fn twiddle<A: NdFloat>(k: usize, n: usize) -> Complex<A> {
+    let angle = -2. * PI * (k as f64) / (n as f64);
+    Complex::new(A::from(angle.cos()).unwrap(), A::from(angle.sin()).unwrap())
+}
+
+/// Return the factors of `n` in the order they are applied, or `None` if a
+/// prime factor is larger than `MAX_RADIX`.
+// `is_multiple_of` is newer than the minimum supported Rust version
+#[allow(clippy::manual_is_multiple_of)]
+fn factorize(mut n: usize) -> Option<Vec<usize>> {
+    let mut factors = Vec::new();
+    while n % 4 == 0 {
+        factors.push(4);
+        n /= 4;
+    }
+    let mut p = 2;
+    while n > 1 {
+        if p > MAX_RADIX {
+            return None;
+        }
+        while n % p == 0 {
+            factors.push(p);
+            n /= p;
+        }
+        p += 1;
+    }
+    Some(factors)
+}
+
+impl<A: NdFloat> FftPlan<A> {
+    /// Create a plan for transforms of length `len`.
+    pub fn new(len: usize) -> Self {
+        let algorithm = if len <= 1 {
+            Algorithm::Identity
+        } else if len.is_power_of_two() {
+            Algorithm::Radix2 {
+                twiddles: (0..len / 2).map(|k| twiddle(k, len)).collect(),
+            }
+        } else if let Some(factors) = factorize(len) {
+            Algorithm::MixedRadix {
+                factors,
+                twiddles: (0..len).map(|k| twiddle(k, len)).collect(),
+            }
+        } else {
+            // w_k = exp(-πi k²/n); k² is reduced modulo 2n to keep the angle
+            // small.
+            let chirp = (0..len)
+                .map(|k| twiddle((k * k) % (2 * len), 2 * len))
+                .collect::<Vec<_>>();
+            let inner = FftPlan::new((2 * len - 1).next_power_of_two());
+            let m = inner.len;
+            let scale = A::one() / A::from(m).unwrap();
+            let mut kernel = vec![Complex::zero(); m];
+            kernel[0] = chirp[0].conj().scale(scale);
+            for k in 1..len {
+                kernel[k] = chirp[k].conj().scale(scale);
+                kernel[m - k] = kernel[k];
+            }
+            inner.process(&mut kernel, &mut [], false);
+            Algorithm::Bluestein {
+                chirp,
+                kernel,
+                inner: Box::new(inner),
+            }
+        };
+        FftPlan { len, algorithm }
+    }
+
+    /// Return the length of the transforms of the plan.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Return `true` if the plan is for transforms of length zero.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Replace every lane of `a` along `axis` by its discrete Fourier
+    /// transform.
+    ///
+    /// **Panics** if `axis` is out of bounds or its length is not the length
+    /// of the plan.
+    pub fn forward<S, D>(&self, a: &mut ArrayBase<S, D>, axis: Axis)
+    where
+        S: DataMut<Elem = Complex<A>>,
+        D: Dimension,
+    {
+        self.transform_lanes(a, axis, false);
+    }
+
+    /// Replace every lane of `a` along `axis` by its inverse discrete
+    /// Fourier transform, normalized by `1/n`.
+    ///
+    /// **Panics** if `axis` is out of bounds or its length is not the length
+    /// of the plan.
+    pub fn inverse<S, D>(&self, a: &mut ArrayBase<S, D>, axis: Axis)
+    where
+        S: DataMut<Elem = Complex<A>>,
+        D: Dimension,
+    {
+        self.transform_lanes(a, axis, true);
+        if self.len > 1 {
+            let scale = A::one() / A::from(self.len).unwrap();
+            a.map_inplace(|x| *x = x.scale(scale));
+        }
+    }
+
+    fn transform_lanes<S, D>(&self, a: &mut ArrayBase<S, D>, axis: Axis, inverse: bool)
+    where
+        S: DataMut<Elem = Complex<A>>,
+        D: Dimension,
+    {
+        assert_eq!(
+            a.len_of(axis),
+            self.len,
+            "FftPlan: axis length does not match the plan"
+        );
+        if self.len <= 1 {
+            return;
+        }
+        let mut buffer = vec![Complex::zero(); self.len];
+        let mut scratch = vec![Complex::zero(); self.scratch_len()];
+        for mut lane in a.lanes_mut(axis) {
+            match lane.as_slice_mut() {
+                Some(lane) => self.process(lane, &mut scratch, inverse),
+                None => {
+                    buffer.iter_mut().zip(&lane).for_each(|(b, &x)| *b = x);
+                    self.process(&mut buffer, &mut scratch, inverse);
+                    lane.iter_mut().zip(&buffer).for_each(|(x, &b)| *x = b);
+                }
+            }
+        }
+    }
+
+    /// Return the length of the scratch space needed by `process`.
+    fn scratch_len(&self) -> usize {
+        match self.algorithm {
+            Algorithm::Identity | Algorithm::Radix2 { .. } => 0,
+            Algorithm::MixedRadix { .. } => self.len,
+            Algorithm::Bluestein { ref inner, .. } => inner.len,
+        }
+    }
+
+    /// Transform `buffer` in place; the unnormalized inverse transform if
+    /// `inverse` is true.
+    pub(crate) fn process(
+        &self,
+        buffer: &mut [Complex<A>],
+        scratch: &mut [Complex<A>],
+        inverse: bool,
+    ) {
+        debug_assert_eq!(buffer.len(), self.len);
+        // The inverse transform is the conjugate of the forward transform of
+        // the conjugate.
+        if inverse {
+            buffer.iter_mut().for_each(|x| *x = x.conj());
+        }
+        match self.algorithm {
+            Algorithm::Identity => {}
+            Algorithm::Radix2 { ref twiddles } => radix2(buffer, twiddles),
+            Algorithm::MixedRadix {
+                ref factors,
+                ref twiddles,
+            } => {
+                let scratch = &mut scratch[..self.len];
+                scratch.copy_from_slice(buffer);
+                mixed_radix(buffer, scratch, 1, factors, twiddles);
+            }
+            Algorithm::Bluestein {
+                ref chirp,
+                ref kernel,
+                ref inner,
+            } => {
+                let work = &mut scratch[..inner.len];
+                for (w, (&x, &c)) in work.iter_mut().zip(buffer.iter().zip(chirp)) {
+                    *w = x * c;
+                }
+                work[self.len..]
+                    .iter_mut()
+                    .for_each(|w| *w = Complex::zero());
+                inner.process(work, &mut [], false);
+                work.iter_mut().zip(kernel).for_each(|(w, &k)| *w *= k);
+                inner.process(work, &mut [], true);
+                for (x, (&w, &c)) in buffer.iter_mut().zip(work.iter().zip(chirp)) {
+                    *x = w * c;
+                }
+            }
+        }
+        if inverse {
+            buffer.iter_mut().for_each(|x| *x = x.conj());
+        }
+    }
+}
+
+/// The in-place radix-2 transform of a power-of-two length.
+fn radix2<A: NdFloat>(buffer: &mut [Complex<A>], twiddles: &[Complex<A>]) {
+    let n = buffer.len();
+    let bits = n.trailing_zeros();
+    for i in 0..n {
+        let j = i.reverse_bits() >> (std::mem::size_of::<usize>() as u32 * 8 - bits);
+        if i < j {
+            buffer.swap(i, j);
+        }
+    }
+    let mut half = 1;
+    while half < n {
+        let stride = n / (2 * half);
+        for block in buffer.chunks_mut(2 * half) {
+            let (lo, hi) = block.split_at_mut(half);
+            for (k, (x, y)) in lo.iter_mut().zip(hi).enumerate() {
+                let t = *y * twiddles[k * stride];
+                *y = *x - t;
+                *x += t;
+            }
+        }
+        half *= 2;
+    }
+}
+
+/// out ← the transform of the elements `input[0], input[stride], ...`,
+/// decimating in time by `factors[0]` and recursing on the rest.
+///
+/// `twiddles` holds `exp(-2πik/N)` for the full length `N`.
+fn mixed_radix<A: NdFloat>(
+    out: &mut [Complex<A>],
+    input: &[Complex<A>],
+    stride: usize,
+    factors: &[usize],
+    twiddles: &[Complex<A>],
+) {
+    let n = out.len();
+    let p = factors[0];
+    let m = n / p;
+    if m == 1 {
+        for (j, x) in out.iter_mut().enumerate() {
+            *x = input[j * stride];
+        }
+    } else {
+        for (j, part) in out.chunks_mut(m).enumerate() {
+            mixed_radix(
+                part,
+                &input[j * stride..],
+                stride * p,
+                &factors[1..],
+                twiddles,
+            );
+        }
+    }
+
+    // Combine the p transforms of length m; the twiddle exp(-2πik/n) is
+    // twiddles[k * fstride].
+    let fstride = twiddles.len() / n;
+    if p == 2 {
+        for k in 0..m {
+            let t = out[k + m] * twiddles[k * fstride];
+            out[k + m] = out[k] - t;
+            out[k] += t;
+        }
+        return;
+    }
+    let mut tmp = [Complex::zero(); MAX_RADIX];
+    for k in 0..m {
+        for (j, t) in tmp[..p].iter_mut().enumerate() {
+            *t = out[k + j * m] * twiddles[j * k * fstride];
+        }
+        for q in 0..p {
+            let mut sum = tmp[0];
+            for (j, &t) in tmp[..p].iter().enumerate().skip(1) {
+                sum += t * twiddles[((j * q) % p) * m * fstride];
+            }
+            out[k + q * m] = sum;
+        }
+    }
+}
+
+/// The number of plans each thread keeps in its cache.
+const CACHED_PLANS: usize = 16;
+
+/// Cached plans keyed by element type and length, most recently used first.
+type PlanCache = Vec<((TypeId, usize), Rc<dyn Any>)>;
+
+thread_local! {
+    static PLANS: RefCell<PlanCache> = RefCell::new(Vec::new());
+}
+
+/// Return the plan for length `len` from the per-thread cache, creating it
+/// if needed.
+///
+/// The cache holds at most `CACHED_PLANS` plans; when it is full, the least
+/// recently used plan is dropped. Callers transforming many lengths
+/// repeatedly can keep their own `FftPlan` instead.
+pub(crate) fn cached_plan<A: NdFloat>(len: usize) -> Rc<FftPlan<A>> {
+    let key = (TypeId::of::<A>(), len);
+    PLANS.with(|plans| {
+        let mut plans = plans.borrow_mut();
+        let plan = match plans.iter().position(|&(k, _)| k == key) {
+            Some(i) => plans.remove(i).1,
+            None => Rc::new(FftPlan::<A>::new(len)) as Rc<dyn Any>,
+        };
+        plans.insert(0, (key, plan.clone()));
+        plans.truncate(CACHED_PLANS);
+        plan.downcast::<FftPlan<A>>().unwrap()
+    })
+}
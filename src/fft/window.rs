@@ -0,0 +1,61 @@
+// Copyright 2019 bluss and ndarray developers.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use std::f64::consts::PI;
+
+use crate::imp_prelude::*;
+use crate::NdFloat;
+
+/// Return the symmetric window of length `n` given by the cosine series
+/// `Σ (-1)^j coefs[j] cos(2πjk/(n-1))`.
+fn cosine_window<A: NdFloat>(n: usize, coefs: &[f64]) -> Array1<A> {
+    if n == 1 {
+        return Array1::ones(1);
+    }
+    Array1::from_shape_fn(n, |k| {
+        let x = 2. * PI * (k as f64) / ((n - 1) as f64);
+        let mut sign = 1.;
+        let mut w = 0.;
+        for (j, &c) in coefs.iter().enumerate() {
+            w += sign * c * (j as f64 * x).cos();
+            sign = -sign;
+        }
+        A::from(w).unwrap()
+    })
+}
+
+/// Return the Hann window of length `n`,
+/// `w[k] = 0.5 - 0.5 cos(2πk/(n-1))`.
+///
+/// The window is symmetric, and is zero at both ends.
+///
+/// ```
+/// use ndarray::fft::hann;
+///
+/// let w = hann::<f64>(5);
+/// assert!((&w - &ndarray::arr1(&[0., 0.5, 1., 0.5, 0.])).iter().all(|x| x.abs() < 1e-15));
+/// ```
+pub fn hann<A: NdFloat>(n: usize) -> Array1<A> {
+    cosine_window(n, &[0.5, 0.5])
+}
+
+/// Return the Hamming window of length `n`,
+/// `w[k] = 0.54 - 0.46 cos(2πk/(n-1))`.
+///
+/// The window is symmetric.
+pub fn hamming<A: NdFloat>(n: usize) -> Array1<A> {
+    cosine_window(n, &[0.54, 0.46])
+}
+
+/// Return the Blackman window of length `n`,
+/// `w[k] = 0.42 - 0.5 cos(2πk/(n-1)) + 0.08 cos(4πk/(n-1))`.
+///
+/// The window is symmetric.
+pub fn blackman<A: NdFloat>(n: usize) -> Array1<A> {
+    cosine_window(n, &[0.42, 0.5, 0.08])
+}
@@ -7,7 +7,10 @@
 // except according to those terms.
 
 //! Methods for two-dimensional arrays.
+use crate::error::{from_kind, ErrorKind, ShapeError};
 use crate::imp_prelude::*;
+use num_traits::Zero;
+use std::ops::Add;
 
 /// # Methods For 2-D Arrays
 impl<A, S> ArrayBase<S, Ix2>
@@ -68,4 +71,28 @@ where
     pub fn is_square(&self) -> bool {
         self.rows() == self.cols()
     }
+
+    /// Return the trace of the square matrix, the sum of its diagonal
+    /// elements.
+    ///
+    /// ***Errors*** if the matrix is not square.
+    ///
+    /// ```
+    /// use ndarray::arr2;
+    ///
+    /// let a = arr2(&[[1, 2],
+    ///                [3, 4]]);
+    /// assert_eq!(a.trace().unwrap(), 5);
+    /// assert!(a.slice(ndarray::s![.., ..1]).trace().is_err());
+    /// ```
+    pub fn trace(&self) -> Result<A, ShapeError>
+    where
+        S: Data,
+        A: Clone + Add<Output = A> + Zero,
+    {
+        if !self.is_square() {
+            return Err(from_kind(ErrorKind::IncompatibleShape));
+        }
+        Ok(self.diag().sum())
+    }
 }
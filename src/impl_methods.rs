@@ -24,8 +24,9 @@ use crate::error::{self, ErrorKind, ShapeError};
 use crate::zip::Zip;
 
 use crate::iter::{
-    AxisChunksIter, AxisChunksIterMut, AxisIter, AxisIterMut, ExactChunks, ExactChunksMut,
-    IndexedIter, IndexedIterMut, Iter, IterMut, Lanes, LanesMut, Windows,
+    AxisChunksIter, AxisChunksIterMut, AxisIter, AxisIterMut, AxisWindows, Chunks, ChunksMut,
+    ExactChunks, ExactChunksMut, IndexedIter, IndexedIterMut, Iter, IterMut, Lanes, LanesMut,
+    Windows,
 };
 use crate::stacking::stack;
 use crate::{NdIndex, Slice, SliceInfo, SliceOrIndex};
@@ -1103,6 +1104,84 @@ where
         ExactChunksMut::new(self.view_mut(), chunk_size)
     }
 
+    /// Return a chunks producer (and iterable).
+    ///
+    /// It produces the chunks of a given n-dimensional chunk size that tile
+    /// the array, in logical order. Unlike
+    /// [`.exact_chunks()`](#method.exact_chunks), the remainder along each
+    /// dimension is not skipped: the chunks at the end of an axis are
+    /// smaller if the chunk size does not divide its length.
+    ///
+    /// The produced element is a `ArrayView<A, D>` with at most the
+    /// dimension `chunk_size` along each axis. The producer has one element
+    /// for each chunk, so it can be zipped with the chunks of other arrays,
+    /// or with an array of one element per chunk. (`Zip` requires a fixed
+    /// dimensionality; for `IxDyn`, only the iterable is available.)
+    ///
+    /// **Panics** if any dimension of `chunk_size` is zero<br>
+    /// (**Panics** if `D` is `IxDyn` and `chunk_size` does not match the
+    /// number of array axes.)
+    ///
+    /// ```
+    /// use ndarray::{arr2, Array, Zip};
+    ///
+    /// let a = Array::from_shape_fn((5, 5), |(i, j)| (i * 5 + j) as i32);
+    /// // the sum of each 2×2 tile, with the partial tiles at the edges
+    /// let mut sums = Array::zeros((3, 3));
+    /// Zip::from(&mut sums)
+    ///     .and(a.chunks((2, 2)))
+    ///     .apply(|s, chunk| *s = chunk.sum());
+    /// assert_eq!(sums, arr2(&[[12, 20, 13],
+    ///                         [52, 60, 33],
+    ///                         [41, 45, 24]]));
+    /// ```
+    pub fn chunks<E>(&self, chunk_size: E) -> Chunks<'_, A, D>
+    where
+        E: IntoDimension<Dim = D>,
+        S: Data,
+    {
+        Chunks::new(self.view(), chunk_size)
+    }
+
+    /// Return a chunks producer (and iterable).
+    ///
+    /// It produces the chunks of a given n-dimensional chunk size that tile
+    /// the array, including the smaller chunks at the end of each axis; see
+    /// [`.chunks()`](#method.chunks) for more information.
+    ///
+    /// The produced element is a `ArrayViewMut<A, D>` with at most the
+    /// dimension `chunk_size` along each axis.
+    ///
+    /// **Panics** if any dimension of `chunk_size` is zero<br>
+    /// (**Panics** if `D` is `IxDyn` and `chunk_size` does not match the
+    /// number of array axes.)
+    ///
+    /// ```
+    /// use ndarray::{arr2, Array2, Zip};
+    ///
+    /// let mut a = Array2::zeros((3, 5));
+    /// let b = Array2::from_elem((3, 5), 1);
+    /// // number the tiles, and add b tile by tile
+    /// let mut n = 0;
+    /// Zip::from(a.chunks_mut((2, 2)))
+    ///     .and(b.chunks((2, 2)))
+    ///     .apply(|mut chunk, other| {
+    ///         chunk.fill(n);
+    ///         chunk += &other;
+    ///         n += 1;
+    ///     });
+    /// assert_eq!(a, arr2(&[[1, 1, 2, 2, 3],
+    ///                      [1, 1, 2, 2, 3],
+    ///                      [4, 4, 5, 5, 6]]));
+    /// ```
+    pub fn chunks_mut<E>(&mut self, chunk_size: E) -> ChunksMut<'_, A, D>
+    where
+        E: IntoDimension<Dim = D>,
+        S: DataMut,
+    {
+        ChunksMut::new(self.view_mut(), chunk_size)
+    }
+
     /// Return a window producer and iterable.
     ///
     /// The windows are all distinct overlapping views of size `window_size`
@@ -1147,6 +1226,111 @@ where
         Windows::new(self.view(), window_size)
     }
 
+    /// Return a window producer and iterable, with windows that are `stride`
+    /// elements apart along each axis.
+    ///
+    /// The windows are the views of size `window_size` that fit into the
+    /// array's shape and start at multiples of `stride`; with a stride equal
+    /// to the window size, they are the non-overlapping tiles of pooling.
+    /// See [`.windows()`](#method.windows) for more information.
+    ///
+    /// **Panics** if any dimension of `window_size` or `stride` is zero.<br>
+    /// (**Panics** if `D` is `IxDyn` and `window_size` or `stride` does not
+    /// match the number of array axes.)
+    ///
+    /// ```
+    /// use ndarray::{arr2, Array, Zip};
+    ///
+    /// let a = Array::from_shape_fn((4, 5), |(i, j)| (i * 5 + j) as i32);
+    /// // 2×2 max pooling
+    /// let mut pooled = Array::zeros((2, 2));
+    /// Zip::from(&mut pooled)
+    ///     .and(a.windows_with_stride((2, 2), (2, 2)))
+    ///     .apply(|p, w| *p = *w.iter().max().unwrap());
+    /// assert_eq!(pooled, arr2(&[[6, 8],
+    ///                           [16, 18]]));
+    /// ```
+    pub fn windows_with_stride<E>(&self, window_size: E, stride: E) -> Windows<'_, A, D>
+    where
+        E: IntoDimension<Dim = D>,
+        S: Data,
+    {
+        let window = window_size.into_dimension();
+        let mut dilation = window.clone();
+        for d in dilation.slice_mut() {
+            *d = 1;
+        }
+        Windows::new_with_stride_dilation(self.view(), window, stride.into_dimension(), dilation)
+    }
+
+    /// Return a window producer and iterable, with windows that are `stride`
+    /// elements apart and have their elements `dilation` elements apart
+    /// along each axis.
+    ///
+    /// A window of size `n` and dilation `d` along an axis covers
+    /// `(n - 1) * d + 1` elements of the array, of which it holds every
+    /// `d`th. The windows are those that fit into the array's shape and
+    /// start at multiples of `stride`. See [`.windows()`](#method.windows)
+    /// for more information.
+    ///
+    /// **Panics** if any dimension of `window_size`, `stride` or `dilation`
+    /// is zero.<br>
+    /// (**Panics** if `D` is `IxDyn` and `window_size`, `stride` or
+    /// `dilation` does not match the number of array axes.)
+    ///
+    /// ```
+    /// use ndarray::{arr1, Array};
+    ///
+    /// let a = Array::range(0., 8., 1.);
+    /// let windows: Vec<_> = a.windows_with_stride_dilation(3, 2, 2).into_iter().collect();
+    /// assert_eq!(windows, vec![arr1(&[0., 2., 4.]), arr1(&[2., 4., 6.])]);
+    /// ```
+    pub fn windows_with_stride_dilation<E>(
+        &self,
+        window_size: E,
+        stride: E,
+        dilation: E,
+    ) -> Windows<'_, A, D>
+    where
+        E: IntoDimension<Dim = D>,
+        S: Data,
+    {
+        Windows::new_with_stride_dilation(self.view(), window_size, stride, dilation)
+    }
+
+    /// Return a window producer and iterable, with windows that slide along
+    /// `axis` only.
+    ///
+    /// The windows are all distinct overlapping views that hold
+    /// `window_size` consecutive subviews along `axis`, and the whole
+    /// array along the other axes. The producer is one-dimensional, with one
+    /// element for each window.
+    ///
+    /// Will yield over no elements if `window_size` is larger than the
+    /// length of `axis`.
+    ///
+    /// **Panics** if `axis` is out of bounds or if `window_size` is zero.
+    ///
+    /// ```
+    /// use ndarray::{arr1, arr2, Array, Axis, Zip};
+    ///
+    /// let a = arr2(&[[1, 2, 3],
+    ///                [4, 5, 6],
+    ///                [7, 8, 9]]);
+    /// // moving sum of pairs of rows
+    /// let mut sums = Array::zeros(2);
+    /// Zip::from(&mut sums)
+    ///     .and(a.axis_windows(Axis(0), 2))
+    ///     .apply(|s, w| *s = w.sum());
+    /// assert_eq!(sums, arr1(&[21, 39]));
+    /// ```
+    pub fn axis_windows(&self, axis: Axis, window_size: usize) -> AxisWindows<'_, A, D>
+    where
+        S: Data,
+    {
+        AxisWindows::new(self.view(), axis, window_size)
+    }
+
     // Return (length, stride) for diagonal
     fn diag_params(&self) -> (Ix, Ixs) {
         /* empty shape has len 1 */
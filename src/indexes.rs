@@ -114,7 +114,7 @@ where
 
 #[derive(Copy, Clone, Debug)]
 pub struct IndexPtr<D> {
-    index: D,
+    pub(crate) index: D,
 }
 
 impl<D> Offset for IndexPtr<D>
@@ -0,0 +1,204 @@
+// Copyright 2019 bluss and ndarray developers.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Numerical integration of sampled functions.
+//!
+//! The methods are
+//! [`.trapz()`](../struct.ArrayBase.html#method.trapz),
+//! [`.cumulative_trapz()`](../struct.ArrayBase.html#method.cumulative_trapz)
+//! and [`.simpson()`](../struct.ArrayBase.html#method.simpson). The
+//! positions of the samples along the axis of integration are given by
+//! [`Spacing`](enum.Spacing.html).
+
+use crate::imp_prelude::*;
+use crate::{NdFloat, Zip};
+
+/// The positions of the samples along the axis of integration.
+#[derive(Copy, Clone, Debug)]
+pub enum Spacing<'a, A> {
+    /// Samples spaced uniformly, this distance apart.
+    Uniform(A),
+    /// Samples at these coordinates, which should be increasing (or
+    /// decreasing, which flips the sign of the integral).
+    Coords(ArrayView1<'a, A>),
+}
+
+impl<'a, A: NdFloat> Spacing<'a, A> {
+    /// Return the distances between consecutive samples, for `n` samples.
+    ///
+    /// **Panics** if there are coordinates, but not `n` of them.
+    fn steps(&self, n: usize) -> Vec<A> {
+        match *self {
+            Spacing::Uniform(dx) => vec![dx; n.saturating_sub(1)],
+            Spacing::Coords(ref x) => {
+                assert_eq!(
+                    x.len(),
+                    n,
+                    "integration: number of coordinates does not match the axis length"
+                );
+                x.windows(2).into_iter().map(|w| w[1] - w[0]).collect()
+            }
+        }
+    }
+}
+
+/// Return the weights of the trapezoidal rule for the steps `h`.
+fn trapz_weights<A: NdFloat>(h: &[A]) -> Array1<A> {
+    let half = A::from(0.5).unwrap();
+    let mut w = Array1::zeros(h.len() + 1);
+    for (i, &h) in h.iter().enumerate() {
+        w[i] += half * h;
+        w[i + 1] += half * h;
+    }
+    w
+}
+
+/// Return the weights of Simpson's rule for the steps `h`.
+///
+/// Pairs of intervals are integrated with the quadratic through their three
+/// samples. With an odd number of intervals, the last interval is
+/// integrated with the quadratic through the last three samples.
+fn simpson_weights<A: NdFloat>(h: &[A]) -> Array1<A> {
+    let n = h.len();
+    if n < 2 {
+        return trapz_weights(h);
+    }
+    let (two, three, six) = (
+        A::from(2).unwrap(),
+        A::from(3).unwrap(),
+        A::from(6).unwrap(),
+    );
+    let mut w = Array1::zeros(n + 1);
+    for i in (0..n - 1).step_by(2) {
+        let (h0, h1) = (h[i], h[i + 1]);
+        let s = (h0 + h1) / six;
+        w[i] += s * (two - h1 / h0);
+        w[i + 1] += s * (h0 + h1) * (h0 + h1) / (h0 * h1);
+        w[i + 2] += s * (two - h0 / h1);
+    }
+    if n % 2 == 1 {
+        let (h0, h1) = (h[n - 2], h[n - 1]);
+        w[n] += (two * h1 * h1 + three * h0 * h1) / (six * (h0 + h1));
+        w[n - 1] += (h1 * h1 + three * h0 * h1) / (six * h0);
+        w[n - 2] -= h1 * h1 * h1 / (six * h0 * (h0 + h1));
+    }
+    w
+}
+
+/// # Numerical Integration
+///
+/// These methods integrate every lane of the array along an axis, which
+/// holds samples of a function at the positions given by
+/// [`Spacing`](integrate/enum.Spacing.html).
+impl<A, S, D> ArrayBase<S, D>
+where
+    S: Data<Elem = A>,
+    D: Dimension,
+    A: NdFloat,
+{
+    /// Return the integral of every lane along `axis`, by the trapezoidal
+    /// rule.
+    ///
+    /// Lanes with fewer than two samples integrate to zero.
+    ///
+    /// **Panics** if `axis` is out of bounds, or if there are coordinates,
+    /// but not one for each sample.
+    ///
+    /// ```
+    /// use ndarray::{arr1, arr2, Axis};
+    /// use ndarray::integrate::Spacing;
+    ///
+    /// let a = arr2(&[[0., 1., 2.],
+    ///                [1., 1., 1.]]);
+    /// assert_eq!(a.trapz(Axis(1), Spacing::Uniform(0.5)), arr1(&[1., 1.]));
+    /// let x = arr1(&[0., 1., 3.]);
+    /// assert_eq!(a.trapz(Axis(1), Spacing::Coords(x.view())), arr1(&[3.5, 3.]));
+    /// ```
+    pub fn trapz(&self, axis: Axis, spacing: Spacing<'_, A>) -> Array<A, D::Smaller>
+    where
+        D: RemoveAxis,
+    {
+        let weights = trapz_weights(&spacing.steps(self.len_of(axis)));
+        self.weighted_sum_axis(axis, &weights)
+    }
+
+    /// Return the running integral of every lane along `axis`, by the
+    /// trapezoidal rule.
+    ///
+    /// The result has the shape of the array; element `i` along `axis` is
+    /// the integral from the first sample to sample `i`, so the first is
+    /// zero and the last is the result of
+    /// [`.trapz()`](#method.trapz).
+    ///
+    /// **Panics** if `axis` is out of bounds, or if there are coordinates,
+    /// but not one for each sample.
+    ///
+    /// ```
+    /// use ndarray::{arr1, Axis};
+    /// use ndarray::integrate::Spacing;
+    ///
+    /// let a = arr1(&[1., 3., 5., 7.]);
+    /// assert_eq!(a.cumulative_trapz(Axis(0), Spacing::Uniform(1.)), arr1(&[0., 2., 6., 12.]));
+    /// ```
+    pub fn cumulative_trapz(&self, axis: Axis, spacing: Spacing<'_, A>) -> Array<A, D> {
+        let h = spacing.steps(self.len_of(axis));
+        let half = A::from(0.5).unwrap();
+        let mut out = Array::zeros(self.raw_dim());
+        Zip::from(out.lanes_mut(axis))
+            .and(self.lanes(axis))
+            .apply(|mut out, f| {
+                let mut sum = A::zero();
+                for (i, &h) in h.iter().enumerate() {
+                    sum += half * h * (f[i] + f[i + 1]);
+                    out[i + 1] = sum;
+                }
+            });
+        out
+    }
+
+    /// Return the integral of every lane along `axis`, by Simpson's rule.
+    ///
+    /// The samples may be spaced non-uniformly. Each pair of intervals is
+    /// integrated with the quadratic through its three samples, and if the
+    /// number of intervals is odd, the last interval is integrated with the
+    /// quadratic through the last three samples. Lanes with two samples are
+    /// integrated by the trapezoidal rule, and shorter lanes integrate to
+    /// zero.
+    ///
+    /// **Panics** if `axis` is out of bounds, or if there are coordinates,
+    /// but not one for each sample.
+    ///
+    /// ```
+    /// use ndarray::{Array, Axis};
+    /// use ndarray::integrate::Spacing;
+    ///
+    /// // Simpson's rule integrates cubic polynomials exactly
+    /// let x = Array::linspace(0., 2., 5);
+    /// let f = x.mapv(|x: f64| x * x * x);
+    /// assert!((f.simpson(Axis(0), Spacing::Coords(x.view())).into_scalar() - 4.).abs() < 1e-14);
+    /// ```
+    pub fn simpson(&self, axis: Axis, spacing: Spacing<'_, A>) -> Array<A, D::Smaller>
+    where
+        D: RemoveAxis,
+    {
+        let weights = simpson_weights(&spacing.steps(self.len_of(axis)));
+        self.weighted_sum_axis(axis, &weights)
+    }
+
+    /// Return the sum of the subviews along `axis`, weighted by `weights`.
+    fn weighted_sum_axis(&self, axis: Axis, weights: &Array1<A>) -> Array<A, D::Smaller>
+    where
+        D: RemoveAxis,
+    {
+        let mut out = Array::zeros(self.raw_dim().remove_axis(axis));
+        for (&w, sub) in weights.iter().zip(self.axis_iter(axis)) {
+            Zip::from(&mut out).and(&sub).apply(|out, &f| *out += w * f);
+        }
+        out
+    }
+}
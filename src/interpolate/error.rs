@@ -0,0 +1,68 @@
+// Copyright 2019 bluss and ndarray developers.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+use crate::error::{from_kind, ErrorKind, ShapeError};
+use std::error::Error;
+use std::fmt;
+
+/// An error from an interpolation.
+///
+/// This enumeration is not exhaustive.
+#[derive(Clone, Debug, PartialEq)]
+pub enum InterpolateError {
+    /// a point is outside the range of the coordinates, and extrapolation is
+    /// [`Extrapolate::Error`](enum.Extrapolate.html#variant.Error)
+    OutOfRange,
+    /// the coordinates are not strictly increasing
+    NotIncreasing,
+    /// the coordinates and values have incompatible shapes, or there are no
+    /// data points
+    Shape(ShapeError),
+    #[doc(hidden)]
+    __Incomplete,
+}
+
+impl InterpolateError {
+    fn message(&self) -> &'static str {
+        match *self {
+            InterpolateError::OutOfRange => "a point is outside the range of the coordinates",
+            InterpolateError::NotIncreasing => "the coordinates are not strictly increasing",
+            InterpolateError::Shape(_) => "incompatible shapes",
+            InterpolateError::__Incomplete => "this error variant is not in use",
+        }
+    }
+}
+
+impl Error for InterpolateError {
+    fn description(&self) -> &str {
+        self.message()
+    }
+}
+
+impl fmt::Display for InterpolateError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            InterpolateError::Shape(ref e) => write!(f, "InterpolateError: {}", e),
+            _ => write!(f, "InterpolateError: {}", self.message()),
+        }
+    }
+}
+
+impl From<ShapeError> for InterpolateError {
+    fn from(e: ShapeError) -> Self {
+        InterpolateError::Shape(e)
+    }
+}
+
+/// Return an error unless `len` is `expected` and at least one.
+pub(crate) fn check_len(len: usize, expected: usize) -> Result<(), InterpolateError> {
+    if len == expected && len > 0 {
+        Ok(())
+    } else {
+        Err(from_kind(ErrorKind::IncompatibleShape).into())
+    }
+}
@@ -0,0 +1,160 @@
+// Copyright 2019 bluss and ndarray developers.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use crate::error::{from_kind, ErrorKind};
+use crate::imp_prelude::*;
+use crate::NdFloat;
+
+use super::error::{check_len, InterpolateError};
+use super::{check_increasing, fraction, locate, Extrapolate, Position};
+
+/// Multilinear interpolation of values on a regular grid.
+///
+/// The grid is given by strictly increasing coordinates along each axis, and
+/// the values by an array with one element for each grid point. Points
+/// outside the grid are handled as selected with
+/// [`.extrapolate()`](#method.extrapolate); the default is
+/// `Extrapolate::Error`.
+///
+/// ```
+/// use ndarray::{arr1, arr2};
+/// use ndarray::interpolate::{Extrapolate, RegularGridInterpolator};
+///
+/// // f(x, y) = x + 10 y on the grid x ∈ {0, 1, 2}, y ∈ {0, 2}
+/// let values = arr2(&[[0., 20.],
+///                     [1., 21.],
+///                     [2., 22.]]);
+/// let f = RegularGridInterpolator::new(vec![arr1(&[0., 1., 2.]), arr1(&[0., 2.])], values)
+///     .unwrap();
+/// assert_eq!(f.eval(&[1.5, 0.5]).unwrap(), 6.5);
+/// assert!(f.eval(&[3., 0.]).is_err());
+///
+/// let f = f.extrapolate(Extrapolate::Clamp);
+/// assert_eq!(f.eval(&[3., 0.]).unwrap(), 2.);
+/// let points = arr2(&[[0., 0.], [2., 1.]]);
+/// assert_eq!(f.eval_points(&points).unwrap(), arr1(&[0., 12.]));
+/// ```
+#[derive(Clone, Debug)]
+pub struct RegularGridInterpolator<A, D>
+where
+    D: Dimension,
+{
+    coords: Vec<Array1<A>>,
+    values: Array<A, D>,
+    extrapolate: Extrapolate<A>,
+}
+
+impl<A, D> RegularGridInterpolator<A, D>
+where
+    A: NdFloat,
+    D: Dimension,
+{
+    /// Create an interpolator of `values`, with the coordinates `coords[k]`
+    /// along axis `k`.
+    ///
+    /// ***Errors*** if the number of coordinate arrays is not the number of
+    /// axes of `values`, if the length of an axis is zero or not the length
+    /// of its coordinates, or if coordinates are not strictly increasing.
+    pub fn new(coords: Vec<Array1<A>>, values: Array<A, D>) -> Result<Self, InterpolateError> {
+        if coords.len() != values.ndim() {
+            return Err(from_kind(ErrorKind::IncompatibleShape).into());
+        }
+        for (c, &len) in coords.iter().zip(values.shape()) {
+            check_len(len, c.len())?;
+            check_increasing(&c.view())?;
+        }
+        Ok(RegularGridInterpolator {
+            coords,
+            values,
+            extrapolate: Extrapolate::Error,
+        })
+    }
+
+    /// Set the handling of points outside the grid.
+    pub fn extrapolate(mut self, extrapolate: Extrapolate<A>) -> Self {
+        self.extrapolate = extrapolate;
+        self
+    }
+
+    /// Return the coordinates along each axis.
+    pub fn coords(&self) -> &[Array1<A>] {
+        &self.coords
+    }
+
+    /// Return the values at the grid points.
+    pub fn values(&self) -> ArrayView<'_, A, D> {
+        self.values.view()
+    }
+
+    /// Return the interpolated value at `point`, which has one coordinate
+    /// for each axis.
+    ///
+    /// ***Errors*** if `point` has the wrong length, or if it is outside the
+    /// grid and extrapolation is `Extrapolate::Error`.
+    pub fn eval(&self, point: &[A]) -> Result<A, InterpolateError> {
+        let ndim = self.values.ndim();
+        if point.len() != ndim {
+            return Err(from_kind(ErrorKind::IncompatibleShape).into());
+        }
+        // the lower corner of the grid cell and the fractions along each axis
+        let mut corner = self.values.raw_dim();
+        let mut t = Vec::with_capacity(ndim);
+        for (k, (c, &x)) in self.coords.iter().zip(point).enumerate() {
+            match locate(&c.view(), x, self.extrapolate)? {
+                Position::Inside { index, x } => {
+                    corner[k] = index;
+                    t.push(fraction(&c.view(), index, x));
+                }
+                Position::Fill(value) => return Ok(value),
+            }
+        }
+        // sum over the corners of the cell, skipping those of weight zero
+        let mut sum = A::zero();
+        let mut index = corner.clone();
+        'corners: for mask in 0..1usize << ndim {
+            let mut weight = A::one();
+            for k in 0..ndim {
+                if mask & (1 << k) != 0 {
+                    if t[k].is_zero() {
+                        continue 'corners;
+                    }
+                    index[k] = corner[k] + 1;
+                    weight *= t[k];
+                } else {
+                    index[k] = corner[k];
+                    weight *= A::one() - t[k];
+                }
+            }
+            sum += weight * self.values[index.clone()];
+        }
+        Ok(sum)
+    }
+
+    /// Return the interpolated values at the points given by the rows of
+    /// `points`.
+    ///
+    /// ***Errors*** if `points` does not have one column for each axis, or if
+    /// a point is outside the grid and extrapolation is
+    /// `Extrapolate::Error`.
+    pub fn eval_points<S>(&self, points: &ArrayBase<S, Ix2>) -> Result<Array1<A>, InterpolateError>
+    where
+        S: Data<Elem = A>,
+    {
+        if points.cols() != self.values.ndim() {
+            return Err(from_kind(ErrorKind::IncompatibleShape).into());
+        }
+        let mut point = vec![A::zero(); points.cols()];
+        points
+            .outer_iter()
+            .map(|row| {
+                point.iter_mut().zip(&row).for_each(|(p, &x)| *p = x);
+                self.eval(&point)
+            })
+            .collect()
+    }
+}
@@ -0,0 +1,225 @@
+// Copyright 2019 bluss and ndarray developers.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Interpolation.
+//!
+//! - [`interp`](fn.interp.html) and [`interp_axis`](fn.interp_axis.html)
+//!   interpolate linearly between data points.
+//! - [`RegularGridInterpolator`](struct.RegularGridInterpolator.html)
+//!   interpolates multilinearly between values on a grid with coordinates
+//!   along each axis.
+//! - [`CubicSpline`](struct.CubicSpline.html) interpolates with natural or
+//!   clamped cubic splines.
+//!
+//! The coordinates of the data points must be strictly increasing. What
+//! happens at points outside their range is selected by
+//! [`Extrapolate`](enum.Extrapolate.html).
+//!
+//! ```
+//! use ndarray::arr1;
+//! use ndarray::interpolate::{interp, Extrapolate};
+//!
+//! let xp = arr1(&[0., 1., 3.]);
+//! let fp = arr1(&[10., 20., 0.]);
+//! let x = arr1(&[0.5, 2., 4.]);
+//! assert_eq!(interp(&x, &xp, &fp, Extrapolate::Clamp).unwrap(), arr1(&[15., 10., 0.]));
+//! assert_eq!(interp(&x, &xp, &fp, Extrapolate::Fill(-1.)).unwrap(), arr1(&[15., 10., -1.]));
+//! assert!(interp(&x, &xp, &fp, Extrapolate::Error).is_err());
+//! ```
+
+pub use self::error::InterpolateError;
+pub use self::grid::RegularGridInterpolator;
+pub use self::spline::CubicSpline;
+
+use crate::imp_prelude::*;
+use crate::NdFloat;
+
+use self::error::check_len;
+
+mod error;
+mod grid;
+mod spline;
+
+/// What an interpolation does at points outside the range of the
+/// coordinates of the data points.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum Extrapolate<A> {
+    /// Return [`InterpolateError::OutOfRange`](enum.InterpolateError.html).
+    Error,
+    /// Move the point to the nearest end of the range (along each axis).
+    Clamp,
+    /// Use the given value.
+    Fill(A),
+}
+
+/// The position of a point relative to the coordinates of the data points.
+enum Position<A> {
+    /// The point `x`, which lies in the interval from `xp[index]` to
+    /// `xp[index + 1]` (or is `xp[0]` if there is only one coordinate).
+    Inside { index: usize, x: A },
+    /// The point is out of range, and the result is the fill value.
+    Fill(A),
+}
+
+/// Return an error unless `xp` is strictly increasing.
+fn check_increasing<A: NdFloat>(xp: &ArrayView1<'_, A>) -> Result<(), InterpolateError> {
+    if xp.windows(2).into_iter().all(|w| w[0] < w[1]) {
+        Ok(())
+    } else {
+        Err(InterpolateError::NotIncreasing)
+    }
+}
+
+/// Locate `x` among the nonempty, increasing coordinates `xp`.
+fn locate<A: NdFloat>(
+    xp: &ArrayView1<'_, A>,
+    mut x: A,
+    extrapolate: Extrapolate<A>,
+) -> Result<Position<A>, InterpolateError> {
+    let n = xp.len();
+    let (first, last) = (xp[0], xp[n - 1]);
+    if x < first || x > last {
+        match extrapolate {
+            Extrapolate::Error => return Err(InterpolateError::OutOfRange),
+            Extrapolate::Clamp => x = if x < first { first } else { last },
+            Extrapolate::Fill(value) => return Ok(Position::Fill(value)),
+        }
+    }
+    // the largest index below n - 1 with xp[index] <= x
+    let (mut lo, mut hi) = (0, n - 1);
+    while hi - lo > 1 {
+        let mid = (lo + hi) / 2;
+        if xp[mid] <= x {
+            lo = mid;
+        } else {
+            hi = mid;
+        }
+    }
+    Ok(Position::Inside { index: lo, x })
+}
+
+/// Return the fraction `t` of the way from `xp[index]` to `xp[index + 1]`
+/// at which `x` lies; zero if there is only one coordinate.
+fn fraction<A: NdFloat>(xp: &ArrayView1<'_, A>, index: usize, x: A) -> A {
+    if index + 1 < xp.len() {
+        (x - xp[index]) / (xp[index + 1] - xp[index])
+    } else {
+        A::zero()
+    }
+}
+
+/// Return the linear interpolation between `fp[index]` and `fp[index + 1]`
+/// at the fraction `t`.
+fn lerp<A: NdFloat>(fp: &ArrayView1<'_, A>, index: usize, t: A) -> A {
+    if t.is_zero() {
+        fp[index]
+    } else {
+        fp[index] * (A::one() - t) + fp[index + 1] * t
+    }
+}
+
+/// Return the piecewise linear interpolation through the data points
+/// `(xp[i], fp[i])` at each element of `x`.
+///
+/// ***Errors*** if `xp` and `fp` have different lengths or are empty, if
+/// `xp` is not strictly increasing, or if an element of `x` is out of range
+/// and `extrapolate` is `Extrapolate::Error`.
+pub fn interp<A, S1, S2, S3, D>(
+    x: &ArrayBase<S1, D>,
+    xp: &ArrayBase<S2, Ix1>,
+    fp: &ArrayBase<S3, Ix1>,
+    extrapolate: Extrapolate<A>,
+) -> Result<Array<A, D>, InterpolateError>
+where
+    A: NdFloat,
+    S1: Data<Elem = A>,
+    S2: Data<Elem = A>,
+    S3: Data<Elem = A>,
+    D: Dimension,
+{
+    let (xp, fp) = (xp.view(), fp.view());
+    check_len(fp.len(), xp.len())?;
+    check_increasing(&xp)?;
+    let mut out = Array::zeros(x.raw_dim());
+    for (out, &x) in out.iter_mut().zip(x) {
+        *out = match locate(&xp, x, extrapolate)? {
+            Position::Inside { index, x } => lerp(&fp, index, fraction(&xp, index, x)),
+            Position::Fill(value) => value,
+        };
+    }
+    Ok(out)
+}
+
+/// Return the piecewise linear interpolation at the points `x` of every
+/// lane of `fp` along `axis`, with the coordinates `xp` along the axis.
+///
+/// The result has the shape of `fp`, except for length `x.len()` along
+/// `axis`.
+///
+/// ***Errors*** if the length of `fp` along `axis` is not the length of
+/// `xp`, or is zero, if `xp` is not strictly increasing, or if an element
+/// of `x` is out of range and `extrapolate` is `Extrapolate::Error`.<br>
+/// **Panics** if `axis` is out of bounds.
+///
+/// ```
+/// use ndarray::{arr1, arr2, Axis};
+/// use ndarray::interpolate::{interp_axis, Extrapolate};
+///
+/// // two time series sampled at the times xp, resampled at x
+/// let fp = arr2(&[[0., 1., 4.],
+///                 [2., 2., 0.]]);
+/// let xp = arr1(&[0., 1., 2.]);
+/// let x = arr1(&[0.5, 1.5]);
+/// let f = interp_axis(&x, &xp, &fp, Axis(1), Extrapolate::Error).unwrap();
+/// assert_eq!(f, arr2(&[[0.5, 2.5],
+///                      [2., 1.]]));
+/// ```
+pub fn interp_axis<A, S1, S2, S3, D>(
+    x: &ArrayBase<S1, Ix1>,
+    xp: &ArrayBase<S2, Ix1>,
+    fp: &ArrayBase<S3, D>,
+    axis: Axis,
+    extrapolate: Extrapolate<A>,
+) -> Result<Array<A, D>, InterpolateError>
+where
+    A: NdFloat,
+    S1: Data<Elem = A>,
+    S2: Data<Elem = A>,
+    S3: Data<Elem = A>,
+    D: Dimension,
+{
+    let xp = xp.view();
+    check_len(fp.len_of(axis), xp.len())?;
+    check_increasing(&xp)?;
+    // the interval and fraction of each point, or None for the fill value
+    let positions = x
+        .iter()
+        .map(|&x| {
+            Ok(match locate(&xp, x, extrapolate)? {
+                Position::Inside { index, x } => Some((index, fraction(&xp, index, x))),
+                Position::Fill(_) => None,
+            })
+        })
+        .collect::<Result<Vec<_>, InterpolateError>>()?;
+    let fill = match extrapolate {
+        Extrapolate::Fill(value) => value,
+        _ => A::zero(),
+    };
+    let mut dim = fp.raw_dim();
+    dim[axis.index()] = x.len();
+    let mut out = Array::zeros(dim);
+    for (mut out, fp) in out.lanes_mut(axis).into_iter().zip(fp.lanes(axis)) {
+        for (out, position) in out.iter_mut().zip(&positions) {
+            *out = match *position {
+                Some((index, t)) => lerp(&fp, index, t),
+                None => fill,
+            };
+        }
+    }
+    Ok(out)
+}
@@ -0,0 +1,191 @@
+// Copyright 2019 bluss and ndarray developers.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use crate::error::{from_kind, ErrorKind};
+use crate::imp_prelude::*;
+use crate::NdFloat;
+
+use super::error::{check_len, InterpolateError};
+use super::{check_increasing, locate, Extrapolate, Position};
+
+/// Interpolation with a cubic spline.
+///
+/// The spline is a piecewise cubic polynomial through the data points, with
+/// continuous first and second derivatives. Its end conditions are either
+///
+/// - *natural*: zero second derivative at both ends, or
+/// - *clamped*: given first derivatives at both ends.
+///
+/// Points outside the range of the data points are handled as selected
+/// with [`.extrapolate()`](#method.extrapolate); the default is
+/// `Extrapolate::Error`.
+///
+/// ```
+/// use ndarray::arr1;
+/// use ndarray::interpolate::CubicSpline;
+///
+/// // a clamped spline reproduces a cubic polynomial exactly
+/// let f = |x: f64| x * x * x - 2. * x;
+/// let x = arr1(&[0., 0.5, 1.5, 2., 3.]);
+/// let spline = CubicSpline::clamped(&x, &x.mapv(f), -2., 25.).unwrap();
+/// assert!((spline.eval(1.2).unwrap() - f(1.2)).abs() < 1e-12);
+/// ```
+#[derive(Clone, Debug)]
+pub struct CubicSpline<A> {
+    x: Array1<A>,
+    y: Array1<A>,
+    /// The second derivatives at the data points.
+    m: Array1<A>,
+    extrapolate: Extrapolate<A>,
+}
+
+/// The end conditions of a spline.
+enum Ends<A> {
+    Natural,
+    Clamped(A, A),
+}
+
+impl<A: NdFloat> CubicSpline<A> {
+    /// Create the natural cubic spline through the data points
+    /// `(x[i], y[i])`.
+    ///
+    /// ***Errors*** if `x` and `y` have different lengths or fewer than two
+    /// elements, or if `x` is not strictly increasing.
+    pub fn natural<S1, S2>(
+        x: &ArrayBase<S1, Ix1>,
+        y: &ArrayBase<S2, Ix1>,
+    ) -> Result<Self, InterpolateError>
+    where
+        S1: Data<Elem = A>,
+        S2: Data<Elem = A>,
+    {
+        Self::build(x.to_owned(), y.to_owned(), Ends::Natural)
+    }
+
+    /// Create the cubic spline through the data points `(x[i], y[i])` with
+    /// the first derivatives `start_slope` at `x[0]` and `end_slope` at the
+    /// last point.
+    ///
+    /// ***Errors*** if `x` and `y` have different lengths or fewer than two
+    /// elements, or if `x` is not strictly increasing.
+    pub fn clamped<S1, S2>(
+        x: &ArrayBase<S1, Ix1>,
+        y: &ArrayBase<S2, Ix1>,
+        start_slope: A,
+        end_slope: A,
+    ) -> Result<Self, InterpolateError>
+    where
+        S1: Data<Elem = A>,
+        S2: Data<Elem = A>,
+    {
+        Self::build(
+            x.to_owned(),
+            y.to_owned(),
+            Ends::Clamped(start_slope, end_slope),
+        )
+    }
+
+    fn build(x: Array1<A>, y: Array1<A>, ends: Ends<A>) -> Result<Self, InterpolateError> {
+        check_len(y.len(), x.len())?;
+        if x.len() < 2 {
+            return Err(from_kind(ErrorKind::IncompatibleShape).into());
+        }
+        check_increasing(&x.view())?;
+        let n = x.len();
+        let six = A::from(6).unwrap();
+        let two = A::from(2).unwrap();
+        let h = |i: usize| x[i + 1] - x[i];
+        let slope = |i: usize| (y[i + 1] - y[i]) / h(i);
+
+        // The tridiagonal system for the second derivatives: sub, diag and
+        // super are the three diagonals and rhs the right hand side.
+        let mut sub = vec![A::zero(); n];
+        let mut diag = vec![A::one(); n];
+        let mut sup = vec![A::zero(); n];
+        let mut rhs = vec![A::zero(); n];
+        for i in 1..n - 1 {
+            sub[i] = h(i - 1);
+            diag[i] = two * (h(i - 1) + h(i));
+            sup[i] = h(i);
+            rhs[i] = six * (slope(i) - slope(i - 1));
+        }
+        if let Ends::Clamped(start, end) = ends {
+            diag[0] = two * h(0);
+            sup[0] = h(0);
+            rhs[0] = six * (slope(0) - start);
+            sub[n - 1] = h(n - 2);
+            diag[n - 1] = two * h(n - 2);
+            rhs[n - 1] = six * (end - slope(n - 2));
+        }
+
+        // Gaussian elimination without pivoting, which is stable for these
+        // diagonally dominant systems.
+        for i in 1..n {
+            let w = sub[i] / diag[i - 1];
+            diag[i] -= w * sup[i - 1];
+            let r = w * rhs[i - 1];
+            rhs[i] -= r;
+        }
+        let mut m = Array1::zeros(n);
+        m[n - 1] = rhs[n - 1] / diag[n - 1];
+        for i in (0..n - 1).rev() {
+            m[i] = (rhs[i] - sup[i] * m[i + 1]) / diag[i];
+        }
+        Ok(CubicSpline {
+            x,
+            y,
+            m,
+            extrapolate: Extrapolate::Error,
+        })
+    }
+
+    /// Set the handling of points outside the range of the data points.
+    pub fn extrapolate(mut self, extrapolate: Extrapolate<A>) -> Self {
+        self.extrapolate = extrapolate;
+        self
+    }
+
+    /// Return the second derivatives of the spline at the data points.
+    pub fn second_derivatives(&self) -> ArrayView1<'_, A> {
+        self.m.view()
+    }
+
+    /// Return the value of the spline at `x`.
+    ///
+    /// ***Errors*** if `x` is out of range and extrapolation is
+    /// `Extrapolate::Error`.
+    pub fn eval(&self, x: A) -> Result<A, InterpolateError> {
+        let (i, x) = match locate(&self.x.view(), x, self.extrapolate)? {
+            Position::Inside { index, x } => (index, x),
+            Position::Fill(value) => return Ok(value),
+        };
+        let six = A::from(6).unwrap();
+        let h = self.x[i + 1] - self.x[i];
+        let (t, u) = (x - self.x[i], self.x[i + 1] - x);
+        let (m0, m1) = (self.m[i], self.m[i + 1]);
+        Ok((m0 * u * u * u + m1 * t * t * t) / (six * h)
+            + (self.y[i] / h - m0 * h / six) * u
+            + (self.y[i + 1] / h - m1 * h / six) * t)
+    }
+
+    /// Return the values of the spline at each element of `x`.
+    ///
+    /// ***Errors*** if an element of `x` is out of range and extrapolation
+    /// is `Extrapolate::Error`.
+    pub fn eval_points<S, D>(&self, x: &ArrayBase<S, D>) -> Result<Array<A, D>, InterpolateError>
+    where
+        S: Data<Elem = A>,
+        D: Dimension,
+    {
+        let mut out = Array::zeros(x.raw_dim());
+        for (out, &x) in out.iter_mut().zip(x) {
+            *out = self.eval(x)?;
+        }
+        Ok(out)
+    }
+}
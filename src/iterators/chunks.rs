@@ -1,4 +1,5 @@
 use crate::imp_prelude::*;
+use crate::indexes::IndexPtr;
 use crate::ElementsBase;
 use crate::ElementsBaseMut;
 use crate::IntoDimension;
@@ -229,3 +230,261 @@ pub struct ExactChunksIterMut<'a, A, D> {
     chunk: D,
     inner_strides: D,
 }
+
+/// Return the number of chunks of size `chunk` along each axis of `dim`,
+/// counting partial chunks at the ends.
+// `div_ceil` is newer than the minimum supported Rust version
+#[allow(clippy::manual_div_ceil)]
+fn chunk_grid<D: Dimension>(dim: &D, chunk: &D) -> D {
+    let mut grid = dim.clone();
+    for (g, &c) in grid.slice_mut().iter_mut().zip(chunk.slice()) {
+        *g = (*g + c - 1) / c;
+    }
+    grid
+}
+
+/// Return the pointer offset and the shape of the chunk at `index` in the
+/// grid of chunks of size `chunk`, of an array with shape `dim` and
+/// `strides`.
+fn chunk_at<D: Dimension>(dim: &D, strides: &D, chunk: &D, index: &D) -> (isize, D) {
+    let mut shape = chunk.clone();
+    let mut offset = 0;
+    for i in 0..dim.ndim() {
+        let start = index[i] * chunk[i];
+        shape[i] = usize::min(chunk[i], dim[i] - start);
+        offset += start as isize * strides[i] as isize;
+    }
+    (offset, shape)
+}
+
+/// Check the chunk size for an array of shape `dim`.
+fn check_chunk<D: Dimension>(dim: &D, chunk: &D) {
+    ndassert!(
+        dim.ndim() == chunk.ndim(),
+        concat!(
+            "Chunk dimension {} does not match array dimension {} ",
+            "(with array of shape {:?})"
+        ),
+        chunk.ndim(),
+        dim.ndim(),
+        dim.slice()
+    );
+    assert!(
+        chunk.slice().iter().all(|&c| c != 0),
+        "chunk size must not be zero!"
+    );
+}
+
+macro_rules! chunks_producer {
+    ($name:ident, $iter_name:ident, $view:ident, $doc_method:expr) => {
+        impl<'a, A, D: Dimension> $name<'a, A, D> {
+            /// Creates a new chunks producer.
+            ///
+            /// **Panics** if any chunk dimension is zero
+            pub(crate) fn new<E>(a: $view<'a, A, D>, chunk: E) -> Self
+            where
+                E: IntoDimension<Dim = D>,
+            {
+                let chunk = chunk.into_dimension();
+                check_chunk(&a.dim, &chunk);
+                $name { base: a, chunk }
+            }
+
+            /// Return the number of chunks along each axis.
+            pub(crate) fn grid(&self) -> D {
+                chunk_grid(&self.base.dim, &self.chunk)
+            }
+
+            /// Split into the chunks before and from `index` along `axis`.
+            pub(crate) fn split_chunks(self, axis: Axis, index: usize) -> (Self, Self) {
+                let mid = usize::min(index * self.chunk[axis.index()], self.base.len_of(axis));
+                let (a, b) = self.base.split_at(axis, mid);
+                (
+                    $name {
+                        base: a,
+                        chunk: self.chunk.clone(),
+                    },
+                    $name {
+                        base: b,
+                        chunk: self.chunk,
+                    },
+                )
+            }
+
+            unsafe fn chunk(&self, index: &D) -> $view<'a, A, D> {
+                let (offset, shape) =
+                    chunk_at(&self.base.dim, &self.base.strides, &self.chunk, index);
+                $view::new_(
+                    self.base.ptr.offset(offset),
+                    shape,
+                    self.base.strides.clone(),
+                )
+            }
+        }
+
+        impl<'a, A, D> NdProducer for $name<'a, A, D>
+        where
+            D: Dimension + Copy,
+        {
+            type Item = $view<'a, A, D>;
+            type Dim = D;
+            type Ptr = IndexPtr<D>;
+            type Stride = usize;
+
+            private_impl! {}
+
+            #[doc(hidden)]
+            fn raw_dim(&self) -> D {
+                self.grid()
+            }
+
+            #[doc(hidden)]
+            fn layout(&self) -> Layout {
+                if self.base.ndim() <= 1 {
+                    Layout::one_dimensional()
+                } else {
+                    Layout::none()
+                }
+            }
+
+            #[doc(hidden)]
+            fn as_ptr(&self) -> IndexPtr<D> {
+                IndexPtr {
+                    index: D::zeros(self.base.ndim()),
+                }
+            }
+
+            #[doc(hidden)]
+            unsafe fn as_ref(&self, ptr: IndexPtr<D>) -> Self::Item {
+                self.chunk(&ptr.index)
+            }
+
+            #[doc(hidden)]
+            unsafe fn uget_ptr(&self, i: &D) -> IndexPtr<D> {
+                IndexPtr { index: *i }
+            }
+
+            #[doc(hidden)]
+            fn stride_of(&self, axis: Axis) -> usize {
+                axis.index()
+            }
+
+            #[doc(hidden)]
+            fn contiguous_stride(&self) -> usize {
+                0
+            }
+
+            #[doc(hidden)]
+            fn split_at(self, axis: Axis, index: usize) -> (Self, Self) {
+                self.split_chunks(axis, index)
+            }
+        }
+
+        impl<'a, A, D> IntoIterator for $name<'a, A, D>
+        where
+            D: Dimension,
+            A: 'a,
+        {
+            type Item = $view<'a, A, D>;
+            type IntoIter = $iter_name<'a, A, D>;
+            fn into_iter(self) -> Self::IntoIter {
+                let grid = self.grid();
+                $iter_name {
+                    index: grid.first_index(),
+                    grid,
+                    chunks: self,
+                }
+            }
+        }
+
+        #[doc = "Chunks iterator."]
+        #[doc = ""]
+        #[doc = $doc_method]
+        #[doc = "for more information."]
+        pub struct $iter_name<'a, A, D> {
+            chunks: $name<'a, A, D>,
+            grid: D,
+            index: Option<D>,
+        }
+
+        impl<'a, A, D> Iterator for $iter_name<'a, A, D>
+        where
+            D: Dimension,
+            A: 'a,
+        {
+            type Item = $view<'a, A, D>;
+
+            fn next(&mut self) -> Option<Self::Item> {
+                let index = self.index.take()?;
+                let chunk = unsafe { self.chunks.chunk(&index) };
+                self.index = self.grid.next_for(index);
+                Some(chunk)
+            }
+
+            fn size_hint(&self) -> (usize, Option<usize>) {
+                let len = match self.index {
+                    None => 0,
+                    Some(ref index) => {
+                        let gone = self
+                            .grid
+                            .default_strides()
+                            .slice()
+                            .iter()
+                            .zip(index.slice())
+                            .fold(0, |s, (&a, &b)| s + a * b);
+                        self.grid.size() - gone
+                    }
+                };
+                (len, Some(len))
+            }
+        }
+
+        impl<'a, A, D> ExactSizeIterator for $iter_name<'a, A, D>
+        where
+            D: Dimension,
+            A: 'a,
+        {
+        }
+    };
+}
+
+/// Chunks producer and iterable.
+///
+/// See [`.chunks()`](../struct.ArrayBase.html#method.chunks) for more
+/// information.
+pub struct Chunks<'a, A, D> {
+    base: BaseProducerRef<'a, A, D>,
+    chunk: D,
+}
+
+impl<'a, A, D: Clone> Clone for Chunks<'a, A, D> {
+    fn clone(&self) -> Self {
+        Chunks {
+            base: self.base.clone(),
+            chunk: self.chunk.clone(),
+        }
+    }
+}
+
+chunks_producer!(
+    Chunks,
+    ChunksIter,
+    ArrayView,
+    "See [`.chunks()`](../struct.ArrayBase.html#method.chunks)"
+);
+
+/// Chunks producer and iterable.
+///
+/// See [`.chunks_mut()`](../struct.ArrayBase.html#method.chunks_mut) for
+/// more information.
+pub struct ChunksMut<'a, A, D> {
+    base: BaseProducerMut<'a, A, D>,
+    chunk: D,
+}
+
+chunks_producer!(
+    ChunksMut,
+    ChunksIterMut,
+    ArrayViewMut,
+    "See [`.chunks_mut()`](../struct.ArrayBase.html#method.chunks_mut)"
+);
@@ -22,9 +22,12 @@ use crate::Ix1;
 use super::{ArrayBase, ArrayView, ArrayViewMut, Axis, Data, NdProducer, RemoveAxis};
 use super::{Dimension, Ix, Ixs};
 
-pub use self::chunks::{ExactChunks, ExactChunksIter, ExactChunksIterMut, ExactChunksMut};
+pub use self::chunks::{
+    Chunks, ChunksIter, ChunksIterMut, ChunksMut, ExactChunks, ExactChunksIter, ExactChunksIterMut,
+    ExactChunksMut,
+};
 pub use self::lanes::{Lanes, LanesMut};
-pub use self::windows::Windows;
+pub use self::windows::{AxisWindows, Windows};
 
 use std::slice::{self, Iter as SliceIter, IterMut as SliceIterMut};
 
@@ -20,6 +20,25 @@ impl<'a, A, D: Dimension> Windows<'a, A, D> {
         E: IntoDimension<Dim = D>,
     {
         let window = window_size.into_dimension();
+        let mut ones = window.clone();
+        for s in ones.slice_mut() {
+            *s = 1;
+        }
+        Self::new_with_stride_dilation(a, window, ones.clone(), ones)
+    }
+
+    pub(crate) fn new_with_stride_dilation<E>(
+        a: ArrayView<'a, A, D>,
+        window_size: E,
+        stride: E,
+        dilation: E,
+    ) -> Self
+    where
+        E: IntoDimension<Dim = D>,
+    {
+        let window = window_size.into_dimension();
+        let stride = stride.into_dimension();
+        let dilation = dilation.into_dimension();
         ndassert!(
             a.ndim() == window.ndim(),
             concat!(
@@ -30,18 +49,36 @@ impl<'a, A, D: Dimension> Windows<'a, A, D> {
             a.ndim(),
             a.shape()
         );
-        let mut size = a.dim;
-        for (sz, &ws) in size.slice_mut().iter_mut().zip(window.slice()) {
+        ndassert!(
+            a.ndim() == stride.ndim() && a.ndim() == dilation.ndim(),
+            "Window stride dimension {} or dilation dimension {} does not match array dimension {}",
+            stride.ndim(),
+            dilation.ndim(),
+            a.ndim()
+        );
+        let mut size = a.dim.clone();
+        let mut base_strides = a.strides.clone();
+        let mut window_strides = a.strides.clone();
+        for i in 0..a.ndim() {
+            let (ws, st, dl) = (window[i], stride[i], dilation[i]);
             assert_ne!(ws, 0, "window-size must not be zero!");
+            assert_ne!(st, 0, "window stride must not be zero!");
+            assert_ne!(dl, 0, "window dilation must not be zero!");
+            // the extent of a window along the axis
+            let extent = (ws - 1) * dl + 1;
             // cannot use std::cmp::max(0, ..) since arithmetic underflow panics
-            *sz = if *sz < ws { 0 } else { *sz - ws + 1 };
+            size[i] = if size[i] < extent {
+                0
+            } else {
+                (size[i] - extent) / st + 1
+            };
+            base_strides[i] = (a.strides[i] as isize * st as isize) as usize;
+            window_strides[i] = (a.strides[i] as isize * dl as isize) as usize;
         }
 
-        let window_strides = a.strides.clone();
-
         unsafe {
             Windows {
-                base: ArrayView::from_shape_ptr(size.clone().strides(a.strides), a.ptr),
+                base: ArrayView::from_shape_ptr(size.strides(base_strides), a.ptr),
                 window,
                 strides: window_strides,
             }
@@ -115,3 +152,155 @@ impl_iterator! {
         }
     }
 }
+
+/// Window producer and iterable, for windows that slide along one axis.
+///
+/// See [`.axis_windows()`](../struct.ArrayBase.html#method.axis_windows) for
+/// more information.
+pub struct AxisWindows<'a, A, D> {
+    base: ArrayView<'a, A, Ix1>,
+    window: D,
+    strides: D,
+}
+
+impl<'a, A, D: Dimension> AxisWindows<'a, A, D> {
+    pub(crate) fn new(a: ArrayView<'a, A, D>, axis: Axis, window_size: usize) -> Self {
+        assert_ne!(window_size, 0, "window-size must not be zero!");
+        let len = a.len_of(axis);
+        let n = if len < window_size {
+            0
+        } else {
+            len - window_size + 1
+        };
+        let mut window = a.dim.clone();
+        window[axis.index()] = window_size;
+        let stride = a.strides[axis.index()];
+
+        unsafe {
+            AxisWindows {
+                base: ArrayView::from_shape_ptr(Ix1(n).strides(Ix1(stride)), a.ptr),
+                window,
+                strides: a.strides,
+            }
+        }
+    }
+}
+
+impl<'a, A, D: Dimension> NdProducer for AxisWindows<'a, A, D> {
+    type Item = ArrayView<'a, A, D>;
+    type Dim = Ix1;
+    type Ptr = *mut A;
+    type Stride = isize;
+
+    #[doc(hidden)]
+    fn raw_dim(&self) -> Ix1 {
+        self.base.raw_dim()
+    }
+
+    #[doc(hidden)]
+    fn layout(&self) -> Layout {
+        self.base.layout()
+    }
+
+    #[doc(hidden)]
+    fn as_ptr(&self) -> *mut A {
+        self.base.as_ptr() as *mut _
+    }
+
+    #[doc(hidden)]
+    fn contiguous_stride(&self) -> isize {
+        self.base.contiguous_stride()
+    }
+
+    #[doc(hidden)]
+    unsafe fn as_ref(&self, ptr: *mut A) -> Self::Item {
+        ArrayView::new_(ptr, self.window.clone(), self.strides.clone())
+    }
+
+    #[doc(hidden)]
+    unsafe fn uget_ptr(&self, i: &Ix1) -> *mut A {
+        self.base.uget_ptr(i)
+    }
+
+    #[doc(hidden)]
+    fn stride_of(&self, axis: Axis) -> isize {
+        self.base.stride_of(axis)
+    }
+
+    #[doc(hidden)]
+    fn split_at(self, axis: Axis, index: usize) -> (Self, Self) {
+        let (a, b) = self.base.split_at(axis, index);
+        (
+            AxisWindows {
+                base: a,
+                window: self.window.clone(),
+                strides: self.strides.clone(),
+            },
+            AxisWindows {
+                base: b,
+                window: self.window,
+                strides: self.strides,
+            },
+        )
+    }
+
+    private_impl! {}
+}
+
+impl<'a, A, D: Clone> Clone for AxisWindows<'a, A, D> {
+    fn clone(&self) -> Self {
+        AxisWindows {
+            base: self.base,
+            window: self.window.clone(),
+            strides: self.strides.clone(),
+        }
+    }
+}
+
+impl<'a, A, D> IntoIterator for AxisWindows<'a, A, D>
+where
+    D: Dimension,
+    A: 'a,
+{
+    type Item = <Self::IntoIter as Iterator>::Item;
+    type IntoIter = AxisWindowsIter<'a, A, D>;
+    fn into_iter(self) -> Self::IntoIter {
+        AxisWindowsIter {
+            iter: self.base.into_elements_base(),
+            window: self.window,
+            strides: self.strides,
+        }
+    }
+}
+
+/// Window iterator, for windows that slide along one axis.
+///
+/// See [`.axis_windows()`](../struct.ArrayBase.html#method.axis_windows) for
+/// more information.
+pub struct AxisWindowsIter<'a, A, D> {
+    iter: ElementsBase<'a, A, Ix1>,
+    window: D,
+    strides: D,
+}
+
+impl_iterator! {
+    ['a, A, D: Dimension]
+    [Clone => 'a, A, D: Clone]
+    AxisWindowsIter {
+        iter,
+        window,
+        strides,
+    }
+    AxisWindowsIter<'a, A, D> {
+        type Item = ArrayView<'a, A, D>;
+
+        fn item(&mut self, elt) {
+            unsafe {
+                ArrayView::new_(
+                    elt,
+                    self.window.clone(),
+                    self.strides.clone())
+            }
+        }
+    }
+}
@@ -122,8 +122,10 @@ use crate::iterators::Baseiter;
 use crate::iterators::{ElementsBase, ElementsBaseMut, Iter, IterMut, Lanes, LanesMut};
 
 pub use crate::arraytraits::AsArray;
-pub use crate::linalg_traits::{LinalgScalar, NdFloat};
+pub use crate::linalg_traits::{ComplexFloat, LinalgScalar, NdFloat};
 pub use crate::stacking::stack;
+#[doc(hidden)]
+pub use crate::stacking::{block_from_grid, BlockGrid};
 
 pub use crate::impl_views::IndexLonger;
 pub use crate::shape_builder::ShapeBuilder;
@@ -1584,8 +1586,22 @@ mod impl_dyn;
 
 mod numeric;
 
+pub mod convolution;
+
+pub mod fft;
+
+pub mod integrate;
+
+pub mod interpolate;
+
 pub mod linalg;
 
+pub mod ode;
+
+pub mod polynomial;
+
+pub mod sparse;
+
 mod impl_ops;
 pub use crate::impl_ops::ScalarOperand;
 
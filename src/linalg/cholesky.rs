@@ -0,0 +1,184 @@
+// Copyright 2019 bluss and ndarray developers.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use crate::imp_prelude::*;
+use crate::linalg::error::{check_len, check_square, LinalgError};
+use crate::linalg::general_mat_mul;
+use crate::linalg::triangular::{solve_lower_inplace, solve_upper_inplace, UPLO};
+use crate::linalg::util::{conj_t, rhs_from_matrix, rhs_matrix};
+use crate::ComplexFloat;
+
+use num_traits::{Float, Zero};
+
+/// Number of columns in each panel of the blocked factorization.
+const CHOLESKY_BLOCK: usize = 32;
+
+/// Overwrite the lower triangle of `a` with its Cholesky factor `L`, where
+/// `A = L Lᴴ`; the strict upper triangle is set to zero.
+fn factorize_lower<A>(a: &mut Array2<A>) -> Result<(), LinalgError>
+where
+    A: ComplexFloat,
+{
+    let n = a.rows();
+    let mut k0 = 0;
+    while k0 < n {
+        let kb = CHOLESKY_BLOCK.min(n - k0);
+        factorize_block(a.slice_mut(s![k0..k0 + kb, k0..k0 + kb]), k0)?;
+        if k0 + kb < n {
+            let (top, mut bottom) = a.slice_mut(s![k0.., k0..]).split_at(Axis(0), kb);
+            let l11 = top.slice(s![.., ..kb]);
+            let (mut l21, mut a22) = bottom.view_mut().split_at(Axis(1), kb);
+            // L21 ← A21 L11⁻ᴴ
+            let mut x = conj_t(&l21);
+            solve_lower_inplace(&l11, x.view_mut(), false);
+            l21.assign(&conj_t(&x));
+            // A22 ← A22 - L21 L21ᴴ
+            general_mat_mul(-A::one(), &l21, &x, A::one(), &mut a22);
+        }
+        k0 += kb;
+    }
+    for ((i, j), x) in a.indexed_iter_mut() {
+        if j > i {
+            *x = A::zero();
+        }
+    }
+    Ok(())
+}
+
+/// Unblocked factorization of the diagonal block `a`, whose first row is row
+/// `offset` of the whole matrix.
+fn factorize_block<A>(mut a: ArrayViewMut2<'_, A>, offset: usize) -> Result<(), LinalgError>
+where
+    A: ComplexFloat,
+{
+    let n = a.rows();
+    for j in 0..n {
+        let d = a[(j, j)].re();
+        if d.is_nan() || d <= A::Real::zero() {
+            return Err(LinalgError::NotPositiveDefinite {
+                minor: offset + j + 1,
+            });
+        }
+        let l = Float::sqrt(d);
+        a[(j, j)] = A::from_real(l);
+        for i in j + 1..n {
+            a[(i, j)] = a[(i, j)].scale(l.recip());
+        }
+        for c in j + 1..n {
+            let ljc = a[(c, j)].conj();
+            for i in c..n {
+                let lij = a[(i, j)];
+                a[(i, c)] -= lij * ljc;
+            }
+        }
+    }
+    Ok(())
+}
+
+/// # Cholesky Factorization and Solvers
+impl<A, S> ArrayBase<S, Ix2>
+where
+    S: Data<Elem = A>,
+    A: ComplexFloat,
+{
+    /// Compute the Cholesky factorization of the symmetric (Hermitian, for
+    /// complex elements) positive definite matrix `self`.
+    ///
+    /// Only the `uplo` triangle of `self` is read. Return the lower triangular
+    /// `L` with `A = L Lᴴ` for `UPLO::Lower`, and the upper triangular `U`
+    /// with `A = Uᴴ U` for `UPLO::Upper`; the other triangle of the result is
+    /// zero.
+    ///
+    /// ***Errors*** if `self` is not square, and with
+    /// `LinalgError::NotPositiveDefinite` if it is not positive definite;
+    /// the error holds the order of the first leading minor that is not.
+    ///
+    /// ```
+    /// use ndarray::arr2;
+    /// use ndarray::linalg::{LinalgError, UPLO};
+    ///
+    /// let a = arr2(&[[4., 2.],
+    ///                [2., 5.]]);
+    /// let l = a.cholesky(UPLO::Lower).unwrap();
+    /// assert_eq!(l, arr2(&[[2., 0.],
+    ///                      [1., 2.]]));
+    /// assert_eq!(l.dot(&l.t()), a);
+    ///
+    /// let b = arr2(&[[1., 2.],
+    ///                [2., 1.]]);
+    /// assert_eq!(b.cholesky(UPLO::Lower),
+    ///            Err(LinalgError::NotPositiveDefinite { minor: 2 }));
+    /// ```
+    pub fn cholesky(&self, uplo: UPLO) -> Result<Array2<A>, LinalgError> {
+        let l = self.cholesky_lower(uplo)?;
+        Ok(match uplo {
+            UPLO::Lower => l,
+            UPLO::Upper => conj_t(&l),
+        })
+    }
+
+    /// Solve `self x = b` where `self` is symmetric (Hermitian) positive
+    /// definite, using its Cholesky factorization.
+    ///
+    /// Only the `uplo` triangle of `self` is read. `b` may be one-dimensional
+    /// or two-dimensional; in the latter case each column is a separate right
+    /// hand side.
+    ///
+    /// ***Errors*** as [`.cholesky()`](#method.cholesky), or if `b` is not
+    /// one- or two-dimensional or its number of rows does not match.
+    pub fn solve_cholesky<S2, D>(
+        &self,
+        uplo: UPLO,
+        b: &ArrayBase<S2, D>,
+    ) -> Result<Array<A, D>, LinalgError>
+    where
+        S2: Data<Elem = A>,
+        D: Dimension,
+    {
+        let ndim = b.ndim();
+        let b = rhs_matrix(b)?;
+        check_len(self.rows(), b.rows())?;
+        let l = self.cholesky_lower(uplo)?;
+        let mut x = b.to_owned();
+        solve_cholesky_inplace(&l, x.view_mut());
+        rhs_from_matrix(x, ndim)
+    }
+
+    /// Return the inverse of the symmetric (Hermitian) positive definite
+    /// matrix `self`, using its Cholesky factorization.
+    ///
+    /// Only the `uplo` triangle of `self` is read.
+    ///
+    /// ***Errors*** as [`.cholesky()`](#method.cholesky).
+    pub fn inv_cholesky(&self, uplo: UPLO) -> Result<Array2<A>, LinalgError> {
+        let l = self.cholesky_lower(uplo)?;
+        let mut x = Array2::eye(l.rows());
+        solve_cholesky_inplace(&l, x.view_mut());
+        Ok(x)
+    }
+
+    /// Return the lower Cholesky factor, reading the `uplo` triangle.
+    fn cholesky_lower(&self, uplo: UPLO) -> Result<Array2<A>, LinalgError> {
+        check_square(self.dim())?;
+        let mut l = match uplo {
+            UPLO::Lower => self.to_owned(),
+            UPLO::Upper => conj_t(self),
+        };
+        factorize_lower(&mut l)?;
+        Ok(l)
+    }
+}
+
+/// B ← (L Lᴴ)⁻¹ B
+fn solve_cholesky_inplace<A>(l: &Array2<A>, mut b: ArrayViewMut2<'_, A>)
+where
+    A: ComplexFloat,
+{
+    solve_lower_inplace(&l.view(), b.view_mut(), false);
+    solve_upper_inplace(&conj_t(l).view(), b, false);
+}
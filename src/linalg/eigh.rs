@@ -0,0 +1,207 @@
+// Copyright 2019 bluss and ndarray developers.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use crate::imp_prelude::*;
+use crate::linalg::error::{check_square, LinalgError};
+use crate::linalg::householder::{apply_left, apply_right, reflector, vector};
+use crate::linalg::triangular::UPLO;
+use crate::{ComplexFloat, Zip};
+
+use num_traits::{Float, One, Zero};
+
+/// Maximum number of QL iterations for each eigenvalue.
+const MAX_ITER: usize = 30;
+
+/// # Symmetric Eigendecomposition
+impl<A, S> ArrayBase<S, Ix2>
+where
+    S: Data<Elem = A>,
+    A: ComplexFloat,
+{
+    /// Compute the eigenvalues and eigenvectors of the symmetric (Hermitian,
+    /// for complex elements) matrix `self`.
+    ///
+    /// Only the `uplo` triangle of `self` is read; the imaginary parts of the
+    /// diagonal are taken to be zero.
+    ///
+    /// Return `(w, v)` where the eigenvalues `w` are real and in ascending
+    /// order, and column `i` of the unitary matrix `v` is the eigenvector of
+    /// `w[i]`, so that `self v = v diag(w)`.
+    ///
+    /// The matrix is reduced to tridiagonal form with Householder reflectors,
+    /// which is then diagonalized with the implicit QL algorithm.
+    ///
+    /// ***Errors*** if `self` is not square, or with
+    /// `LinalgError::NotConverged` if the iteration does not converge.
+    ///
+    /// ```
+    /// use ndarray::{arr1, arr2};
+    /// use ndarray::linalg::UPLO;
+    ///
+    /// let a = arr2(&[[2f64, 1.],
+    ///                [1.,   2.]]);
+    /// let (w, v) = a.eigh(UPLO::Lower).unwrap();
+    /// assert!((w - arr1(&[1., 3.])).iter().all(|&x: &f64| x.abs() < 1e-12));
+    /// assert!((a.dot(&v.column(1)) - 3. * &v.column(1)).iter().all(|&x: &f64| x.abs() < 1e-12));
+    /// ```
+    pub fn eigh(&self, uplo: UPLO) -> Result<(Array1<A::Real>, Array2<A>), LinalgError> {
+        let n = check_square(self.dim())?;
+        let mut a = Array2::from_shape_fn((n, n), |(i, j)| {
+            let stored = match uplo {
+                UPLO::Lower => i >= j,
+                UPLO::Upper => i <= j,
+            };
+            if i == j {
+                A::from_real(self[(i, i)].re())
+            } else if stored {
+                self[(i, j)]
+            } else {
+                self[(j, i)].conj()
+            }
+        });
+        let mut q = Array2::eye(n);
+        let mut e = tridiagonalize(&mut a, &mut q);
+        let mut d = a.diag().mapv(A::re);
+        tql(&mut d, &mut e, &mut q)?;
+        if d.iter().any(|x| x.is_nan()) {
+            return Err(LinalgError::NotConverged);
+        }
+
+        let mut order = (0..n).collect::<Vec<_>>();
+        order.sort_by(|&i, &j| d[i].partial_cmp(&d[j]).unwrap());
+        let w = order.iter().map(|&i| d[i]).collect();
+        let v = q.select(Axis(1), &order);
+        Ok((w, v))
+    }
+}
+
+/// Reduce the Hermitian matrix `a` to real tridiagonal form `T = Qᴴ A Q`,
+/// and multiply `q` by `Q` from the right.
+///
+/// The diagonal of `T` is left in the diagonal of `a`; return its
+/// subdiagonal, extended with a trailing zero.
+fn tridiagonalize<A>(a: &mut Array2<A>, q: &mut Array2<A>) -> Array1<A::Real>
+where
+    A: ComplexFloat,
+{
+    let n = a.rows();
+    let mut e = Array1::zeros(n);
+    for k in 0..n.saturating_sub(1) {
+        let alpha = a[(k + 1, k)];
+        let (beta, tau) = reflector(alpha, a.slice_mut(s![k + 2.., k]));
+        e[k] = beta.re();
+        let v = vector(&a.slice(s![k + 2.., k]));
+        let mut a22 = a.slice_mut(s![k + 1.., k + 1..]);
+        apply_left(&v.view(), tau.conj(), a22.view_mut());
+        apply_right(&v.view(), tau, a22);
+        apply_right(&v.view(), tau, q.slice_mut(s![.., k + 1..]));
+    }
+    e
+}
+
+/// Diagonalize the symmetric tridiagonal matrix with diagonal `d` and
+/// subdiagonal `e` (whose last element is ignored) using the implicit QL
+/// algorithm, applying the rotations to the columns of `z`.
+///
+/// On return `d` holds the eigenvalues, in no particular order.
+fn tql<A>(
+    d: &mut Array1<A::Real>,
+    e: &mut Array1<A::Real>,
+    z: &mut Array2<A>,
+) -> Result<(), LinalgError>
+where
+    A: ComplexFloat,
+{
+    let n = d.len();
+    if n == 0 {
+        return Ok(());
+    }
+    let zero = A::Real::zero();
+    let one = A::Real::one();
+    let two = one + one;
+    let eps = A::Real::epsilon();
+    e[n - 1] = zero;
+    let mut f = zero;
+    let mut tst1 = zero;
+    for l in 0..n {
+        tst1 = Float::max(tst1, Float::abs(d[l]) + Float::abs(e[l]));
+        let mut m = l;
+        while Float::abs(e[m]) > eps * tst1 {
+            m += 1;
+        }
+        if m > l {
+            let mut iter = 0;
+            loop {
+                iter += 1;
+                if iter > MAX_ITER {
+                    return Err(LinalgError::NotConverged);
+                }
+                // compute the implicit shift
+                let g = d[l];
+                let mut p = (d[l + 1] - g) / (two * e[l]);
+                let mut r = Float::hypot(p, one);
+                if p < zero {
+                    r = -r;
+                }
+                d[l] = e[l] / (p + r);
+                d[l + 1] = e[l] * (p + r);
+                let dl1 = d[l + 1];
+                let h = g - d[l];
+                for i in l + 2..n {
+                    d[i] -= h;
+                }
+                f += h;
+
+                // implicit QL transformation
+                p = d[m];
+                let mut c = one;
+                let mut c2 = c;
+                let mut c3 = c;
+                let el1 = e[l + 1];
+                let mut s = zero;
+                let mut s2 = zero;
+                for i in (l..m).rev() {
+                    c3 = c2;
+                    c2 = c;
+                    s2 = s;
+                    let g = c * e[i];
+                    let h = c * p;
+                    r = Float::hypot(p, e[i]);
+                    e[i + 1] = s * r;
+                    s = e[i] / r;
+                    c = p / r;
+                    p = c * d[i] - s * g;
+                    d[i + 1] = h + s * (c * g + s * d[i]);
+                    rotate(z, i, c, s);
+                }
+                p = -s * s2 * c3 * el1 * e[l] / dl1;
+                e[l] = s * p;
+                d[l] = c * p;
+                if Float::abs(e[l]) <= eps * tst1 {
+                    break;
+                }
+            }
+        }
+        d[l] += f;
+        e[l] = zero;
+    }
+    Ok(())
+}
+
+/// Apply a plane rotation to columns `i` and `i + 1` of `z`.
+fn rotate<A>(z: &mut Array2<A>, i: usize, c: A::Real, s: A::Real)
+where
+    A: ComplexFloat,
+{
+    let (mut zi, mut zi1) = z.slice_mut(s![.., i..i + 2]).split_at(Axis(1), 1);
+    Zip::from(&mut zi).and(&mut zi1).apply(|x, y| {
+        let h = *y;
+        *y = x.scale(s) + h.scale(c);
+        *x = x.scale(c) - h.scale(s);
+    });
+}
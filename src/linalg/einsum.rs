@@ -0,0 +1,219 @@
+// Copyright 2019 bluss and ndarray developers.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use crate::error::{from_kind, ErrorKind, ShapeError};
+use crate::imp_prelude::*;
+use crate::linalg::tensordot::contract;
+use crate::{LinalgScalar, Zip};
+
+use num_traits::Zero;
+
+/// Einstein summation of `operands`, as described by `subscripts`.
+///
+/// The subscripts label the axes of each operand with a letter, for
+/// example `"ij,jk->ik"` for the matrix product. Labels that appear in the
+/// output after `->` are kept, and all other labels are summed over. If
+/// the `->` part is omitted, the output has the labels that appear exactly
+/// once, in alphabetical order. Whitespace is ignored.
+///
+/// A label repeated within one operand takes its diagonal, so `"ii->i"` is
+/// the diagonal and `"ii"` the trace of a matrix.
+///
+/// The operands are contracted pairwise from left to right. Each pairwise
+/// contraction is a (batched) matrix multiplication, as in
+/// [`tensordot`](fn.tensordot.html); diagonals are gathered with `Zip` and
+/// axes that appear in a single operand only are summed before the
+/// contraction.
+///
+/// ***Errors*** with `IncompatibleShape` if the subscripts are malformed,
+/// if their number or length does not match the operands, if an output
+/// label is repeated or does not appear in the input, or if the axes with
+/// the same label have different lengths. Broadcasting and `...` are not
+/// supported.
+///
+/// ```
+/// use ndarray::{arr1, arr2};
+/// use ndarray::linalg::einsum;
+///
+/// let a = arr2(&[[1., 2.],
+///                [3., 4.]]);
+/// let b = arr2(&[[5., 6.],
+///                [7., 8.]]);
+/// assert_eq!(einsum("ij,jk->ik", &[&a, &b]).unwrap(), a.dot(&b).into_dyn());
+/// assert_eq!(einsum("ij,ij", &[&a, &b]).unwrap()[[]], 70.);
+/// assert_eq!(einsum("ii->i", &[&a]).unwrap(), arr1(&[1., 4.]).into_dyn());
+/// assert_eq!(einsum("ji", &[&a]).unwrap(), a.t().into_dyn());
+/// ```
+pub fn einsum<A, S, D>(
+    subscripts: &str,
+    operands: &[&ArrayBase<S, D>],
+) -> Result<ArrayD<A>, ShapeError>
+where
+    S: Data<Elem = A>,
+    D: Dimension,
+    A: LinalgScalar,
+{
+    let (inputs, output) = parse(subscripts)?;
+    if inputs.len() != operands.len() {
+        return Err(from_kind(ErrorKind::IncompatibleShape));
+    }
+    let mut lens: Vec<(u8, usize)> = Vec::new();
+    for (labels, op) in inputs.iter().zip(operands) {
+        if labels.len() != op.ndim() {
+            return Err(from_kind(ErrorKind::IncompatibleShape));
+        }
+        for (&label, &len) in labels.iter().zip(op.shape()) {
+            match lens.iter().find(|&&(l, _)| l == label) {
+                Some(&(_, n)) if n != len => return Err(from_kind(ErrorKind::IncompatibleShape)),
+                Some(_) => {}
+                None => lens.push((label, len)),
+            }
+        }
+    }
+
+    let mut terms = Vec::with_capacity(operands.len());
+    for (i, (labels, op)) in inputs.iter().zip(operands).enumerate() {
+        let (mut term, mut labels) = diagonal(op.view().into_dyn(), labels);
+        let used_elsewhere = |label: u8| {
+            output.contains(&label)
+                || inputs
+                    .iter()
+                    .enumerate()
+                    .any(|(j, other)| j != i && other.contains(&label))
+        };
+        for ax in (0..labels.len()).rev() {
+            if !used_elsewhere(labels[ax]) {
+                term = term.sum_axis(Axis(ax));
+                labels.remove(ax);
+            }
+        }
+        terms.push((term, labels));
+    }
+
+    let mut terms = terms.into_iter().enumerate();
+    let (_, (mut result, mut result_labels)) = terms.next().unwrap();
+    for (i, (term, labels)) in terms {
+        let later = &inputs[i + 1..];
+        let needed =
+            |label: u8| output.contains(&label) || later.iter().any(|o| o.contains(&label));
+        let (mut batch_a, mut batch_b, mut sum_a, mut sum_b) = (vec![], vec![], vec![], vec![]);
+        for (ia, label) in result_labels.iter().enumerate() {
+            if let Some(ib) = labels.iter().position(|l| l == label) {
+                if needed(*label) {
+                    batch_a.push(ia);
+                    batch_b.push(ib);
+                } else {
+                    sum_a.push(ia);
+                    sum_b.push(ib);
+                }
+            }
+        }
+        let mut new_labels: Vec<u8> = batch_a.iter().map(|&ia| result_labels[ia]).collect();
+        new_labels.extend(
+            (0..result_labels.len())
+                .filter(|ia| !batch_a.contains(ia) && !sum_a.contains(ia))
+                .map(|ia| result_labels[ia]),
+        );
+        new_labels.extend(
+            (0..labels.len())
+                .filter(|ib| !batch_b.contains(ib) && !sum_b.contains(ib))
+                .map(|ib| labels[ib]),
+        );
+        result = contract(
+            result.view(),
+            &batch_a,
+            &sum_a,
+            term.view(),
+            &batch_b,
+            &sum_b,
+        );
+        result_labels = new_labels;
+    }
+
+    let order: Vec<usize> = output
+        .iter()
+        .map(|label| result_labels.iter().position(|l| l == label).unwrap())
+        .collect();
+    Ok(result.permuted_axes(order))
+}
+
+/// Parse `subscripts` into the labels of each input and of the output.
+fn parse(subscripts: &str) -> Result<(Vec<Vec<u8>>, Vec<u8>), ShapeError> {
+    let s: String = subscripts.chars().filter(|c| !c.is_whitespace()).collect();
+    let (lhs, rhs) = match s.find("->") {
+        Some(i) => (&s[..i], Some(&s[i + 2..])),
+        None => (&s[..], None),
+    };
+    let labels = |t: &str| {
+        if t.bytes().all(|c| c.is_ascii_alphabetic()) {
+            Ok(t.bytes().collect::<Vec<_>>())
+        } else {
+            Err(from_kind(ErrorKind::IncompatibleShape))
+        }
+    };
+    let inputs = lhs.split(',').map(labels).collect::<Result<Vec<_>, _>>()?;
+    let count = |label: u8| inputs.iter().flatten().filter(|&&l| l == label).count();
+    let output = match rhs {
+        Some(rhs) => {
+            let output = labels(rhs)?;
+            for (i, label) in output.iter().enumerate() {
+                if count(*label) == 0 || output[..i].contains(label) {
+                    return Err(from_kind(ErrorKind::IncompatibleShape));
+                }
+            }
+            output
+        }
+        None => {
+            let mut output: Vec<u8> = inputs
+                .iter()
+                .flatten()
+                .cloned()
+                .filter(|&l| count(l) == 1)
+                .collect();
+            output.sort();
+            output
+        }
+    };
+    Ok((inputs, output))
+}
+
+/// Return the diagonal of `a` along the axes with the same label, and the
+/// distinct labels, in order of their first appearance.
+fn diagonal<A>(a: ArrayViewD<'_, A>, labels: &[u8]) -> (ArrayD<A>, Vec<u8>)
+where
+    A: Clone + Zero,
+{
+    let mut unique = Vec::with_capacity(labels.len());
+    for &label in labels {
+        if !unique.contains(&label) {
+            unique.push(label);
+        }
+    }
+    if unique.len() == labels.len() {
+        return (a.to_owned(), unique);
+    }
+    let shape: Vec<usize> = unique
+        .iter()
+        .map(|u| a.len_of(Axis(labels.iter().position(|l| l == u).unwrap())))
+        .collect();
+    // the axes with the same label are merged into one axis, stepping along
+    // all of them at once
+    let mut strides = IxDyn::zeros(unique.len());
+    for (label, &stride) in labels.iter().zip(a.strides()) {
+        let p = unique.iter().position(|u| u == label).unwrap();
+        strides[p] = strides[p].wrapping_add(stride as usize);
+    }
+    // safe because the lengths of axes with the same label are equal, so
+    // that the view covers a subset of the elements of `a`
+    let diag_view = unsafe { ArrayView::new_(a.as_ptr(), IxDyn(&shape), strides) };
+    let mut diag = ArrayD::zeros(shape);
+    Zip::from(&mut diag)
+        .and(&diag_view)
+        .apply(|x, y| *x = y.clone());
+    (diag, unique)
+}
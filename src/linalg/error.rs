@@ -0,0 +1,102 @@
+// Copyright 2019 bluss and ndarray developers.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+use crate::error::{from_kind, ErrorKind, ShapeError};
+use std::error::Error;
+use std::fmt;
+
+/// An error from a matrix factorization or solver.
+///
+/// This enumeration is not exhaustive.
+#[derive(Clone, Debug, PartialEq)]
+pub enum LinalgError {
+    /// the matrix is required to be square
+    NotSquare {
+        /// number of rows
+        rows: usize,
+        /// number of columns
+        cols: usize,
+    },
+    /// the matrix is singular (to working precision)
+    Singular,
+    /// the matrix is not positive definite
+    NotPositiveDefinite {
+        /// order of the first leading minor that is not positive definite
+        minor: usize,
+    },
+    /// an iterative algorithm did not converge
+    NotConverged,
+    /// the operands have incompatible shapes
+    Shape(ShapeError),
+    #[doc(hidden)]
+    __Incomplete,
+}
+
+impl LinalgError {
+    fn message(&self) -> &'static str {
+        match *self {
+            LinalgError::NotSquare { .. } => "the matrix is not square",
+            LinalgError::Singular => "the matrix is singular",
+            LinalgError::NotPositiveDefinite { .. } => "the matrix is not positive definite",
+            LinalgError::NotConverged => "the algorithm did not converge",
+            LinalgError::Shape(_) => "incompatible shapes",
+            LinalgError::__Incomplete => "this error variant is not in use",
+        }
+    }
+}
+
+impl Error for LinalgError {
+    fn description(&self) -> &str {
+        self.message()
+    }
+}
+
+impl fmt::Display for LinalgError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            LinalgError::NotSquare { rows, cols } => {
+                write!(
+                    f,
+                    "LinalgError: the matrix is not square ({} × {})",
+                    rows, cols
+                )
+            }
+            LinalgError::NotPositiveDefinite { minor } => write!(
+                f,
+                "LinalgError: the matrix is not positive definite \
+                 (leading minor of order {})",
+                minor
+            ),
+            LinalgError::Shape(ref e) => write!(f, "LinalgError: {}", e),
+            _ => write!(f, "LinalgError: {}", self.message()),
+        }
+    }
+}
+
+impl From<ShapeError> for LinalgError {
+    fn from(e: ShapeError) -> Self {
+        LinalgError::Shape(e)
+    }
+}
+
+/// Return `Ok(n)` if the matrix of shape `(rows, cols)` is square.
+pub(crate) fn check_square((rows, cols): (usize, usize)) -> Result<usize, LinalgError> {
+    if rows == cols {
+        Ok(rows)
+    } else {
+        Err(LinalgError::NotSquare { rows, cols })
+    }
+}
+
+/// Return an error if the lengths `a` and `b` differ.
+pub(crate) fn check_len(a: usize, b: usize) -> Result<(), LinalgError> {
+    if a == b {
+        Ok(())
+    } else {
+        Err(from_kind(ErrorKind::IncompatibleShape).into())
+    }
+}
@@ -0,0 +1,93 @@
+// Copyright 2019 bluss and ndarray developers.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Elementary (Householder) reflectors.
+//!
+//! A reflector is `H = I - τ v vᴴ` where `v[0] == 1`. Its vector is stored
+//! without the leading one, usually in the part of a matrix that the
+//! reflector has zeroed.
+
+use crate::imp_prelude::*;
+use crate::ComplexFloat;
+
+use num_traits::{Float, Zero};
+
+/// Generate a reflector `H` such that `Hᴴ [alpha; x] = [beta; 0]` where
+/// `beta` is real.
+///
+/// On return `x` holds `v[1..]`; return `(beta, τ)`.
+pub(crate) fn reflector<A>(alpha: A, mut x: ArrayViewMut1<'_, A>) -> (A, A)
+where
+    A: ComplexFloat,
+{
+    let xnorm = x.norm_l2();
+    if xnorm.is_zero() && alpha.im().is_zero() {
+        return (alpha, A::zero());
+    }
+    let norm = Float::hypot(alpha.abs(), xnorm);
+    let beta = if alpha.re() >= A::Real::zero() {
+        -norm
+    } else {
+        norm
+    };
+    let beta_ = A::from_real(beta);
+    let tau = (beta_ - alpha) / beta_;
+    let scale = A::one() / (alpha - beta_);
+    x.map_inplace(|v| *v *= scale);
+    (beta_, tau)
+}
+
+/// Return the reflector vector `[1; tail]`.
+pub(crate) fn vector<A, S>(tail: &ArrayBase<S, Ix1>) -> Array1<A>
+where
+    S: Data<Elem = A>,
+    A: ComplexFloat,
+{
+    let mut v = Array1::zeros(tail.len() + 1);
+    v[0] = A::one();
+    v.slice_mut(s![1..]).assign(tail);
+    v
+}
+
+/// C ← (I - τ v vᴴ) C
+///
+/// Pass `τ.conj()` to apply `Hᴴ` instead.
+pub(crate) fn apply_left<A>(v: &ArrayView1<'_, A>, tau: A, mut c: ArrayViewMut2<'_, A>)
+where
+    A: ComplexFloat,
+{
+    if tau.is_zero() {
+        return;
+    }
+    // w = vᴴ C
+    let mut w = Array1::zeros(c.cols());
+    for (&vi, row) in v.iter().zip(c.outer_iter()) {
+        w.scaled_add(vi.conj(), &row);
+    }
+    for (&vi, mut row) in v.iter().zip(c.outer_iter_mut()) {
+        row.scaled_add(-tau * vi, &w);
+    }
+}
+
+/// C ← C (I - τ v vᴴ)
+pub(crate) fn apply_right<A>(v: &ArrayView1<'_, A>, tau: A, mut c: ArrayViewMut2<'_, A>)
+where
+    A: ComplexFloat,
+{
+    if tau.is_zero() {
+        return;
+    }
+    // w = C v
+    let mut w = Array1::zeros(c.rows());
+    for (&vj, col) in v.iter().zip(c.axis_iter(Axis(1))) {
+        w.scaled_add(vj, &col);
+    }
+    for (&vj, mut col) in v.iter().zip(c.axis_iter_mut(Axis(1))) {
+        col.scaled_add(-tau * vj.conj(), &w);
+    }
+}
@@ -9,10 +9,19 @@
 use crate::imp_prelude::*;
 use crate::numeric_util;
 
-use crate::{LinalgScalar, Zip};
+use crate::linalg::int_gemm::int_gemm;
+use crate::linalg::Transpose;
+use crate::{ComplexFloat, FoldWhile, LinalgScalar, Zip};
 
+use num_complex::Complex;
+use num_traits::{Float, One, Zero};
 use std::any::TypeId;
 
+#[allow(non_camel_case_types)]
+type c32 = Complex<f32>;
+#[allow(non_camel_case_types)]
+type c64 = Complex<f64>;
+
 #[cfg(feature = "blas")]
 use std::cmp;
 #[cfg(feature = "blas")]
@@ -23,7 +32,9 @@ use std::os::raw::c_int;
 #[cfg(feature = "blas")]
 use cblas_sys as blas_sys;
 #[cfg(feature = "blas")]
-use cblas_sys::{CblasNoTrans, CblasRowMajor, CblasTrans, CBLAS_LAYOUT};
+use cblas_sys::{
+    CblasConjTrans, CblasNoTrans, CblasRowMajor, CblasTrans, CBLAS_LAYOUT, CBLAS_TRANSPOSE,
+};
 
 /// len of vector before we use blas
 #[cfg(feature = "blas")]
@@ -35,6 +46,31 @@ const GEMM_BLAS_CUTOFF: usize = 7;
 #[allow(non_camel_case_types)]
 type blas_index = c_int; // blas index type
 
+/// Return the product of the complex vectors `$lhs` and `$rhs` with the BLAS
+/// function `$func`, if `A` is `$ty` and the layouts are compatible.
+#[cfg(feature = "blas")]
+macro_rules! complex_dot {
+    ($lhs:expr, $rhs:expr, $ty:ty, $func:ident) => {{
+        let (lhs, rhs) = ($lhs, $rhs);
+        if blas_compat_1d::<$ty, _>(lhs) && blas_compat_1d::<$ty, _>(rhs) {
+            unsafe {
+                let (lhs_ptr, n, incx) = blas_1d_params(lhs.ptr, lhs.len(), lhs.strides()[0]);
+                let (rhs_ptr, _, incy) = blas_1d_params(rhs.ptr, rhs.len(), rhs.strides()[0]);
+                let mut ret = <$ty>::zero();
+                blas_sys::$func(
+                    n,
+                    lhs_ptr as *const _,
+                    incx,
+                    rhs_ptr as *const _,
+                    incy,
+                    &mut ret as *mut $ty as *mut _,
+                );
+                return cast_as::<$ty, A>(&ret);
+            }
+        }
+    }};
+}
+
 impl<A, S> ArrayBase<S, Ix1>
 where
     S: Data<Elem = A>,
@@ -54,8 +90,8 @@ where
     /// shape *N*.
     ///
     /// **Panics** if the array shapes are incompatible.<br>
-    /// *Note:* If enabled, uses blas `dot` for elements of `f32, f64` when memory
-    /// layout allows.
+    /// *Note:* If enabled, uses blas `dot`/`dotu` for elements of `f32, f64`
+    /// and `Complex<f32>, Complex<f64>` when memory layout allows.
     pub fn dot<Rhs>(&self, rhs: &Rhs) -> <Self as Dot<Rhs>>::Output
     where
         Self: Dot<Rhs>,
@@ -126,9 +162,53 @@ where
 
             dot! {f32, cblas_sdot};
             dot! {f64, cblas_ddot};
+            complex_dot!(self, rhs, c32, cblas_cdotu_sub);
+            complex_dot!(self, rhs, c64, cblas_zdotu_sub);
         }
         self.dot_generic(rhs)
     }
+
+    /// Return the conjugating dot product of `self` and `rhs`, which is the
+    /// sum of the elementwise products of the complex conjugate of `self`
+    /// with `rhs`; this is the inner product of complex vectors.
+    ///
+    /// For real elements it is the same as [`.dot()`](#method.dot).
+    ///
+    /// **Panics** if the lengths of the arrays differ.<br>
+    /// *Note:* If enabled, uses blas `dot`/`dotc` for elements of `f32, f64`
+    /// and `Complex<f32>, Complex<f64>` when memory layout allows.
+    ///
+    /// ```
+    /// use ndarray::arr1;
+    /// use num_complex::Complex;
+    ///
+    /// let a = arr1(&[Complex::new(1., 1.), Complex::new(0., 2.)]);
+    /// assert_eq!(a.vdot(&a), Complex::new(6., 0.));
+    /// assert_eq!(a.dot(&a), Complex::new(-4., 2.));
+    /// ```
+    pub fn vdot<S2>(&self, rhs: &ArrayBase<S2, Ix1>) -> A
+    where
+        S2: Data<Elem = A>,
+        A: ComplexFloat,
+    {
+        assert!(self.len() == rhs.len());
+        if same_type::<A, f32>() || same_type::<A, f64>() {
+            return self.dot_impl(rhs);
+        }
+        #[cfg(feature = "blas")]
+        {
+            if self.len() >= DOT_BLAS_CUTOFF {
+                complex_dot!(self, rhs, c32, cblas_cdotc_sub);
+                complex_dot!(self, rhs, c64, cblas_zdotc_sub);
+            }
+        }
+        Zip::from(self)
+            .and(rhs)
+            .fold_while(A::zero(), |acc, x, &y| {
+                FoldWhile::Continue(acc + x.conj() * y)
+            })
+            .into_inner()
+    }
 }
 
 /// Return a pointer to the starting element in BLAS's view.
@@ -154,6 +234,38 @@ unsafe fn blas_1d_params<A>(
     }
 }
 
+/// Return the Euclidean norm of `x` computed with BLAS `nrm2`, if `A` is one
+/// of the BLAS element types, the vector is large enough and the layout is
+/// compatible.
+#[cfg(feature = "blas")]
+pub(crate) fn blas_nrm2<A, S>(x: &ArrayBase<S, Ix1>) -> Option<A::Real>
+where
+    S: Data<Elem = A>,
+    A: ComplexFloat,
+{
+    if x.len() < DOT_BLAS_CUTOFF || x.strides()[0] == 0 {
+        return None;
+    }
+    macro_rules! nrm2 {
+        ($ty:ty, $func:ident) => {
+            if blas_compat_1d::<$ty, _>(x) {
+                unsafe {
+                    // nrm2 requires a positive increment, and the norm does
+                    // not depend on the order of the elements
+                    let (ptr, n, incx) = blas_1d_params(x.ptr, x.len(), x.strides()[0]);
+                    let ret = blas_sys::$func(n, ptr as *const _, incx.abs());
+                    return Some(cast_as(&ret));
+                }
+            }
+        };
+    }
+    nrm2!(f32, cblas_snrm2);
+    nrm2!(f64, cblas_dnrm2);
+    nrm2!(c32, cblas_scnrm2);
+    nrm2!(c64, cblas_dznrm2);
+    None
+}
+
 /// Matrix Multiplication
 ///
 /// For two-dimensional arrays, the dot method computes the matrix
@@ -296,7 +408,14 @@ fn dot_shape_error(m: usize, k: usize, k2: usize, n: usize) -> ! {
 
 #[cold]
 #[inline(never)]
-fn general_dot_shape_error(m: usize, k: usize, k2: usize, n: usize, c1: usize, c2: usize) -> ! {
+pub(crate) fn general_dot_shape_error(
+    m: usize,
+    k: usize,
+    k2: usize,
+    n: usize,
+    c1: usize,
+    c2: usize,
+) -> ! {
     panic!("ndarray: inputs {} × {}, {} × {}, and output {} × {} are not compatible for matrix multiplication",
            m, k, k2, n, c1, c2);
 }
@@ -355,16 +474,68 @@ where
     }
 }
 
-// mat_mul_impl uses ArrayView arguments to send all array kinds into
+/// Use the blocked integer kernel if `A` is one of the integer types `$ty`.
+macro_rules! int_gemm_dispatch {
+    ($alpha:ident, $lhs:ident, $rhs:ident, $beta:ident, $c:ident; $($ty:ty),*) => {
+        $(
+            if same_type::<A, $ty>() {
+                int_gemm::<$ty, $ty>(
+                    cast_as(&$alpha),
+                    &cast_view($lhs),
+                    &cast_view($rhs),
+                    cast_as(&$beta),
+                    &mut cast_view_mut($c),
+                );
+                return;
+            }
+        )*
+    };
+}
+
+/// C ← α A B + β C
+pub(crate) fn mat_mul_impl<A>(
+    alpha: A,
+    lhs: &ArrayView2<'_, A>,
+    rhs: &ArrayView2<'_, A>,
+    beta: A,
+    c: &mut ArrayViewMut2<'_, A>,
+) where
+    A: LinalgScalar,
+{
+    mat_mul_conj_impl(alpha, lhs, false, rhs, false, beta, c)
+}
+
+// mat_mul_conj_impl uses ArrayView arguments to send all array kinds into
 // the same instantiated implementation.
 #[cfg(not(feature = "blas"))]
-use self::mat_mul_general as mat_mul_impl;
+use self::mat_mul_general as mat_mul_conj_impl;
+
+/// Pass the scalar `$var` of type `A` to a BLAS function for `$ty`.
+#[cfg(feature = "blas")]
+macro_rules! gemm_scalar_cast {
+    (f32, $var:ident) => {
+        cast_as(&$var)
+    };
+    (f64, $var:ident) => {
+        cast_as(&$var)
+    };
+    (c32, $var:ident) => {
+        &$var as *const A as *const _
+    };
+    (c64, $var:ident) => {
+        &$var as *const A as *const _
+    };
+}
 
+/// C ← α A B + β C, where `A` (`B`) is conjugated if `conj_lhs` (`conj_rhs`)
+/// is true.
 #[cfg(feature = "blas")]
-fn mat_mul_impl<A>(
+fn mat_mul_conj_impl<A>(
     alpha: A,
     lhs: &ArrayView2<'_, A>,
+    conj_lhs: bool,
     rhs: &ArrayView2<'_, A>,
+    conj_rhs: bool,
     beta: A,
     c: &mut ArrayViewMut2<'_, A>,
 ) where
@@ -373,8 +544,13 @@ fn mat_mul_impl<A>(
     // size cutoff for using BLAS
     let cut = GEMM_BLAS_CUTOFF;
     let ((mut m, a), (_, mut n)) = (lhs.dim(), rhs.dim());
-    if !(m > cut || n > cut || a > cut) || !(same_type::<A, f32>() || same_type::<A, f64>()) {
-        return mat_mul_general(alpha, lhs, rhs, beta, c);
+    if !(m > cut || n > cut || a > cut)
+        || !(same_type::<A, f32>()
+            || same_type::<A, f64>()
+            || same_type::<A, c32>()
+            || same_type::<A, c64>())
+    {
+        return mat_mul_general(alpha, lhs, conj_lhs, rhs, conj_rhs, beta, c);
     }
     {
         // Use `c` for c-order and `f` for an f-order matrix
@@ -383,6 +559,8 @@ fn mat_mul_impl<A>(
         let mut lhs_ = lhs.view();
         let mut rhs_ = rhs.view();
         let mut c_ = c.view_mut();
+        let mut conj_lhs_ = conj_lhs;
+        let mut conj_rhs_ = conj_rhs;
         let lhs_s0 = lhs_.strides()[0];
         let rhs_s0 = rhs_.strides()[0];
         let both_f = lhs_s0 == 1 && rhs_s0 == 1;
@@ -395,16 +573,19 @@ fn mat_mul_impl<A>(
             rhs_ = lhs_t;
             c_ = c_.reversed_axes();
             swap(&mut m, &mut n);
+            swap(&mut conj_lhs_, &mut conj_rhs_);
         } else if lhs_s0 == 1 && m == a {
             lhs_ = lhs_.reversed_axes();
-            lhs_trans = CblasTrans;
+            lhs_trans = blas_trans(conj_lhs_);
+            conj_lhs_ = false;
         } else if rhs_s0 == 1 && a == n {
             rhs_ = rhs_.reversed_axes();
-            rhs_trans = CblasTrans;
+            rhs_trans = blas_trans(conj_rhs_);
+            conj_rhs_ = false;
         }
 
         macro_rules! gemm {
-            ($ty:ty, $gemm:ident) => {
+            ($ty:tt, $gemm:ident) => {
                 if blas_row_major_2d::<$ty, _>(&lhs_)
                     && blas_row_major_2d::<$ty, _>(&rhs_)
                     && blas_row_major_2d::<$ty, _>(&c_)
@@ -432,34 +613,55 @@ fn mat_mul_impl<A>(
                             CblasRowMajor,
                             lhs_trans,
                             rhs_trans,
-                            m as blas_index,      // m, rows of Op(a)
-                            n as blas_index,      // n, cols of Op(b)
-                            k as blas_index,      // k, cols of Op(a)
-                            cast_as(&alpha),      // alpha
-                            lhs_.ptr as *const _, // a
-                            lhs_stride,           // lda
-                            rhs_.ptr as *const _, // b
-                            rhs_stride,           // ldb
-                            cast_as(&beta),       // beta
-                            c_.ptr as *mut _,     // c
-                            c_stride,             // ldc
+                            m as blas_index,               // m, rows of Op(a)
+                            n as blas_index,               // n, cols of Op(b)
+                            k as blas_index,               // k, cols of Op(a)
+                            gemm_scalar_cast!($ty, alpha), // alpha
+                            lhs_.ptr as *const _,          // a
+                            lhs_stride,                    // lda
+                            rhs_.ptr as *const _,          // b
+                            rhs_stride,                    // ldb
+                            gemm_scalar_cast!($ty, beta),  // beta
+                            c_.ptr as *mut _,              // c
+                            c_stride,                      // ldc
                         );
                     }
                     return;
                 }
             };
         }
-        gemm!(f32, cblas_sgemm);
-        gemm!(f64, cblas_dgemm);
+        // a conjugated operand that is not also transposed can not be
+        // expressed in BLAS
+        if !conj_lhs_ && !conj_rhs_ {
+            gemm!(f32, cblas_sgemm);
+            gemm!(f64, cblas_dgemm);
+            gemm!(c32, cblas_cgemm);
+            gemm!(c64, cblas_zgemm);
+        }
     }
-    mat_mul_general(alpha, lhs, rhs, beta, c)
+    mat_mul_general(alpha, lhs, conj_lhs, rhs, conj_rhs, beta, c)
 }
 
-/// C ← α A B + β C
+/// Return the BLAS transpose flag for a transposed operand.
+#[cfg(feature = "blas")]
+fn blas_trans(conj: bool) -> CBLAS_TRANSPOSE {
+    if conj {
+        CblasConjTrans
+    } else {
+        CblasTrans
+    }
+}
+
+/// C ← α A B + β C, where `A` (`B`) is conjugated if `conj_lhs` (`conj_rhs`)
+/// is true.
+///
+/// Conjugation only has an effect for complex elements.
 fn mat_mul_general<A>(
     alpha: A,
     lhs: &ArrayView2<'_, A>,
+    conj_lhs: bool,
     rhs: &ArrayView2<'_, A>,
+    conj_rhs: bool,
     beta: A,
     c: &mut ArrayViewMut2<'_, A>,
 ) where
@@ -510,7 +712,34 @@ fn mat_mul_general<A>(
                 csc,
             );
         }
+    } else if same_type::<A, c32>() {
+        unsafe {
+            complex_gemm(
+                ::matrixmultiply::sgemm,
+                cast_as(&alpha),
+                (&cast_view(lhs), conj_lhs),
+                (&cast_view(rhs), conj_rhs),
+                cast_as(&beta),
+                &mut cast_view_mut(c),
+            );
+        }
+    } else if same_type::<A, c64>() {
+        unsafe {
+            complex_gemm(
+                ::matrixmultiply::dgemm,
+                cast_as(&alpha),
+                (&cast_view(lhs), conj_lhs),
+                (&cast_view(rhs), conj_rhs),
+                cast_as(&beta),
+                &mut cast_view_mut(c),
+            );
+        }
     } else {
+        debug_assert!(!conj_lhs && !conj_rhs);
+        int_gemm_dispatch!(
+            alpha, lhs, rhs, beta, c;
+            i8, i16, i32, i64, isize, u8, u16, u32, u64, usize
+        );
         // It's a no-op if `c` has zero length.
         if c.is_empty() {
             return;
@@ -544,6 +773,118 @@ fn mat_mul_general<A>(
     }
 }
 
+/// The signature of the real matrix multiplication kernels of
+/// `matrixmultiply`.
+type RealGemm<T> = unsafe fn(
+    usize,
+    usize,
+    usize,
+    T,
+    *const T,
+    isize,
+    isize,
+    *const T,
+    isize,
+    isize,
+    T,
+    *mut T,
+    isize,
+    isize,
+);
+
+/// C ← α A B + β C for complex elements, where each operand is given with a
+/// flag that requests its conjugate.
+///
+/// The product is formed from real matrix multiplications of the real and
+/// imaginary parts, which are accessed in place through doubled strides,
+/// using the blocked real kernel `gemm`.
+unsafe fn complex_gemm<T>(
+    gemm: RealGemm<T>,
+    alpha: Complex<T>,
+    (a, conj_a): (&ArrayView2<'_, Complex<T>>, bool),
+    (b, conj_b): (&ArrayView2<'_, Complex<T>>, bool),
+    beta: Complex<T>,
+    c: &mut ArrayViewMut2<'_, Complex<T>>,
+) where
+    T: LinalgScalar + Float,
+{
+    let ((m, k), (_, n)) = (a.dim(), b.dim());
+    if !alpha.im.is_zero() || !beta.im.is_zero() {
+        // form the product separately, then scale it
+        let mut p = Array2::zeros((m, n));
+        complex_gemm(
+            gemm,
+            Complex::one(),
+            (a, conj_a),
+            (b, conj_b),
+            Complex::zero(),
+            &mut p.view_mut(),
+        );
+        if beta.is_zero() {
+            Zip::from(c).and(&p).apply(|c, &p| *c = alpha * p);
+        } else {
+            Zip::from(c)
+                .and(&p)
+                .apply(|c, &p| *c = alpha * p + beta * *c);
+        }
+        return;
+    }
+    let (alpha, beta) = (alpha.re, beta.re);
+    let sign = |conj: bool| if conj { -T::one() } else { T::one() };
+    let (sa, sb) = (sign(conj_a), sign(conj_b));
+    // real parts at offset 0, imaginary parts at offset 1, with the strides
+    // counted in units of `T`
+    let parts = |ptr: *const Complex<T>, s: &[isize]| {
+        let re = ptr as *const T;
+        (re, re.offset(1), 2 * s[0], 2 * s[1])
+    };
+    let (ar, ai, rsa, csa) = parts(a.as_ptr(), a.strides());
+    let (br, bi, rsb, csb) = parts(b.as_ptr(), b.strides());
+    let (cr, ci, rsc, csc) = parts(c.as_ptr(), c.strides());
+    let (cr, ci) = (cr as *mut T, ci as *mut T);
+    // Re(C) ← α (Re A Re B - sa sb Im A Im B) + β Re(C)
+    gemm(
+        m, k, n, alpha, ar, rsa, csa, br, rsb, csb, beta, cr, rsc, csc,
+    );
+    let s = -alpha * sa * sb;
+    gemm(
+        m,
+        k,
+        n,
+        s,
+        ai,
+        rsa,
+        csa,
+        bi,
+        rsb,
+        csb,
+        T::one(),
+        cr,
+        rsc,
+        csc,
+    );
+    // Im(C) ← α (sb Re A Im B + sa Im A Re B) + β Im(C)
+    let s = alpha * sb;
+    gemm(m, k, n, s, ar, rsa, csa, bi, rsb, csb, beta, ci, rsc, csc);
+    let s = alpha * sa;
+    gemm(
+        m,
+        k,
+        n,
+        s,
+        ai,
+        rsa,
+        csa,
+        br,
+        rsb,
+        csb,
+        T::one(),
+        ci,
+        rsc,
+        csc,
+    );
+}
+
 /// General matrix-matrix multiplication.
 ///
 /// Compute C ← α A B + β C
@@ -552,9 +893,14 @@ fn mat_mul_general<A>(
 /// if `a` is *M* × *N*, then `b` is *N* × *K* and `c` is *M* × *K*.
 ///
 /// ***Panics*** if array shapes are not compatible<br>
-/// *Note:* If enabled, uses blas `gemm` for elements of `f32, f64` when memory
-/// layout allows.  The default matrixmultiply backend is otherwise used for
-/// `f32, f64` for all memory layouts.
+/// *Note:* If enabled, uses blas `gemm` for elements of `f32, f64` and
+/// `Complex<f32>, Complex<f64>` when memory layout allows.  The default
+/// matrixmultiply backend is otherwise used for these types for all memory
+/// layouts; complex products are formed from real products of the real and
+/// imaginary parts. Products of the primitive integer types use a
+/// cache-blocked kernel, with arithmetic that wraps on overflow; see
+/// [`general_mat_mul_widening`](fn.general_mat_mul_widening.html) for a
+/// wider accumulator.
 pub fn general_mat_mul<A, S1, S2, S3>(
     alpha: A,
     a: &ArrayBase<S1, Ix2>,
@@ -576,6 +922,66 @@ pub fn general_mat_mul<A, S1, S2, S3>(
     }
 }
 
+/// General matrix-matrix multiplication with transposed operands.
+///
+/// Compute C ← α op(A) op(B) + β C
+///
+/// where `op` is selected by `trans_a` and `trans_b`: the operand itself, its
+/// transpose, or its conjugate transpose. The array shapes must agree in the
+/// way that if `op(a)` is *M* × *N*, then `op(b)` is *N* × *K* and `c` is
+/// *M* × *K*. No operand is copied to transpose or conjugate it.
+///
+/// ***Panics*** if array shapes are not compatible<br>
+/// *Note:* If enabled, uses blas `gemm` (with `CblasConjTrans` for conjugate
+/// transposes) as [`general_mat_mul`](fn.general_mat_mul.html) does.
+///
+/// ```
+/// use ndarray::arr2;
+/// use ndarray::linalg::{general_mat_mul_trans, Transpose};
+/// use num_complex::Complex;
+///
+/// let i = Complex::i();
+/// let a = arr2(&[[i, 1. + i],
+///                [Complex::new(2., 0.), -i]]);
+/// let mut c = arr2(&[[Complex::new(0., 0.); 2]; 2]);
+/// // c ← aᴴ a
+/// general_mat_mul_trans(Complex::new(1., 0.), &a, Transpose::ConjTrans,
+///                       &a, Transpose::NoTrans, Complex::new(0., 0.), &mut c);
+/// assert_eq!(c, a.t().mapv(|x| x.conj()).dot(&a));
+/// ```
+#[allow(clippy::too_many_arguments)]
+pub fn general_mat_mul_trans<A, S1, S2, S3>(
+    alpha: A,
+    a: &ArrayBase<S1, Ix2>,
+    trans_a: Transpose,
+    b: &ArrayBase<S2, Ix2>,
+    trans_b: Transpose,
+    beta: A,
+    c: &mut ArrayBase<S3, Ix2>,
+) where
+    S1: Data<Elem = A>,
+    S2: Data<Elem = A>,
+    S3: DataMut<Elem = A>,
+    A: ComplexFloat,
+{
+    fn op<'a, A>(a: ArrayView2<'a, A>, trans: Transpose) -> (ArrayView2<'a, A>, bool) {
+        match trans {
+            Transpose::NoTrans => (a, false),
+            Transpose::Trans => (a.reversed_axes(), false),
+            Transpose::ConjTrans => (a.reversed_axes(), true),
+        }
+    }
+    let (a, conj_a) = op(a.view(), trans_a);
+    let (b, conj_b) = op(b.view(), trans_b);
+    let ((m, k), (k2, n)) = (a.dim(), b.dim());
+    let (m2, n2) = c.dim();
+    if k != k2 || m != m2 || n != n2 {
+        general_dot_shape_error(m, k, k2, n, m2, n2);
+    } else {
+        mat_mul_conj_impl(alpha, &a, conj_a, &b, conj_b, beta, &mut c.view_mut());
+    }
+}
+
 /// General matrix-vector multiplication.
 ///
 /// Compute y ← α A x + β y
@@ -584,8 +990,8 @@ pub fn general_mat_mul<A, S1, S2, S3>(
 /// y an *M*-element column vector (one dimensional arrays).
 ///
 /// ***Panics*** if array shapes are not compatible<br>
-/// *Note:* If enabled, uses blas `gemv` for elements of `f32, f64` when memory
-/// layout allows.
+/// *Note:* If enabled, uses blas `gemv` for elements of `f32, f64` and
+/// `Complex<f32>, Complex<f64>` when memory layout allows.
 #[allow(clippy::collapsible_if)]
 pub fn general_mat_vec_mul<A, S1, S2, S3>(
     alpha: A,
@@ -606,7 +1012,7 @@ pub fn general_mat_vec_mul<A, S1, S2, S3>(
     } else {
         #[cfg(feature = "blas")]
         macro_rules! gemv {
-            ($ty:ty, $gemv:ident) => {
+            ($ty:tt, $gemv:ident) => {
                 if let Some(layout) = blas_layout::<$ty, _>(&a) {
                     if blas_compat_1d::<$ty, _>(&x) && blas_compat_1d::<$ty, _>(&y) {
                         // Determine stride between rows or columns. Note that the stride is
@@ -630,15 +1036,15 @@ pub fn general_mat_vec_mul<A, S1, S2, S3>(
                             blas_sys::$gemv(
                                 layout,
                                 a_trans,
-                                m as blas_index,   // m, rows of Op(a)
-                                k as blas_index,   // n, cols of Op(a)
-                                cast_as(&alpha),   // alpha
-                                a.ptr as *const _, // a
-                                a_stride,          // lda
-                                x.ptr as *const _, // x
+                                m as blas_index,               // m, rows of Op(a)
+                                k as blas_index,               // n, cols of Op(a)
+                                gemm_scalar_cast!($ty, alpha), // alpha
+                                a.ptr as *const _,             // a
+                                a_stride,                      // lda
+                                x.ptr as *const _,             // x
                                 x_stride,
-                                cast_as(&beta),  // beta
-                                y.ptr as *mut _, // x
+                                gemm_scalar_cast!($ty, beta), // beta
+                                y.ptr as *mut _,              // x
                                 y_stride,
                             );
                         }
@@ -651,6 +1057,10 @@ pub fn general_mat_vec_mul<A, S1, S2, S3>(
         gemv!(f32, cblas_sgemv);
         #[cfg(feature = "blas")]
         gemv!(f64, cblas_dgemv);
+        #[cfg(feature = "blas")]
+        gemv!(c32, cblas_cgemv);
+        #[cfg(feature = "blas")]
+        gemv!(c64, cblas_zgemv);
 
         /* general */
 
@@ -680,6 +1090,24 @@ fn cast_as<A: 'static + Copy, B: 'static + Copy>(a: &A) -> B {
     unsafe { ::std::ptr::read(a as *const _ as *const B) }
 }
 
+// View elements of type `A` as type `B`.
+//
+// **Panics** if `A` and `B` are not the same type
+fn cast_view<'a, A: 'static, B: 'static>(a: &ArrayView2<'a, A>) -> ArrayView2<'a, B> {
+    assert!(same_type::<A, B>());
+    unsafe { ArrayView::new_(a.ptr as *const B, a.dim, a.strides) }
+}
+
+// View elements of type `A` as type `B`.
+//
+// **Panics** if `A` and `B` are not the same type
+fn cast_view_mut<'a, A: 'static, B: 'static>(
+    a: &'a mut ArrayViewMut2<'_, A>,
+) -> ArrayViewMut2<'a, B> {
+    assert!(same_type::<A, B>());
+    unsafe { ArrayViewMut::new_(a.ptr as *mut B, a.dim, a.strides) }
+}
+
 #[cfg(feature = "blas")]
 fn blas_compat_1d<A, S>(a: &ArrayBase<S, Ix1>) -> bool
 where
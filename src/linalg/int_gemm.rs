@@ -0,0 +1,206 @@
+// Copyright 2019 bluss and ndarray developers.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use crate::imp_prelude::*;
+use crate::linalg::impl_linalg::general_dot_shape_error;
+use crate::{LinalgScalar, Zip};
+
+use num_traits::{WrappingAdd, WrappingMul};
+
+/// Rows of `A` packed per block.
+const MC: usize = 64;
+/// Depth (columns of `A`, rows of `B`) packed per block.
+const KC: usize = 128;
+/// Columns of `B` packed per block.
+const NC: usize = 256;
+/// Rows of `C` updated together by the kernel.
+const MR: usize = 4;
+
+/// An integer type whose values convert losslessly into the accumulator
+/// type `Acc`.
+///
+/// This is implemented for each integer type into itself and into the
+/// wider integer types of the same signedness, and for unsigned types into
+/// wider signed types.
+pub trait Widen<Acc>: 'static + Copy {
+    /// Convert `self` into the accumulator type.
+    fn widen(self) -> Acc;
+}
+
+macro_rules! impl_widen {
+    ($from:ty => $($to:ty),*) => {
+        $(
+            impl Widen<$to> for $from {
+                #[inline(always)]
+                fn widen(self) -> $to {
+                    self as $to
+                }
+            }
+        )*
+    };
+}
+
+impl_widen!(i8 => i8, i16, i32, i64, isize);
+impl_widen!(i16 => i16, i32, i64, isize);
+impl_widen!(i32 => i32, i64);
+impl_widen!(i64 => i64);
+impl_widen!(isize => isize);
+impl_widen!(u8 => u8, u16, u32, u64, usize, i16, i32, i64, isize);
+impl_widen!(u16 => u16, u32, u64, usize, i32, i64);
+impl_widen!(u32 => u32, u64, i64);
+impl_widen!(u64 => u64);
+impl_widen!(usize => usize);
+
+/// Integer matrix-matrix multiplication with a wider accumulator.
+///
+/// Compute C ← α A B + β C
+///
+/// where the elements of `a` and `b` are widened to the element type of `c`
+/// before they are multiplied, for example to multiply `i8` matrices with
+/// `i32` accumulation. Arithmetic in the accumulator type wraps on
+/// overflow.
+///
+/// The array shapes must agree in the way that
+/// if `a` is *M* × *N*, then `b` is *N* × *K* and `c` is *M* × *K*.
+///
+/// ***Panics*** if array shapes are not compatible
+///
+/// ```
+/// use ndarray::arr2;
+/// use ndarray::linalg::general_mat_mul_widening;
+///
+/// let a = arr2(&[[100i8, 100],
+///                [-128, 127]]);
+/// let mut c = arr2(&[[0i32, 0],
+///                    [0, 0]]);
+/// general_mat_mul_widening(1, &a, &a, 0, &mut c);
+/// assert_eq!(c, arr2(&[[-2800, 22700],
+///                      [-29056, 3329]]));
+/// ```
+pub fn general_mat_mul_widening<A, Acc, S1, S2, S3>(
+    alpha: Acc,
+    a: &ArrayBase<S1, Ix2>,
+    b: &ArrayBase<S2, Ix2>,
+    beta: Acc,
+    c: &mut ArrayBase<S3, Ix2>,
+) where
+    S1: Data<Elem = A>,
+    S2: Data<Elem = A>,
+    S3: DataMut<Elem = Acc>,
+    A: Widen<Acc>,
+    Acc: LinalgScalar + WrappingAdd + WrappingMul,
+{
+    let ((m, k), (k2, n)) = (a.dim(), b.dim());
+    let (m2, n2) = c.dim();
+    if k != k2 || m != m2 || n != n2 {
+        general_dot_shape_error(m, k, k2, n, m2, n2);
+    } else {
+        int_gemm(alpha, &a.view(), &b.view(), beta, &mut c.view_mut());
+    }
+}
+
+/// C ← α A B + β C, with wrapping arithmetic in `Acc`.
+///
+/// The operands are packed block by block into contiguous buffers of
+/// widened elements, so that the kernel's inner loops run over unit stride
+/// slices and can be auto-vectorized.
+pub(crate) fn int_gemm<A, Acc>(
+    alpha: Acc,
+    a: &ArrayView2<'_, A>,
+    b: &ArrayView2<'_, A>,
+    beta: Acc,
+    c: &mut ArrayViewMut2<'_, Acc>,
+) where
+    A: Widen<Acc>,
+    Acc: LinalgScalar + WrappingAdd + WrappingMul,
+{
+    let ((m, k), (_, n)) = (a.dim(), b.dim());
+    if beta.is_zero() {
+        c.fill(Acc::zero());
+    } else {
+        c.map_inplace(|x| *x = x.wrapping_mul(&beta));
+    }
+    if m == 0 || k == 0 || n == 0 || alpha.is_zero() {
+        return;
+    }
+
+    let mut apack = Vec::with_capacity(MC.min(m) * KC.min(k));
+    let mut bpack = Vec::with_capacity(KC.min(k) * NC.min(n));
+    let mut acc = vec![Acc::zero(); MR * NC.min(n)];
+    for j0 in (0..n).step_by(NC) {
+        let nc = NC.min(n - j0);
+        for p0 in (0..k).step_by(KC) {
+            let kc = KC.min(k - p0);
+            pack(&mut bpack, b.slice(s![p0..p0 + kc, j0..j0 + nc]));
+            for i0 in (0..m).step_by(MC) {
+                let mc = MC.min(m - i0);
+                pack(&mut apack, a.slice(s![i0..i0 + mc, p0..p0 + kc]));
+                for r0 in (0..mc).step_by(MR) {
+                    let mr = MR.min(mc - r0);
+                    let acc = &mut acc[..mr * nc];
+                    for x in acc.iter_mut() {
+                        *x = Acc::zero();
+                    }
+                    kernel(mr, kc, nc, &apack[r0 * kc..], &bpack, acc);
+                    for (r, acc_row) in acc.chunks(nc).enumerate() {
+                        let row = i0 + r0 + r;
+                        Zip::from(c.slice_mut(s![row, j0..j0 + nc]))
+                            .and(acc_row)
+                            .apply(|c, x| *c = c.wrapping_add(&alpha.wrapping_mul(x)));
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Copy the widened elements of `a` into `buf` in row major order.
+fn pack<A, Acc>(buf: &mut Vec<Acc>, a: ArrayView2<'_, A>)
+where
+    A: Widen<Acc>,
+{
+    buf.clear();
+    for row in a.outer_iter() {
+        buf.extend(row.iter().map(|&x| x.widen()));
+    }
+}
+
+/// acc ← a b, where `a` is `mr` × `kc`, `b` is `kc` × `nc` and `acc` is
+/// `mr` × `nc`, all row major and contiguous (the rows of `a` are `kc` apart).
+#[inline(always)]
+fn kernel<Acc>(mr: usize, kc: usize, nc: usize, a: &[Acc], b: &[Acc], acc: &mut [Acc])
+where
+    Acc: LinalgScalar + WrappingAdd + WrappingMul,
+{
+    if mr == MR {
+        // update four rows at once, so that each row of `b` is loaded once
+        // for all of them
+        let (acc0, rest) = acc.split_at_mut(nc);
+        let (acc1, rest) = rest.split_at_mut(nc);
+        let (acc2, acc3) = rest.split_at_mut(nc);
+        for (p, b_row) in b.chunks(nc).take(kc).enumerate() {
+            let (a0, a1, a2, a3) = (a[p], a[kc + p], a[2 * kc + p], a[3 * kc + p]);
+            let rows = acc0.iter_mut().zip(acc1.iter_mut());
+            let rows = rows.zip(acc2.iter_mut()).zip(acc3.iter_mut());
+            for ((((x0, x1), x2), x3), bv) in rows.zip(b_row) {
+                *x0 = x0.wrapping_add(&a0.wrapping_mul(bv));
+                *x1 = x1.wrapping_add(&a1.wrapping_mul(bv));
+                *x2 = x2.wrapping_add(&a2.wrapping_mul(bv));
+                *x3 = x3.wrapping_add(&a3.wrapping_mul(bv));
+            }
+        }
+    } else {
+        for (acc_row, a_row) in acc.chunks_mut(nc).zip(a.chunks(kc)).take(mr) {
+            for (ap, b_row) in a_row.iter().zip(b.chunks(nc)) {
+                for (x, bv) in acc_row.iter_mut().zip(b_row) {
+                    *x = x.wrapping_add(&ap.wrapping_mul(bv));
+                }
+            }
+        }
+    }
+}
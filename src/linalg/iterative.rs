@@ -0,0 +1,572 @@
+// Copyright 2019 bluss and ndarray developers.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use crate::imp_prelude::*;
+use crate::linalg::error::{check_len, LinalgError};
+use crate::linalg::triangular::solve_upper_inplace;
+use crate::linalg::Dot;
+use crate::{ComplexFloat, Zip};
+
+use num_traits::{Float, NumCast, One, Zero};
+
+/// A linear operator, which can be applied to vectors without being stored
+/// as a matrix.
+///
+/// The iterative solvers [`cg`](fn.cg.html), [`bicgstab`](fn.bicgstab.html)
+/// and [`gmres`](fn.gmres.html) only access the system matrix through this
+/// trait, and preconditioners are linear operators too.
+///
+/// It is implemented for every type that implements both `Dot` with a
+/// vector (the product `A x`) and the `Dot` of a vector with it (the product
+/// `xᵀ A`), which includes two-dimensional arrays and the
+/// [sparse matrices](../sparse/index.html). Implement it directly for
+/// operators that are defined by a function.
+pub trait LinearOperator<A> {
+    /// Return the product `A x`.
+    fn apply(&self, x: &ArrayView1<'_, A>) -> Array1<A>;
+
+    /// Return the product of the transpose with `x`, `Aᵀ x`.
+    ///
+    /// For complex operators this is the transpose, not the conjugate
+    /// transpose.
+    fn apply_transpose(&self, x: &ArrayView1<'_, A>) -> Array1<A>;
+}
+
+impl<A, T> LinearOperator<A> for T
+where
+    T: for<'a> Dot<ArrayView1<'a, A>, Output = Array1<A>>,
+    for<'a> ArrayView1<'a, A>: Dot<T, Output = Array1<A>>,
+{
+    fn apply(&self, x: &ArrayView1<'_, A>) -> Array1<A> {
+        self.dot(x)
+    }
+
+    fn apply_transpose(&self, x: &ArrayView1<'_, A>) -> Array1<A> {
+        x.dot(self)
+    }
+}
+
+/// The Jacobi (diagonal) preconditioner, which divides by the diagonal of
+/// the system matrix.
+///
+/// ```
+/// use ndarray::arr2;
+/// use ndarray::linalg::{cg, Jacobi, SolverOptions};
+///
+/// let a = arr2(&[[4f64, 1.],
+///                [1., 300.]]);
+/// let b = a.dot(&ndarray::arr1(&[1., 2.]));
+/// let m = Jacobi::new(&a.diag()).unwrap();
+/// let sol = cg(&a, &b, &SolverOptions::new().preconditioner(&m)).unwrap();
+/// assert!(sol.converged);
+/// assert!((sol.solution[1] - 2.).abs() < 1e-10);
+/// ```
+#[derive(Clone, Debug)]
+pub struct Jacobi<A> {
+    inv_diag: Array1<A>,
+}
+
+impl<A> Jacobi<A>
+where
+    A: ComplexFloat,
+{
+    /// Create the preconditioner for a matrix with diagonal `diag`.
+    ///
+    /// ***Errors*** with `LinalgError::Singular` if an element of the
+    /// diagonal is zero.
+    pub fn new<S>(diag: &ArrayBase<S, Ix1>) -> Result<Self, LinalgError>
+    where
+        S: Data<Elem = A>,
+    {
+        if diag.iter().any(|d| d.is_zero()) {
+            return Err(LinalgError::Singular);
+        }
+        Ok(Jacobi {
+            inv_diag: diag.mapv(|d| A::one() / d),
+        })
+    }
+}
+
+impl<A> LinearOperator<A> for Jacobi<A>
+where
+    A: ComplexFloat,
+{
+    fn apply(&self, x: &ArrayView1<'_, A>) -> Array1<A> {
+        x * &self.inv_diag
+    }
+
+    fn apply_transpose(&self, x: &ArrayView1<'_, A>) -> Array1<A> {
+        self.apply(x)
+    }
+}
+
+/// The tolerances, iteration limit, initial guess and preconditioner of an
+/// iterative solver.
+///
+/// The iteration stops when the Euclidean norm of the residual `b - A x` is
+/// at most `max(rtol · ‖b‖, atol)`. The default tolerances are `rtol = 1e-8`
+/// and `atol = 0`, the default iteration limit is `10 · n` for systems of
+/// size `n`, and the default GMRES restart length is `min(30, n)`.
+#[derive(Clone)]
+pub struct SolverOptions<'a, A>
+where
+    A: ComplexFloat,
+{
+    rtol: A::Real,
+    atol: A::Real,
+    max_iter: Option<usize>,
+    restart: Option<usize>,
+    initial_guess: Option<ArrayView1<'a, A>>,
+    preconditioner: Option<&'a dyn LinearOperator<A>>,
+}
+
+impl<'a, A> SolverOptions<'a, A>
+where
+    A: ComplexFloat,
+{
+    /// Create the default options.
+    pub fn new() -> Self {
+        SolverOptions {
+            rtol: NumCast::from(1e-8).unwrap(),
+            atol: A::Real::zero(),
+            max_iter: None,
+            restart: None,
+            initial_guess: None,
+            preconditioner: None,
+        }
+    }
+
+    /// Set the tolerance relative to the norm of the right hand side.
+    pub fn rtol(mut self, rtol: A::Real) -> Self {
+        self.rtol = rtol;
+        self
+    }
+
+    /// Set the absolute tolerance.
+    pub fn atol(mut self, atol: A::Real) -> Self {
+        self.atol = atol;
+        self
+    }
+
+    /// Set the maximum number of iterations.
+    pub fn max_iter(mut self, max_iter: usize) -> Self {
+        self.max_iter = Some(max_iter);
+        self
+    }
+
+    /// Set the number of GMRES iterations between restarts.
+    ///
+    /// **Panics** if `restart` is zero.
+    pub fn restart(mut self, restart: usize) -> Self {
+        assert!(
+            restart > 0,
+            "ndarray: the GMRES restart length must be positive"
+        );
+        self.restart = Some(restart);
+        self
+    }
+
+    /// Start the iteration from `x0` instead of from zero.
+    pub fn initial_guess<S>(mut self, x0: &'a ArrayBase<S, Ix1>) -> Self
+    where
+        S: Data<Elem = A>,
+    {
+        self.initial_guess = Some(x0.view());
+        self
+    }
+
+    /// Use the preconditioner `m`, an operator that approximates the inverse
+    /// of the system matrix.
+    ///
+    /// CG applies it symmetrically and requires it to be Hermitian positive
+    /// definite; BiCGSTAB and GMRES apply it on the right, so that the
+    /// residual they monitor is the residual of the original system.
+    pub fn preconditioner(mut self, m: &'a dyn LinearOperator<A>) -> Self {
+        self.preconditioner = Some(m);
+        self
+    }
+}
+
+impl<'a, A> Default for SolverOptions<'a, A>
+where
+    A: ComplexFloat,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// The result of an iterative solver, with convergence diagnostics.
+#[derive(Clone, Debug)]
+pub struct IterativeSolution<A>
+where
+    A: ComplexFloat,
+{
+    /// The last iterate.
+    pub solution: Array1<A>,
+    /// Whether the residual reached the tolerance. The iteration also stops
+    /// without converging when the iteration limit is reached or the
+    /// method breaks down.
+    pub converged: bool,
+    /// The number of iterations performed.
+    pub iterations: usize,
+    /// The Euclidean norm of the residual `b - A x` of the solution.
+    pub residual_norm: A::Real,
+    /// The residual norm at the start and after each iteration, as
+    /// estimated by the method.
+    pub residual_history: Vec<A::Real>,
+}
+
+/// The state shared by the solvers: the right hand side, the options and
+/// the stopping threshold.
+struct Problem<'a, 'b, A, Op>
+where
+    A: ComplexFloat,
+    Op: LinearOperator<A> + ?Sized,
+{
+    a: &'b Op,
+    b: ArrayView1<'b, A>,
+    opts: &'b SolverOptions<'a, A>,
+    threshold: A::Real,
+    max_iter: usize,
+}
+
+impl<'a, 'b, A, Op> Problem<'a, 'b, A, Op>
+where
+    A: ComplexFloat,
+    Op: LinearOperator<A> + ?Sized,
+{
+    fn new(
+        a: &'b Op,
+        b: ArrayView1<'b, A>,
+        opts: &'b SolverOptions<'a, A>,
+    ) -> Result<Self, LinalgError> {
+        if let Some(ref x0) = opts.initial_guess {
+            check_len(x0.len(), b.len())?;
+        }
+        let threshold = Float::max(opts.rtol * b.norm_l2(), opts.atol);
+        let max_iter = opts.max_iter.unwrap_or(10 * b.len());
+        Ok(Problem {
+            a,
+            b,
+            opts,
+            threshold,
+            max_iter,
+        })
+    }
+
+    /// Return `A x`, checking its length.
+    fn apply(&self, x: &Array1<A>) -> Result<Array1<A>, LinalgError> {
+        let y = self.a.apply(&x.view());
+        check_len(y.len(), self.b.len())?;
+        Ok(y)
+    }
+
+    /// Return `M r` for the preconditioner `M`, or `r` if there is none.
+    fn precondition(&self, r: &Array1<A>) -> Result<Array1<A>, LinalgError> {
+        match self.opts.preconditioner {
+            Some(m) => {
+                let z = m.apply(&r.view());
+                check_len(z.len(), r.len())?;
+                Ok(z)
+            }
+            None => Ok(r.clone()),
+        }
+    }
+
+    /// Return the initial guess and its residual.
+    fn start(&self) -> Result<(Array1<A>, Array1<A>), LinalgError> {
+        match self.opts.initial_guess {
+            Some(ref x0) => {
+                let x = x0.to_owned();
+                let r = &self.b - &self.apply(&x)?;
+                Ok((x, r))
+            }
+            None => Ok((Array1::zeros(self.b.len()), self.b.to_owned())),
+        }
+    }
+
+    /// Return the solution with the norm of its true residual.
+    fn finish(
+        &self,
+        solution: Array1<A>,
+        iterations: usize,
+        residual_history: Vec<A::Real>,
+    ) -> Result<IterativeSolution<A>, LinalgError> {
+        let residual_norm = (&self.b - &self.apply(&solution)?).norm_l2();
+        Ok(IterativeSolution {
+            solution,
+            converged: residual_norm <= self.threshold,
+            iterations,
+            residual_norm,
+            residual_history,
+        })
+    }
+}
+
+/// Solve `A x = b` with the (preconditioned) conjugate gradient method.
+///
+/// `A` must be Hermitian (symmetric, for real elements) and positive
+/// definite. Each iteration applies `A` and the preconditioner once.
+///
+/// ***Errors*** if the lengths of `b`, the initial guess and the results of
+/// the operators are inconsistent.<br>
+/// **Panics** if the operators panic, as the matrix products do for vectors
+/// of the wrong length.
+///
+/// ```
+/// use ndarray::{arr1, arr2};
+/// use ndarray::linalg::{cg, SolverOptions};
+///
+/// let a = arr2(&[[4f64, 1.],
+///                [1., 3.]]);
+/// let b = arr1(&[1., 2.]);
+/// let sol = cg(&a, &b, &SolverOptions::new().rtol(1e-12)).unwrap();
+/// assert!(sol.converged);
+/// assert!(sol.iterations <= 2);
+/// assert!((&a.dot(&sol.solution) - &b).iter().all(|r: &f64| r.abs() < 1e-11));
+/// ```
+pub fn cg<A, Op, S>(
+    a: &Op,
+    b: &ArrayBase<S, Ix1>,
+    opts: &SolverOptions<'_, A>,
+) -> Result<IterativeSolution<A>, LinalgError>
+where
+    A: ComplexFloat,
+    Op: LinearOperator<A> + ?Sized,
+    S: Data<Elem = A>,
+{
+    let problem = Problem::new(a, b.view(), opts)?;
+    let (mut x, mut r) = problem.start()?;
+    let mut res = r.norm_l2();
+    let mut history = vec![res];
+    let mut z = problem.precondition(&r)?;
+    let mut p = z.clone();
+    let mut rz = r.vdot(&z);
+    let mut k = 0;
+    while k < problem.max_iter && res > problem.threshold {
+        let q = problem.apply(&p)?;
+        let pq = p.vdot(&q);
+        if pq.is_zero() {
+            break;
+        }
+        k += 1;
+        let alpha = rz / pq;
+        x.scaled_add(alpha, &p);
+        r.scaled_add(-alpha, &q);
+        res = r.norm_l2();
+        history.push(res);
+        if res <= problem.threshold {
+            break;
+        }
+        z = problem.precondition(&r)?;
+        let rz_next = r.vdot(&z);
+        let beta = rz_next / rz;
+        rz = rz_next;
+        p.zip_mut_with(&z, |p, &z| *p = z + beta * *p);
+    }
+    problem.finish(x, k, history)
+}
+
+/// Solve `A x = b` with the (right preconditioned) biconjugate gradient
+/// stabilized method.
+///
+/// `A` can be any nonsingular square matrix. Each iteration applies `A` and
+/// the preconditioner twice.
+///
+/// ***Errors*** if the lengths of `b`, the initial guess and the results of
+/// the operators are inconsistent.<br>
+/// **Panics** if the operators panic, as the matrix products do for vectors
+/// of the wrong length.
+pub fn bicgstab<A, Op, S>(
+    a: &Op,
+    b: &ArrayBase<S, Ix1>,
+    opts: &SolverOptions<'_, A>,
+) -> Result<IterativeSolution<A>, LinalgError>
+where
+    A: ComplexFloat,
+    Op: LinearOperator<A> + ?Sized,
+    S: Data<Elem = A>,
+{
+    let problem = Problem::new(a, b.view(), opts)?;
+    let (mut x, mut r) = problem.start()?;
+    let mut res = r.norm_l2();
+    let mut history = vec![res];
+    let r_hat = r.clone();
+    let mut p = r.clone();
+    let mut v = Array1::zeros(r.len());
+    let (mut rho, mut alpha, mut omega) = (A::one(), A::one(), A::one());
+    let mut k = 0;
+    while k < problem.max_iter && res > problem.threshold {
+        let rho_next = r_hat.vdot(&r);
+        if rho_next.is_zero() {
+            break;
+        }
+        if k > 0 {
+            let beta = (rho_next / rho) * (alpha / omega);
+            Zip::from(&mut p)
+                .and(&r)
+                .and(&v)
+                .apply(|p, &r, &v| *p = r + beta * (*p - omega * v));
+        }
+        rho = rho_next;
+        k += 1;
+        let p_hat = problem.precondition(&p)?;
+        v = problem.apply(&p_hat)?;
+        let r_hat_v = r_hat.vdot(&v);
+        if r_hat_v.is_zero() {
+            break;
+        }
+        alpha = rho / r_hat_v;
+        x.scaled_add(alpha, &p_hat);
+        r.scaled_add(-alpha, &v);
+        res = r.norm_l2();
+        if res <= problem.threshold {
+            history.push(res);
+            break;
+        }
+        let s_hat = problem.precondition(&r)?;
+        let t = problem.apply(&s_hat)?;
+        let tt = t.vdot(&t);
+        if tt.is_zero() {
+            history.push(res);
+            break;
+        }
+        omega = t.vdot(&r) / tt;
+        x.scaled_add(omega, &s_hat);
+        r.scaled_add(-omega, &t);
+        res = r.norm_l2();
+        history.push(res);
+        if omega.is_zero() {
+            break;
+        }
+    }
+    problem.finish(x, k, history)
+}
+
+/// Solve `A x = b` with the restarted generalized minimal residual method,
+/// GMRES(m), with right preconditioning.
+///
+/// `A` can be any nonsingular square matrix. Each iteration applies `A` and
+/// the preconditioner once, and orthogonalizes against the previous
+/// iterations since the last restart; the restart length is set with
+/// [`SolverOptions::restart`](struct.SolverOptions.html#method.restart).
+/// The iteration count is the total over all restarts.
+///
+/// ***Errors*** if the lengths of `b`, the initial guess and the results of
+/// the operators are inconsistent.<br>
+/// **Panics** if the operators panic, as the matrix products do for vectors
+/// of the wrong length.
+///
+/// ```
+/// use ndarray::{arr1, arr2};
+/// use ndarray::linalg::{gmres, SolverOptions};
+///
+/// let a = arr2(&[[2., 1., 0.],
+///                [0., 3., 1.],
+///                [1., 0., 4.]]);
+/// let b = arr1(&[3., 4., 5.]);
+/// let sol = gmres(&a, &b, &SolverOptions::new().rtol(1e-12)).unwrap();
+/// assert!(sol.converged);
+/// assert!((&sol.solution - &arr1(&[1., 1., 1.])).iter().all(|e: &f64| e.abs() < 1e-10));
+/// ```
+pub fn gmres<A, Op, S>(
+    a: &Op,
+    b: &ArrayBase<S, Ix1>,
+    opts: &SolverOptions<'_, A>,
+) -> Result<IterativeSolution<A>, LinalgError>
+where
+    A: ComplexFloat,
+    Op: LinearOperator<A> + ?Sized,
+    S: Data<Elem = A>,
+{
+    let problem = Problem::new(a, b.view(), opts)?;
+    let n = b.len();
+    let m = opts.restart.unwrap_or_else(|| n.min(30)).max(1);
+    let (mut x, mut r) = problem.start()?;
+    let mut beta = r.norm_l2();
+    let mut history = vec![beta];
+    let mut k = 0;
+    while k < problem.max_iter && beta > problem.threshold {
+        let mut basis = Vec::with_capacity(m + 1);
+        basis.push(r.mapv(|e| e.scale(beta.recip())));
+        let mut h = Array2::<A>::zeros((m + 1, m));
+        let mut g = Array1::<A>::zeros(m + 1);
+        g[0] = A::from_real(beta);
+        let mut rotations = Vec::with_capacity(m);
+        let mut j = 0;
+        while j < m && k < problem.max_iter {
+            k += 1;
+            let mut w = problem.apply(&problem.precondition(&basis[j])?)?;
+            for (i, v) in basis.iter().enumerate() {
+                let hij = v.vdot(&w);
+                h[(i, j)] = hij;
+                w.scaled_add(-hij, v);
+            }
+            let w_norm = w.norm_l2();
+            h[(j + 1, j)] = A::from_real(w_norm);
+            for (i, &(c, s)) in rotations.iter().enumerate() {
+                let (u, v) = rotate(c, s, h[(i, j)], h[(i + 1, j)]);
+                h[(i, j)] = u;
+                h[(i + 1, j)] = v;
+            }
+            let (c, s) = givens(h[(j, j)], h[(j + 1, j)]);
+            rotations.push((c, s));
+            let (u, v) = rotate(c, s, h[(j, j)], h[(j + 1, j)]);
+            h[(j, j)] = u;
+            h[(j + 1, j)] = v;
+            let (u, v) = rotate(c, s, g[j], g[j + 1]);
+            g[j] = u;
+            g[j + 1] = v;
+            j += 1;
+            let res = g[j].abs();
+            history.push(res);
+            if res <= problem.threshold || w_norm.is_zero() {
+                break;
+            }
+            basis.push(w.mapv(|e| e.scale(w_norm.recip())));
+        }
+        // solve the least squares problem min ‖g - H y‖ and update x
+        let mut y = g.slice(s![..j]).insert_axis(Axis(1)).to_owned();
+        solve_upper_inplace(&h.slice(s![..j, ..j]), y.view_mut(), false);
+        let mut update = Array1::zeros(n);
+        for (v, &yi) in basis.iter().zip(y.iter()) {
+            update.scaled_add(yi, v);
+        }
+        x += &problem.precondition(&update)?;
+        r = &problem.b - &problem.apply(&x)?;
+        let beta_next = r.norm_l2();
+        let stagnated = beta_next >= beta || beta_next.is_nan();
+        beta = beta_next;
+        if stagnated {
+            break;
+        }
+    }
+    problem.finish(x, k, history)
+}
+
+/// Return the Givens rotation `(c, s)` that zeroes `b` in `(a, b)`.
+fn givens<A: ComplexFloat>(a: A, b: A) -> (A::Real, A) {
+    let b_abs = b.abs();
+    if b_abs.is_zero() {
+        return (A::Real::one(), A::zero());
+    }
+    let a_abs = a.abs();
+    if a_abs.is_zero() {
+        return (A::Real::zero(), b.conj().scale(b_abs.recip()));
+    }
+    let r = a_abs.hypot(b_abs);
+    let c = a_abs / r;
+    let s = a.scale(a_abs.recip()) * b.conj().scale(r.recip());
+    (c, s)
+}
+
+/// Apply the rotation `(c, s)` to `(x, y)`.
+fn rotate<A: ComplexFloat>(c: A::Real, s: A, x: A, y: A) -> (A, A) {
+    (x.scale(c) + s * y, y.scale(c) - s.conj() * x)
+}
@@ -0,0 +1,95 @@
+// Copyright 2019 bluss and ndarray developers.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use crate::imp_prelude::*;
+use crate::{LinalgScalar, Zip};
+
+/// Return the outer product of the vectors `a` and `b`, the matrix with
+/// elements `a[i] * b[j]`.
+///
+/// ```
+/// use ndarray::{arr1, arr2};
+/// use ndarray::linalg::outer;
+///
+/// let a = arr1(&[1, 2]);
+/// let b = arr1(&[3, 4, 5]);
+/// assert_eq!(outer(&a, &b), arr2(&[[3, 4, 5],
+///                                  [6, 8, 10]]));
+/// ```
+pub fn outer<A, S1, S2>(a: &ArrayBase<S1, Ix1>, b: &ArrayBase<S2, Ix1>) -> Array2<A>
+where
+    S1: Data<Elem = A>,
+    S2: Data<Elem = A>,
+    A: LinalgScalar,
+{
+    let mut c = Array2::zeros((a.len(), b.len()));
+    Zip::from(&mut c)
+        .and_broadcast(a.view().insert_axis(Axis(1)))
+        .and_broadcast(b)
+        .apply(|c, &x, &y| *c = x * y);
+    c
+}
+
+/// Return the Kronecker product of `a` and `b`.
+///
+/// The result is the block array with the blocks `a[i] * b`, for each index
+/// `i` of `a`, placed in the same arrangement as the elements of `a`; its
+/// length along each axis is the product of the lengths of `a` and `b`.
+///
+/// **Panics** if `a` and `b` have different numbers of axes, which is only
+/// possible for dynamic-dimensional arrays.
+///
+/// ```
+/// use ndarray::arr2;
+/// use ndarray::linalg::kron;
+///
+/// let a = arr2(&[[1, 2],
+///                [0, 3]]);
+/// let b = arr2(&[[1, -1]]);
+/// assert_eq!(kron(&a, &b), arr2(&[[1, -1, 2, -2],
+///                                 [0,  0, 3, -3]]));
+/// ```
+pub fn kron<A, S1, S2, D>(a: &ArrayBase<S1, D>, b: &ArrayBase<S2, D>) -> Array<A, D>
+where
+    S1: Data<Elem = A>,
+    S2: Data<Elem = A>,
+    D: Dimension,
+    A: LinalgScalar,
+{
+    let ndim = a.ndim();
+    assert_eq!(
+        ndim,
+        b.ndim(),
+        "ndarray: kron operands must have the same number of axes"
+    );
+    // interleave the axes as (a₀, b₀, a₁, b₁, ...), so that merging each
+    // pair of axes gives the Kronecker product
+    let mut av = a.view().into_dyn();
+    let mut bv = b.view().into_dyn();
+    for k in 0..ndim {
+        av.insert_axis_inplace(Axis(2 * k + 1));
+        bv.insert_axis_inplace(Axis(2 * k));
+    }
+    let shape: Vec<usize> = a
+        .shape()
+        .iter()
+        .zip(b.shape())
+        .flat_map(|(&m, &n)| vec![m, n])
+        .collect();
+    let mut c = ArrayD::zeros(shape);
+    Zip::from(&mut c)
+        .and_broadcast(&av)
+        .and_broadcast(&bv)
+        .apply(|c, &x, &y| *c = x * y);
+
+    let mut dim = a.raw_dim();
+    for (k, &n) in b.shape().iter().enumerate() {
+        dim[k] *= n;
+    }
+    c.into_shape(dim).unwrap()
+}
@@ -0,0 +1,303 @@
+// Copyright 2019 bluss and ndarray developers.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use crate::imp_prelude::*;
+use crate::linalg::error::{check_len, check_square, LinalgError};
+use crate::linalg::general_mat_mul;
+use crate::linalg::triangular::{solve_lower_inplace, solve_upper_inplace};
+use crate::{ComplexFloat, Zip};
+
+use num_traits::{Float, Zero};
+
+/// Number of columns in each panel of the blocked factorization.
+///
+/// Matrices with at most this many columns are factorized without blocking.
+const LU_BLOCK: usize = 32;
+
+/// LU factorization with partial pivoting of a square matrix.
+///
+/// The factorization is `P A = L U` where `P` is a permutation matrix, `L` is
+/// unit lower triangular and `U` is upper triangular. Both factors are stored
+/// in one matrix, with the unit diagonal of `L` left implicit.
+///
+/// Create it with [`.lu()`](../struct.ArrayBase.html#method.lu).
+#[derive(Clone, Debug)]
+pub struct LU<A> {
+    lu: Array2<A>,
+    /// Row `i` was interchanged with row `pivots[i]`, in order.
+    pivots: Vec<usize>,
+}
+
+impl<A> LU<A>
+where
+    A: ComplexFloat,
+{
+    /// Factorize `a` in place.
+    fn factorize(mut a: Array2<A>) -> Self {
+        let n = a.rows();
+        let mut pivots = Vec::with_capacity(n);
+        let mut k0 = 0;
+        while k0 < n {
+            let kb = LU_BLOCK.min(n - k0);
+            for j in k0..k0 + kb {
+                // find the pivot: the largest element of column j on or below the diagonal
+                let mut p = j;
+                let mut max = a[(j, j)].abs();
+                for i in j + 1..n {
+                    let v = a[(i, j)].abs();
+                    if v > max {
+                        p = i;
+                        max = v;
+                    }
+                }
+                pivots.push(p);
+                if p != j {
+                    swap_rows(&mut a, j, p);
+                }
+                let pivot = a[(j, j)];
+                if pivot.is_zero() {
+                    // the column is zero below the diagonal, nothing to eliminate
+                    continue;
+                }
+                let (top, mut bottom) = a.view_mut().split_at(Axis(0), j + 1);
+                let pivot_row = top.slice(s![j, j + 1..k0 + kb]);
+                for mut row in bottom.outer_iter_mut() {
+                    row[j] /= pivot;
+                    let l = row[j];
+                    row.slice_mut(s![j + 1..k0 + kb]).scaled_add(-l, &pivot_row);
+                }
+            }
+            if k0 + kb < n {
+                let (top, bottom) = a.slice_mut(s![k0.., k0..]).split_at(Axis(0), kb);
+                let (l11, mut a12) = top.split_at(Axis(1), kb);
+                // A12 ← L11⁻¹ A12
+                solve_lower_inplace(&l11.view(), a12.view_mut(), true);
+                // A22 ← A22 - L21 A12
+                let (l21, mut a22) = bottom.split_at(Axis(1), kb);
+                general_mat_mul(-A::one(), &l21, &a12, A::one(), &mut a22);
+            }
+            k0 += kb;
+        }
+        LU { lu: a, pivots }
+    }
+
+    /// Return `true` if `U` has a zero on its diagonal.
+    pub fn is_singular(&self) -> bool {
+        self.lu.diag().iter().any(|x| x.is_zero())
+    }
+
+    /// Return the row interchanges: row `i` was swapped with row `pivots()[i]`,
+    /// for `i` in increasing order.
+    pub fn pivots(&self) -> &[usize] {
+        &self.pivots
+    }
+
+    /// Return the permutation matrix `P`.
+    pub fn p(&self) -> Array2<A> {
+        let n = self.lu.rows();
+        let mut perm: Vec<usize> = (0..n).collect();
+        for (i, &p) in self.pivots.iter().enumerate() {
+            perm.swap(i, p);
+        }
+        let mut p = Array2::zeros((n, n));
+        for (i, &j) in perm.iter().enumerate() {
+            p[(i, j)] = A::one();
+        }
+        p
+    }
+
+    /// Return the unit lower triangular factor `L`.
+    pub fn l(&self) -> Array2<A> {
+        let mut l = self.lu.clone();
+        for ((i, j), x) in l.indexed_iter_mut() {
+            if i == j {
+                *x = A::one();
+            } else if j > i {
+                *x = A::zero();
+            }
+        }
+        l
+    }
+
+    /// Return the upper triangular factor `U`.
+    pub fn u(&self) -> Array2<A> {
+        let mut u = self.lu.clone();
+        for ((i, j), x) in u.indexed_iter_mut() {
+            if j < i {
+                *x = A::zero();
+            }
+        }
+        u
+    }
+
+    /// Return the determinant of the factorized matrix.
+    pub fn det(&self) -> A {
+        let swaps = self
+            .pivots
+            .iter()
+            .enumerate()
+            .filter(|&(i, &p)| i != p)
+            .count();
+        let det = self.lu.diag().iter().fold(A::one(), |acc, &x| acc * x);
+        if swaps % 2 == 0 {
+            det
+        } else {
+            -det
+        }
+    }
+
+    /// Return the natural logarithm of the absolute value of the
+    /// determinant, which does not overflow for large matrices.
+    pub(crate) fn ln_abs_det(&self) -> A::Real {
+        self.lu
+            .diag()
+            .fold(A::Real::zero(), |acc, &x| acc + x.abs().ln())
+    }
+
+    /// Solve the system `A x = b`.
+    ///
+    /// ***Errors*** if the matrix is singular or `b` has the wrong length.
+    pub fn solve<S>(&self, b: &ArrayBase<S, Ix1>) -> Result<Array1<A>, LinalgError>
+    where
+        S: Data<Elem = A>,
+    {
+        check_len(self.lu.rows(), b.len())?;
+        let mut x = b.to_owned();
+        self.solve_inplace(x.view_mut().insert_axis(Axis(1)))?;
+        Ok(x)
+    }
+
+    /// Solve the systems `A X = B` for all columns of `B` at once.
+    ///
+    /// ***Errors*** if the matrix is singular or `B` has the wrong number of
+    /// rows.
+    pub fn solve_many<S>(&self, b: &ArrayBase<S, Ix2>) -> Result<Array2<A>, LinalgError>
+    where
+        S: Data<Elem = A>,
+    {
+        check_len(self.lu.rows(), b.rows())?;
+        let mut x = b.to_owned();
+        self.solve_inplace(x.view_mut())?;
+        Ok(x)
+    }
+
+    /// Return the inverse of the factorized matrix.
+    ///
+    /// ***Errors*** if the matrix is singular.
+    pub fn inv(&self) -> Result<Array2<A>, LinalgError> {
+        let mut x = Array2::eye(self.lu.rows());
+        self.solve_inplace(x.view_mut())?;
+        Ok(x)
+    }
+
+    /// Overwrite `b` with `A⁻¹ b`.
+    fn solve_inplace(&self, mut b: ArrayViewMut2<'_, A>) -> Result<(), LinalgError> {
+        if self.is_singular() {
+            return Err(LinalgError::Singular);
+        }
+        for (i, &p) in self.pivots.iter().enumerate() {
+            if i != p {
+                swap_rows(&mut b, i, p);
+            }
+        }
+        let lu = self.lu.view();
+        solve_lower_inplace(&lu, b.view_mut(), true);
+        solve_upper_inplace(&lu, b, false);
+        Ok(())
+    }
+}
+
+/// Swap rows `i` and `j` of `a`, where `i < j`.
+fn swap_rows<A, S>(a: &mut ArrayBase<S, Ix2>, i: usize, j: usize)
+where
+    S: DataMut<Elem = A>,
+{
+    debug_assert!(i < j);
+    let (mut top, mut bottom) = a.view_mut().split_at(Axis(0), j);
+    Zip::from(top.row_mut(i))
+        .and(bottom.row_mut(0))
+        .apply(std::mem::swap);
+}
+
+/// # LU Factorization and Solvers
+impl<A, S> ArrayBase<S, Ix2>
+where
+    S: Data<Elem = A>,
+    A: ComplexFloat,
+{
+    /// Compute the LU factorization with partial pivoting of the square
+    /// matrix `self`.
+    ///
+    /// The factorization is blocked, and the trailing updates use the same
+    /// matrix multiplication as `.dot()`. It succeeds for singular matrices
+    /// too; use [`LU::is_singular`](linalg/struct.LU.html#method.is_singular)
+    /// to check.
+    ///
+    /// ***Errors*** if the matrix is not square.
+    ///
+    /// ```
+    /// use ndarray::arr2;
+    ///
+    /// let a = arr2(&[[1., 2.],
+    ///                [3., 4.]]);
+    /// let lu = a.lu().unwrap();
+    /// assert_eq!(lu.p().dot(&a), lu.l().dot(&lu.u()));
+    /// assert_eq!(lu.det(), -2.);
+    /// ```
+    pub fn lu(&self) -> Result<LU<A>, LinalgError> {
+        check_square(self.dim())?;
+        Ok(LU::factorize(self.to_owned()))
+    }
+
+    /// Solve the linear system `self x = b` for `x`.
+    ///
+    /// ***Errors*** if `self` is not square, if it is singular or if `b` has
+    /// the wrong length.
+    ///
+    /// ```
+    /// use ndarray::{arr1, arr2};
+    ///
+    /// let a = arr2(&[[3., 1.],
+    ///                [1., 2.]]);
+    /// let b = arr1(&[9., 8.]);
+    /// assert_eq!(a.solve(&b).unwrap(), arr1(&[2., 3.]));
+    /// ```
+    pub fn solve<S2>(&self, b: &ArrayBase<S2, Ix1>) -> Result<Array1<A>, LinalgError>
+    where
+        S2: Data<Elem = A>,
+    {
+        self.lu()?.solve(b)
+    }
+
+    /// Solve the linear systems `self X = B` for the columns of `X`.
+    ///
+    /// ***Errors*** if `self` is not square, if it is singular or if `b` has
+    /// the wrong number of rows.
+    pub fn solve_many<S2>(&self, b: &ArrayBase<S2, Ix2>) -> Result<Array2<A>, LinalgError>
+    where
+        S2: Data<Elem = A>,
+    {
+        self.lu()?.solve_many(b)
+    }
+
+    /// Return the inverse of the square matrix `self`.
+    ///
+    /// ***Errors*** if `self` is not square or if it is singular.
+    pub fn inv(&self) -> Result<Array2<A>, LinalgError> {
+        self.lu()?.inv()
+    }
+
+    /// Return the determinant of the square matrix `self`.
+    ///
+    /// The determinant of a singular matrix is zero, not an error.
+    ///
+    /// ***Errors*** if `self` is not square.
+    pub fn det(&self) -> Result<A, LinalgError> {
+        Ok(self.lu()?.det())
+    }
+}
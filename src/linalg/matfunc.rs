@@ -0,0 +1,313 @@
+// Copyright 2019 bluss and ndarray developers.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use crate::imp_prelude::*;
+use crate::linalg::error::{check_square, LinalgError};
+use crate::{ComplexFloat, LinalgScalar};
+
+use num_traits::{Float, NumCast, One};
+
+/// The maximum number of Denman–Beavers iterations in `sqrtm`.
+const SQRTM_MAX_ITER: usize = 100;
+
+/// The coefficients of the numerators of the [m/m] Padé approximants to the
+/// exponential, for m = 3, 5, 7, 9 and 13.
+const PADE_3: [f64; 4] = [120., 60., 12., 1.];
+const PADE_5: [f64; 6] = [30240., 15120., 3360., 420., 30., 1.];
+const PADE_7: [f64; 8] = [
+    17297280., 8648640., 1995840., 277200., 25200., 1512., 56., 1.,
+];
+const PADE_9: [f64; 10] = [
+    17643225600.,
+    8821612800.,
+    2075673600.,
+    302702400.,
+    30270240.,
+    2162160.,
+    110880.,
+    3960.,
+    90.,
+    1.,
+];
+const PADE_13: [f64; 14] = [
+    64764752532480000.,
+    32382376266240000.,
+    7771770303897600.,
+    1187353796428800.,
+    129060195264000.,
+    10559470521600.,
+    670442572800.,
+    33522128640.,
+    1323241920.,
+    40840800.,
+    960960.,
+    16380.,
+    182.,
+    1.,
+];
+
+/// The largest 1-norms for which the Padé approximants of degree 3, 5, 7, 9
+/// and 13 are accurate to double precision (Higham, 2005).
+const THETA_F64: [f64; 5] = [
+    1.495585217958292e-2,
+    2.53939833006323e-1,
+    9.504178996162932e-1,
+    2.097847961257068e0,
+    5.371920351148152e0,
+];
+
+/// The largest 1-norms for which the Padé approximants of degree 3, 5 and 7
+/// are accurate to single precision.
+const THETA_F32: [f64; 3] = [
+    4.258730016922831e-1,
+    1.880152677804762e0,
+    3.92572478313866e0,
+];
+
+/// # Matrix Functions
+impl<A, S> ArrayBase<S, Ix2>
+where
+    S: Data<Elem = A>,
+    A: LinalgScalar,
+{
+    /// Return the `n`th power of the square matrix, computed by repeated
+    /// squaring with [`.dot()`](#method.dot).
+    ///
+    /// The zeroth power is the identity matrix.
+    ///
+    /// ***Errors*** if the matrix is not square.
+    ///
+    /// ```
+    /// use ndarray::arr2;
+    ///
+    /// let fib = arr2(&[[1, 1],
+    ///                  [1, 0]]);
+    /// assert_eq!(fib.powm(10).unwrap(), arr2(&[[89, 55],
+    ///                                          [55, 34]]));
+    /// ```
+    pub fn powm(&self, n: usize) -> Result<Array2<A>, LinalgError> {
+        check_square(self.dim())?;
+        Ok(powm(self.to_owned(), n))
+    }
+}
+
+/// # Matrix Functions
+impl<A, S> ArrayBase<S, Ix2>
+where
+    S: Data<Elem = A>,
+    A: ComplexFloat,
+{
+    /// Return the matrix exponential of the square matrix.
+    ///
+    /// Uses scaling and squaring with the Padé approximants of degree 3, 5,
+    /// 7, 9 or 13, chosen from the 1-norm of the matrix as in Higham's
+    /// algorithm (*The Scaling and Squaring Method for the Matrix
+    /// Exponential Revisited*, 2005).
+    ///
+    /// ***Errors*** if the matrix is not square.
+    ///
+    /// ```
+    /// use ndarray::arr2;
+    ///
+    /// let t = std::f64::consts::FRAC_PI_2;
+    /// let rotation = arr2(&[[0., -t],
+    ///                       [t, 0.]]).expm().unwrap();
+    /// let expected = arr2(&[[0., -1.],
+    ///                       [1., 0.]]);
+    /// assert!((rotation - expected).iter().all(|x: &f64| x.abs() < 1e-15));
+    /// ```
+    pub fn expm(&self) -> Result<Array2<A>, LinalgError> {
+        let n = check_square(self.dim())?;
+        let norm = self.norm_1();
+        if !norm.is_finite() {
+            let nan = A::from_real(A::Real::nan());
+            return Ok(Array2::from_elem((n, n), nan));
+        }
+        let single = A::Real::epsilon() > NumCast::from(1e-10).unwrap();
+        let (thetas, coefs): (&[f64], &[&[f64]]) = if single {
+            (&THETA_F32, &[&PADE_3, &PADE_5, &PADE_7])
+        } else {
+            (&THETA_F64, &[&PADE_3, &PADE_5, &PADE_7, &PADE_9, &PADE_13])
+        };
+        let last = thetas.len() - 1;
+        for (&theta, &b) in thetas[..last].iter().zip(coefs) {
+            if norm <= cast(theta) {
+                return Ok(pade(&self.view(), b));
+            }
+        }
+        // scale so that the norm is at most the largest theta, then square
+        let ratio = norm / cast(thetas[last]);
+        let squarings = if ratio > A::Real::one() {
+            NumCast::from(ratio.log2().ceil()).unwrap()
+        } else {
+            0
+        };
+        let scale = A::from_real(cast::<A::Real>(2.).powi(-squarings));
+        let scaled = self.mapv(|x| x * scale);
+        let mut e = if single {
+            pade(&scaled.view(), coefs[last])
+        } else {
+            pade13(&scaled.view())
+        };
+        for _ in 0..squarings {
+            e = e.dot(&e);
+        }
+        Ok(e)
+    }
+
+    /// Return the principal square root of the square matrix, the matrix
+    /// `X` with `X X = self` whose eigenvalues have positive real part.
+    ///
+    /// Uses the scaled product form of the Denman–Beavers iteration. The
+    /// principal square root exists if the matrix has no eigenvalues on the
+    /// closed negative real axis; for real matrices it is real if it exists.
+    ///
+    /// ***Errors*** with `LinalgError::NotSquare` if the matrix is not square,
+    /// with `LinalgError::Singular` if it is singular, and with
+    /// `LinalgError::NotConverged` if the iteration does not converge, as
+    /// for real matrices with negative real eigenvalues.
+    ///
+    /// ```
+    /// use ndarray::arr2;
+    ///
+    /// let a = arr2(&[[4., 12.],
+    ///                [0., 16.]]);
+    /// let x = a.sqrtm().unwrap();
+    /// assert!((&x - &arr2(&[[2., 2.],
+    ///                       [0., 4.]])).iter().all(|e: &f64| e.abs() < 1e-14));
+    /// ```
+    pub fn sqrtm(&self) -> Result<Array2<A>, LinalgError> {
+        let n = check_square(self.dim())?;
+        let eye = Array2::<A>::eye(n);
+        let tol = cast::<A::Real>(n as f64) * A::Real::epsilon();
+        let half = A::from_real(cast(0.5));
+        let quarter = A::from_real(cast(0.25));
+        let mut m = self.to_owned();
+        let mut y = self.to_owned();
+        let mut prev_err = A::Real::infinity();
+        for _ in 0..SQRTM_MAX_ITER {
+            let err = (&m - &eye).norm_fro();
+            // stop when converged, or when rounding errors stop the progress
+            if err <= tol || (err <= Float::sqrt(tol) && err >= prev_err) {
+                return Ok(y);
+            }
+            prev_err = err;
+            let lu = m.lu()?;
+            let m_inv = lu.inv()?;
+            // determinant scaling, until the iteration is close to converging
+            let mu = if err > cast(1e-2) {
+                (-lu.ln_abs_det() / cast(2. * n as f64)).exp()
+            } else {
+                A::Real::one()
+            };
+            let mu2 = A::from_real(mu * mu);
+            let mu2_inv = A::from_real((mu * mu).recip());
+            y = y.dot(&(&eye + &(m_inv.mapv(|x| x * mu2_inv)))) * A::from_real(mu) * half;
+            m.zip_mut_with(&m_inv, |m, &m_inv| {
+                *m = (*m * mu2 + m_inv * mu2_inv) * quarter
+            });
+            m.diag_mut().map_inplace(|x| *x += half);
+            if !err.is_finite() {
+                break;
+            }
+        }
+        Err(LinalgError::NotConverged)
+    }
+}
+
+/// Return `a` to the `n`th power, by repeated squaring.
+fn powm<A: LinalgScalar>(mut a: Array2<A>, mut n: usize) -> Array2<A> {
+    let mut result: Option<Array2<A>> = None;
+    while n > 0 {
+        if n & 1 == 1 {
+            result = Some(match result {
+                Some(r) => r.dot(&a),
+                None => a.clone(),
+            });
+        }
+        n >>= 1;
+        if n > 0 {
+            a = a.dot(&a);
+        }
+    }
+    result.unwrap_or_else(|| Array2::eye(a.rows()))
+}
+
+fn cast<R: NumCast>(x: f64) -> R {
+    NumCast::from(x).unwrap()
+}
+
+/// Return `Σ c[k] aᵏ` for the powers in `powers`, where `powers[k]` is `aᵏ`
+/// and `powers[0]` is omitted (the identity).
+fn poly<A: ComplexFloat>(n: usize, coefs: &[f64], powers: &[&Array2<A>]) -> Array2<A> {
+    let mut p = Array2::<A>::eye(n) * A::from_real(cast(coefs[0]));
+    for (&c, a) in coefs[1..].iter().zip(powers) {
+        p.scaled_add(A::from_real(cast(c)), *a);
+    }
+    p
+}
+
+/// Return the [m/m] Padé approximant to `exp(a)` with numerator
+/// coefficients `b`, for odd m.
+fn pade<A: ComplexFloat>(a: &ArrayView2<'_, A>, b: &[f64]) -> Array2<A> {
+    let n = a.rows();
+    let a2 = a.dot(a);
+    // the even powers a², a⁴, …, up to the degree m - 1
+    let mut even = vec![a2];
+    while 2 * even.len() + 2 < b.len() {
+        let next = even[even.len() - 1].dot(&even[0]);
+        even.push(next);
+    }
+    let even_refs = even.iter().collect::<Vec<_>>();
+    let odd_coefs = b.iter().skip(1).step_by(2).cloned().collect::<Vec<_>>();
+    let even_coefs = b.iter().step_by(2).cloned().collect::<Vec<_>>();
+    let u = a.dot(&poly(n, &odd_coefs, &even_refs));
+    let v = poly(n, &even_coefs, &even_refs);
+    solve_pade(u, v)
+}
+
+/// Return the degree 13 Padé approximant to `exp(a)`, evaluated with the
+/// minimal number of matrix products.
+fn pade13<A: ComplexFloat>(a: &ArrayView2<'_, A>) -> Array2<A> {
+    let b = &PADE_13;
+    let c = |k: usize| A::from_real(cast(b[k]));
+    let n = a.rows();
+    let a2 = a.dot(a);
+    let a4 = a2.dot(&a2);
+    let a6 = a4.dot(&a2);
+    let mut u_inner = &a6 * c(13);
+    u_inner.scaled_add(c(11), &a4);
+    u_inner.scaled_add(c(9), &a2);
+    let mut u_outer = a6.dot(&u_inner);
+    u_outer.scaled_add(c(7), &a6);
+    u_outer.scaled_add(c(5), &a4);
+    u_outer.scaled_add(c(3), &a2);
+    u_outer.diag_mut().map_inplace(|x| *x += c(1));
+    let u = a.dot(&u_outer);
+    let mut v_inner = &a6 * c(12);
+    v_inner.scaled_add(c(10), &a4);
+    v_inner.scaled_add(c(8), &a2);
+    let mut v = a6.dot(&v_inner);
+    v.scaled_add(c(6), &a6);
+    v.scaled_add(c(4), &a4);
+    v.scaled_add(c(2), &a2);
+    v.diag_mut().map_inplace(|x| *x += c(0));
+    debug_assert_eq!(v.rows(), n);
+    solve_pade(u, v)
+}
+
+/// Return `(v - u)⁻¹ (v + u)`.
+fn solve_pade<A: ComplexFloat>(u: Array2<A>, v: Array2<A>) -> Array2<A> {
+    let p = &v + &u;
+    let q = v - u;
+    // q is well conditioned for the norms that select each approximant
+    match q.lu().and_then(|lu| lu.solve_many(&p)) {
+        Ok(x) => x,
+        Err(_) => Array2::from_elem(p.dim(), A::from_real(A::Real::nan())),
+    }
+}
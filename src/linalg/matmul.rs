@@ -0,0 +1,250 @@
+// Copyright 2019 bluss and ndarray developers.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use crate::imp_prelude::*;
+use crate::linalg::impl_linalg::mat_mul_impl;
+use crate::LinalgScalar;
+
+#[cfg(feature = "rayon")]
+use crate::parallel::prelude::*;
+
+/// Batched matrix multiplication, following the semantics of numpy's
+/// `matmul`.
+///
+/// The last two axes of each operand are the matrices that are multiplied,
+/// and the leading *batch* axes are broadcast together, so that an operand
+/// with fewer batch axes, or with a batch axis of length one, is reused for
+/// each matrix of the other operand.
+///
+/// For dynamic-dimensional arrays, a one-dimensional left operand is treated
+/// as a row vector and a one-dimensional right operand as a column vector;
+/// the added axis is removed from the result.
+///
+/// ***Note:*** `MatMul` is not an extension interface at this point.
+/// Traits in Rust can serve many different roles. This trait is public because
+/// it is used as a bound on public methods.
+pub trait MatMul<Rhs> {
+    /// The result of the operation.
+    type Output;
+    /// Compute the batched matrix product of `self` and `rhs`.
+    fn matmul(&self, rhs: &Rhs) -> Self::Output;
+    /// Compute the batched matrix product of `self` and `rhs`, multiplying
+    /// the matrices of different batches in parallel.
+    ///
+    /// Requires crate feature `rayon`.
+    #[cfg(feature = "rayon")]
+    fn par_matmul(&self, rhs: &Rhs) -> Self::Output;
+
+    private_decl! {}
+}
+
+macro_rules! impl_matmul {
+    ($lhs:ty, $rhs:ty, $out:ty) => {
+        impl<A, S, S2> MatMul<ArrayBase<S2, $rhs>> for ArrayBase<S, $lhs>
+        where
+            S: Data<Elem = A>,
+            S2: Data<Elem = A>,
+            A: LinalgScalar + Send + Sync,
+        {
+            type Output = Array<A, $out>;
+
+            fn matmul(&self, rhs: &ArrayBase<S2, $rhs>) -> Self::Output {
+                matmul_dyn(self.view().into_dyn(), rhs.view().into_dyn(), false)
+                    .into_dimensionality()
+                    .unwrap()
+            }
+
+            #[cfg(feature = "rayon")]
+            fn par_matmul(&self, rhs: &ArrayBase<S2, $rhs>) -> Self::Output {
+                matmul_dyn(self.view().into_dyn(), rhs.view().into_dyn(), true)
+                    .into_dimensionality()
+                    .unwrap()
+            }
+
+            private_impl! {}
+        }
+    };
+}
+
+impl_matmul!(Ix2, Ix2, Ix2);
+impl_matmul!(Ix2, Ix3, Ix3);
+impl_matmul!(Ix3, Ix2, Ix3);
+impl_matmul!(Ix3, Ix3, Ix3);
+impl_matmul!(IxDyn, IxDyn, IxDyn);
+
+/// Batched matrix multiplication.
+///
+/// This is the function form of
+/// [`.matmul()`](../struct.ArrayBase.html#method.matmul).
+///
+/// **Panics** if the shapes are incompatible.
+pub fn matmul<Lhs, Rhs>(a: &Lhs, b: &Rhs) -> Lhs::Output
+where
+    Lhs: MatMul<Rhs>,
+{
+    a.matmul(b)
+}
+
+/// # Batched Matrix Multiplication
+impl<A, S, D> ArrayBase<S, D>
+where
+    S: Data<Elem = A>,
+    D: Dimension,
+{
+    /// Perform batched matrix multiplication of `self` and `rhs`, which
+    /// follows the semantics of numpy's `matmul`. See the
+    /// [`MatMul`](linalg/trait.MatMul.html) trait for the supported
+    /// dimensionalities.
+    ///
+    /// The matrices are the last two axes of each operand, and the leading
+    /// batch axes are broadcast together. Each matrix product is computed
+    /// directly from the (possibly strided or broadcast) inputs, in the same
+    /// way as [`.dot()`](#method.dot).
+    ///
+    /// **Panics** if the matrix shapes are not compatible for multiplication,
+    /// or if the batch axes can not be broadcast together.
+    ///
+    /// ```
+    /// use ndarray::{arr2, Array};
+    ///
+    /// // a stack of two 2 × 3 matrices
+    /// let a = Array::from_shape_fn((2, 2, 3), |(b, i, j)| (b + i + j) as f64);
+    /// let m = arr2(&[[1., 0.],
+    ///                [0., 1.],
+    ///                [1., 1.]]);
+    /// let c = a.matmul(&m);
+    /// assert_eq!(c.shape(), &[2, 2, 2]);
+    /// for (ab, cb) in a.outer_iter().zip(c.outer_iter()) {
+    ///     assert_eq!(ab.dot(&m), cb);
+    /// }
+    /// ```
+    pub fn matmul<Rhs>(&self, rhs: &Rhs) -> <Self as MatMul<Rhs>>::Output
+    where
+        Self: MatMul<Rhs>,
+    {
+        MatMul::matmul(self, rhs)
+    }
+}
+
+/// Return the broadcast shape of the batch shapes `a` and `b`, or `None` if
+/// they are incompatible.
+fn broadcast_batch(a: &[usize], b: &[usize]) -> Option<Vec<usize>> {
+    let n = a.len().max(b.len());
+    let get = |s: &[usize], i: usize| {
+        if i + s.len() < n {
+            1
+        } else {
+            s[i + s.len() - n]
+        }
+    };
+    (0..n)
+        .map(|i| match (get(a, i), get(b, i)) {
+            (x, y) if x == y => Some(x),
+            (1, y) => Some(y),
+            (x, 1) => Some(x),
+            _ => None,
+        })
+        .collect()
+}
+
+fn matmul_dyn<A>(a: ArrayViewD<'_, A>, b: ArrayViewD<'_, A>, parallel: bool) -> ArrayD<A>
+where
+    A: LinalgScalar + Send + Sync,
+{
+    if a.ndim() == 0 || b.ndim() == 0 {
+        panic!("ndarray: matmul operands must have at least one axis");
+    }
+    let a_vector = a.ndim() == 1;
+    let b_vector = b.ndim() == 1;
+    let a = if a_vector { a.insert_axis(Axis(0)) } else { a };
+    let b = if b_vector { b.insert_axis(Axis(1)) } else { b };
+    let (a_batch, a_mat) = a.shape().split_at(a.ndim() - 2);
+    let (b_batch, b_mat) = b.shape().split_at(b.ndim() - 2);
+    let ((m, k), (k2, n)) = ((a_mat[0], a_mat[1]), (b_mat[0], b_mat[1]));
+    if k != k2 {
+        panic!(
+            "ndarray: inputs {:?} and {:?} are not compatible for matrix multiplication",
+            a.shape(),
+            b.shape()
+        );
+    }
+    let batch = match broadcast_batch(a_batch, b_batch) {
+        Some(batch) => batch,
+        None => panic!(
+            "ndarray: could not broadcast the batch axes of {:?} and {:?} together",
+            a.shape(),
+            b.shape()
+        ),
+    };
+    let shape_with = |r, c| {
+        let mut shape = batch.clone();
+        shape.push(r);
+        shape.push(c);
+        IxDyn(&shape)
+    };
+    let a = a.broadcast(shape_with(m, k)).unwrap();
+    let b = b.broadcast(shape_with(k, n)).unwrap();
+    let mut c = ArrayD::zeros(shape_with(m, n));
+    match parallel {
+        #[cfg(feature = "rayon")]
+        true => par_mat_mul_batches(a, b, c.view_mut()),
+        _ => mat_mul_batches(a, b, c.view_mut()),
+    }
+
+    if a_vector {
+        let axis = c.ndim() - 2;
+        c = c.index_axis_move(Axis(axis), 0);
+    }
+    if b_vector {
+        let axis = c.ndim() - 1;
+        c = c.index_axis_move(Axis(axis), 0);
+    }
+    c
+}
+
+/// C ← A B for each matrix in the batch; all arrays must have the same
+/// batch shape.
+fn mat_mul_batches<A>(a: ArrayViewD<'_, A>, b: ArrayViewD<'_, A>, mut c: ArrayViewMutD<'_, A>)
+where
+    A: LinalgScalar,
+{
+    if c.ndim() == 2 {
+        mat_mul_single(a, b, c);
+    } else {
+        for ((a, b), c) in a.outer_iter().zip(b.outer_iter()).zip(c.outer_iter_mut()) {
+            mat_mul_batches(a, b, c);
+        }
+    }
+}
+
+/// Parallel version of `mat_mul_batches`.
+#[cfg(feature = "rayon")]
+fn par_mat_mul_batches<A>(a: ArrayViewD<'_, A>, b: ArrayViewD<'_, A>, mut c: ArrayViewMutD<'_, A>)
+where
+    A: LinalgScalar + Send + Sync,
+{
+    if c.ndim() == 2 {
+        mat_mul_single(a, b, c);
+    } else {
+        a.outer_iter()
+            .into_par_iter()
+            .zip(b.outer_iter())
+            .zip(c.outer_iter_mut())
+            .for_each(|((a, b), c)| par_mat_mul_batches(a, b, c));
+    }
+}
+
+fn mat_mul_single<A>(a: ArrayViewD<'_, A>, b: ArrayViewD<'_, A>, c: ArrayViewMutD<'_, A>)
+where
+    A: LinalgScalar,
+{
+    let a = a.into_dimensionality::<Ix2>().unwrap();
+    let b = b.into_dimensionality::<Ix2>().unwrap();
+    let mut c = c.into_dimensionality::<Ix2>().unwrap();
+    mat_mul_impl(A::one(), &a, &b, A::zero(), &mut c);
+}
@@ -9,7 +9,40 @@
 //! Linear algebra.
 
 pub use self::impl_linalg::general_mat_mul;
+pub use self::impl_linalg::general_mat_mul_trans;
 pub use self::impl_linalg::general_mat_vec_mul;
 pub use self::impl_linalg::Dot;
 
+pub use self::einsum::einsum;
+pub use self::error::LinalgError;
+pub use self::int_gemm::{general_mat_mul_widening, Widen};
+pub use self::iterative::{
+    bicgstab, cg, gmres, IterativeSolution, Jacobi, LinearOperator, SolverOptions,
+};
+pub use self::kron::{kron, outer};
+pub use self::lu::LU;
+pub use self::matmul::{matmul, MatMul};
+pub use self::norm::Norm;
+pub use self::qr::{LeastSquares, QRMode, QR};
+pub use self::svd::SVDMode;
+pub use self::tensordot::tensordot;
+pub use self::triangular::{Diag, Transpose, UPLO};
+
+mod cholesky;
+mod eigh;
+mod einsum;
+mod error;
+mod householder;
 mod impl_linalg;
+mod int_gemm;
+mod iterative;
+mod kron;
+mod lu;
+mod matfunc;
+mod matmul;
+mod norm;
+mod qr;
+mod svd;
+mod tensordot;
+mod triangular;
+mod util;
@@ -0,0 +1,266 @@
+// Copyright 2019 bluss and ndarray developers.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use crate::imp_prelude::*;
+#[cfg(feature = "blas")]
+use crate::linalg::impl_linalg::blas_nrm2;
+use crate::ComplexFloat;
+
+use num_traits::{Float, NumCast, One, Zero};
+
+/// A vector norm, selecting the norm used by
+/// [`.normalize()`](../struct.ArrayBase.html#method.normalize).
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum Norm {
+    /// The sum of absolute values.
+    L1,
+    /// The Euclidean norm.
+    L2,
+    /// The maximum absolute value.
+    Max,
+    /// The *p*-norm, for *p* > 0.
+    Lp(f64),
+}
+
+/// # Vector Norms
+///
+/// The norms treat the elements of the whole array as one vector; the
+/// `_axis` variants compute the norm of each lane along an axis.
+impl<A, S, D> ArrayBase<S, D>
+where
+    S: Data<Elem = A>,
+    D: Dimension,
+    A: ComplexFloat,
+{
+    /// Return the L1 norm of the elements, the sum of their absolute values.
+    pub fn norm_l1(&self) -> A::Real {
+        self.fold(A::Real::zero(), |acc, x| acc + x.abs())
+    }
+
+    /// Return the L2 (Euclidean) norm of the elements, the square root of
+    /// the sum of their squared absolute values.
+    ///
+    /// The elements are scaled by the largest absolute value, so that the
+    /// result does not overflow or underflow unless the norm itself is not
+    /// representable.
+    ///
+    /// *Note:* If enabled, uses blas `nrm2` for elements of `f32, f64` and
+    /// `Complex<f32>, Complex<f64>` when memory layout allows.
+    ///
+    /// ```
+    /// use ndarray::arr1;
+    ///
+    /// let a = arr1(&[3e200f64, 4e200]);
+    /// assert!((a.norm_l2() / 5e200 - 1.).abs() < 1e-15);
+    /// ```
+    pub fn norm_l2(&self) -> A::Real {
+        #[cfg(feature = "blas")]
+        {
+            let norm = match self.as_slice_memory_order() {
+                Some(slice) => blas_nrm2(&aview1(slice)),
+                None => match self.view().into_dimensionality::<Ix1>() {
+                    Ok(v) => blas_nrm2(&v),
+                    Err(_) => None,
+                },
+            };
+            if let Some(norm) = norm {
+                return norm;
+            }
+        }
+        let amax = self.norm_max();
+        if amax.is_zero() || !amax.is_finite() {
+            return amax;
+        }
+        let r = amax.recip();
+        let ssq = self.fold(A::Real::zero(), |acc, x| acc + x.scale(r).abs_sqr());
+        amax * Float::sqrt(ssq)
+    }
+
+    /// Return the maximum norm of the elements, their largest absolute
+    /// value.
+    ///
+    /// The result is NaN if any element is NaN, and zero if the array is
+    /// empty.
+    pub fn norm_max(&self) -> A::Real {
+        self.fold(A::Real::zero(), |acc, x| max_nan(acc, x.abs()))
+    }
+
+    /// Return the *p*-norm of the elements, `(Σ |x|ᵖ)^(1/p)`.
+    ///
+    /// For *p* = ∞ this is the maximum norm. The elements are scaled by the
+    /// largest absolute value in the same way as for the L2 norm.
+    ///
+    /// **Panics** if `p` is not positive.
+    ///
+    /// ```
+    /// use ndarray::arr1;
+    ///
+    /// let a = arr1(&[-2f64, 2., 0.]);
+    /// assert!((a.norm_lp(3.) - 16f64.cbrt()).abs() < 1e-15);
+    /// assert_eq!(a.norm_lp(1.), a.norm_l1());
+    /// ```
+    pub fn norm_lp(&self, p: A::Real) -> A::Real {
+        assert!(
+            p > A::Real::zero(),
+            "ndarray: p must be positive in norm_lp"
+        );
+        let one = A::Real::one();
+        if p == one {
+            return self.norm_l1();
+        } else if p == one + one {
+            return self.norm_l2();
+        } else if p.is_infinite() {
+            return self.norm_max();
+        }
+        let amax = self.norm_max();
+        if amax.is_zero() || !amax.is_finite() {
+            return amax;
+        }
+        let r = amax.recip();
+        let sum = self.fold(A::Real::zero(), |acc, x| acc + (x.abs() * r).powf(p));
+        amax * sum.powf(p.recip())
+    }
+
+    /// Return the L1 norm of each lane along `axis`.
+    ///
+    /// **Panics** if `axis` is out of bounds.
+    pub fn norm_l1_axis(&self, axis: Axis) -> Array<A::Real, D::Smaller>
+    where
+        D: RemoveAxis,
+    {
+        self.map_axis(axis, |lane| lane.norm_l1())
+    }
+
+    /// Return the L2 norm of each lane along `axis`.
+    ///
+    /// **Panics** if `axis` is out of bounds.
+    ///
+    /// ```
+    /// use ndarray::{arr1, arr2, Axis};
+    ///
+    /// let a = arr2(&[[3., 0.],
+    ///                [4., -1.]]);
+    /// assert_eq!(a.norm_l2_axis(Axis(0)), arr1(&[5., 1.]));
+    /// ```
+    pub fn norm_l2_axis(&self, axis: Axis) -> Array<A::Real, D::Smaller>
+    where
+        D: RemoveAxis,
+    {
+        self.map_axis(axis, |lane| lane.norm_l2())
+    }
+
+    /// Return the maximum norm of each lane along `axis`.
+    ///
+    /// **Panics** if `axis` is out of bounds.
+    pub fn norm_max_axis(&self, axis: Axis) -> Array<A::Real, D::Smaller>
+    where
+        D: RemoveAxis,
+    {
+        self.map_axis(axis, |lane| lane.norm_max())
+    }
+
+    /// Return the *p*-norm of each lane along `axis`.
+    ///
+    /// **Panics** if `axis` is out of bounds or if `p` is not positive.
+    pub fn norm_lp_axis(&self, axis: Axis, p: A::Real) -> Array<A::Real, D::Smaller>
+    where
+        D: RemoveAxis,
+    {
+        self.map_axis(axis, |lane| lane.norm_lp(p))
+    }
+
+    /// Return the norm of the elements selected by `norm`.
+    ///
+    /// **Panics** if `norm` is `Norm::Lp(p)` and `p` is not positive.
+    pub fn norm(&self, norm: Norm) -> A::Real {
+        match norm {
+            Norm::L1 => self.norm_l1(),
+            Norm::L2 => self.norm_l2(),
+            Norm::Max => self.norm_max(),
+            Norm::Lp(p) => self.norm_lp(NumCast::from(p).unwrap()),
+        }
+    }
+
+    /// Divide each lane along `axis` by its norm, so that the lanes become
+    /// unit vectors in the selected norm. Lanes with norm zero are left
+    /// unchanged.
+    ///
+    /// For a matrix, `Axis(0)` normalizes each column and `Axis(1)` each row.
+    ///
+    /// **Panics** if `axis` is out of bounds, or if `norm` is `Norm::Lp(p)`
+    /// and `p` is not positive.
+    ///
+    /// ```
+    /// use ndarray::{arr2, Axis};
+    /// use ndarray::linalg::Norm;
+    ///
+    /// let mut a = arr2(&[[3., 0.],
+    ///                    [4., 2.]]);
+    /// a.normalize(Axis(0), Norm::L2);
+    /// assert_eq!(a, arr2(&[[0.6, 0.],
+    ///                      [0.8, 1.]]));
+    /// a.normalize(Axis(1), Norm::Max);
+    /// assert_eq!(a, arr2(&[[1., 0.],
+    ///                      [0.8, 1.]]));
+    /// ```
+    pub fn normalize(&mut self, axis: Axis, norm: Norm)
+    where
+        S: DataMut,
+        D: RemoveAxis,
+    {
+        for mut lane in self.lanes_mut(axis) {
+            let n = lane.norm(norm);
+            if !n.is_zero() {
+                let n = A::from_real(n);
+                lane.map_inplace(|x| *x /= n);
+            }
+        }
+    }
+}
+
+/// # Matrix Norms
+impl<A, S> ArrayBase<S, Ix2>
+where
+    S: Data<Elem = A>,
+    A: ComplexFloat,
+{
+    /// Return the Frobenius norm of the matrix, the L2 norm of its elements.
+    pub fn norm_fro(&self) -> A::Real {
+        self.norm_l2()
+    }
+
+    /// Return the matrix norm induced by the vector L1 norm, which is the
+    /// largest L1 norm of a column.
+    ///
+    /// ```
+    /// use ndarray::arr2;
+    ///
+    /// let a = arr2(&[[1., -7.],
+    ///                [-2., -3.]]);
+    /// assert_eq!(a.norm_1(), 10.);
+    /// assert_eq!(a.norm_inf(), 8.);
+    /// ```
+    pub fn norm_1(&self) -> A::Real {
+        self.norm_l1_axis(Axis(0)).norm_max()
+    }
+
+    /// Return the matrix norm induced by the vector maximum norm, which is
+    /// the largest L1 norm of a row.
+    pub fn norm_inf(&self) -> A::Real {
+        self.norm_l1_axis(Axis(1)).norm_max()
+    }
+}
+
+/// Return the larger of `a` and `b`, or NaN if either is NaN.
+fn max_nan<R: Float>(a: R, b: R) -> R {
+    if b > a || b.is_nan() {
+        b
+    } else {
+        a
+    }
+}
@@ -0,0 +1,355 @@
+// Copyright 2019 bluss and ndarray developers.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use crate::imp_prelude::*;
+use crate::linalg::error::{check_len, LinalgError};
+use crate::linalg::general_mat_mul;
+use crate::linalg::householder::{self, apply_left, reflector};
+use crate::linalg::triangular::{solve_lower_inplace, solve_upper_inplace};
+use crate::linalg::util::{conj_t, rhs_from_matrix, rhs_matrix};
+use crate::{ComplexFloat, Zip};
+
+use num_traits::{Float, NumCast, Zero};
+
+/// Number of columns in each panel of the blocked factorization.
+const QR_BLOCK: usize = 32;
+
+/// The shapes of the factors computed from a [`QR`](struct.QR.html)
+/// factorization of an *M* × *N* matrix, where *K* = min(*M*, *N*).
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum QRMode {
+    /// `Q` is *M* × *K* and `R` is *K* × *N*.
+    Reduced,
+    /// `Q` is *M* × *M* and `R` is *M* × *N*.
+    Complete,
+}
+
+/// Householder QR factorization of a matrix.
+///
+/// The factorization is `A = Q R` where `Q` has orthonormal columns (unitary,
+/// for complex elements) and `R` is upper triangular. `Q` is stored as
+/// a product of elementary reflectors and is only formed when requested.
+///
+/// Create it with [`.qr()`](../struct.ArrayBase.html#method.qr).
+#[derive(Clone, Debug)]
+pub struct QR<A> {
+    /// `R` on and above the diagonal, reflector vectors below it
+    qr: Array2<A>,
+    tau: Array1<A>,
+}
+
+impl<A> QR<A>
+where
+    A: ComplexFloat,
+{
+    /// Return the orthogonal factor `Q`.
+    pub fn q(&self, mode: QRMode) -> Array2<A> {
+        let (m, n) = self.qr.dim();
+        let k = m.min(n);
+        let cols = match mode {
+            QRMode::Reduced => k,
+            QRMode::Complete => m,
+        };
+        let mut q = Array2::zeros((m, cols));
+        for i in 0..cols {
+            q[(i, i)] = A::one();
+        }
+        // Q = H₀ H₁ … Hₖ₋₁ I
+        for j in (0..k).rev() {
+            let v = householder::vector(&self.qr.slice(s![j + 1.., j]));
+            apply_left(&v.view(), self.tau[j], q.slice_mut(s![j.., j..]));
+        }
+        q
+    }
+
+    /// Return the upper triangular (or trapezoidal) factor `R`.
+    pub fn r(&self, mode: QRMode) -> Array2<A> {
+        let (m, n) = self.qr.dim();
+        let rows = match mode {
+            QRMode::Reduced => m.min(n),
+            QRMode::Complete => m,
+        };
+        let mut r = Array2::zeros((rows, n));
+        for ((i, j), x) in r.indexed_iter_mut() {
+            if j >= i {
+                *x = self.qr[(i, j)];
+            }
+        }
+        r
+    }
+
+    /// Return `(Q, R)`.
+    pub fn q_r(&self, mode: QRMode) -> (Array2<A>, Array2<A>) {
+        (self.q(mode), self.r(mode))
+    }
+}
+
+/// Factorize `a` in place and return the reflector scalars.
+///
+/// The panels are factorized one column at a time, and the rest of the
+/// matrix is updated with the block reflector `I - V T Vᴴ` of each panel
+/// using matrix multiplication.
+fn factorize<A>(a: &mut Array2<A>) -> Array1<A>
+where
+    A: ComplexFloat,
+{
+    let (m, n) = a.dim();
+    let k = m.min(n);
+    let mut tau = Array1::zeros(k);
+    let mut j0 = 0;
+    while j0 < k {
+        let jb = QR_BLOCK.min(k - j0);
+        for j in j0..j0 + jb {
+            tau[j] = reflect_column(a, j, j0 + jb);
+        }
+        if j0 + jb < n {
+            let (v, t) =
+                block_reflector(&a.slice(s![j0.., j0..j0 + jb]), &tau.slice(s![j0..j0 + jb]));
+            let mut c = a.slice_mut(s![j0.., j0 + jb..]);
+            // C ← (I - V T Vᴴ)ᴴ C = C - V Tᴴ Vᴴ C
+            let mut w = Array2::zeros((jb, c.cols()));
+            general_mat_mul(A::one(), &conj_t(&v), &c, A::zero(), &mut w);
+            let w = conj_t(&t).dot(&w);
+            general_mat_mul(-A::one(), &v, &w, A::one(), &mut c);
+        }
+        j0 += jb;
+    }
+    tau
+}
+
+/// Zero column `j` of `a` below the diagonal and apply the reflector to
+/// columns `j + 1..end`; return τ.
+fn reflect_column<A>(a: &mut Array2<A>, j: usize, end: usize) -> A
+where
+    A: ComplexFloat,
+{
+    let alpha = a[(j, j)];
+    let (beta, tau) = reflector(alpha, a.slice_mut(s![j + 1.., j]));
+    a[(j, j)] = beta;
+    if j + 1 < end {
+        let v = householder::vector(&a.slice(s![j + 1.., j]));
+        apply_left(&v.view(), tau.conj(), a.slice_mut(s![j.., j + 1..end]));
+    }
+    tau
+}
+
+/// Return `(V, T)` such that `H₀ H₁ … Hₖ₋₁ = I - V T Vᴴ`, for the reflectors
+/// stored in the columns of `panel`.
+fn block_reflector<A>(panel: &ArrayView2<'_, A>, tau: &ArrayView1<'_, A>) -> (Array2<A>, Array2<A>)
+where
+    A: ComplexFloat,
+{
+    let (m, k) = panel.dim();
+    let mut v = Array2::zeros((m, k));
+    for ((i, j), x) in v.indexed_iter_mut() {
+        if i == j {
+            *x = A::one();
+        } else if i > j {
+            *x = panel[(i, j)];
+        }
+    }
+    let mut t = Array2::zeros((k, k));
+    for i in 0..k {
+        t[(i, i)] = tau[i];
+        if i > 0 {
+            // T[..i, i] = -τᵢ T[..i, ..i] V[.., ..i]ᴴ vᵢ
+            let z = conj_t(&v.slice(s![.., ..i])).dot(&v.column(i));
+            let z = t.slice(s![..i, ..i]).dot(&z) * -tau[i];
+            t.slice_mut(s![..i, i]).assign(&z);
+        }
+    }
+    (v, t)
+}
+
+/// Factorize `a` in place with column pivoting; return the reflector scalars
+/// and the column permutation.
+fn factorize_pivoted<A>(a: &mut Array2<A>) -> (Array1<A>, Vec<usize>)
+where
+    A: ComplexFloat,
+{
+    let (m, n) = a.dim();
+    let k = m.min(n);
+    let mut tau = Array1::zeros(k);
+    let mut perm: Vec<usize> = (0..n).collect();
+    for j in 0..k {
+        // move the column with the largest remaining norm into place
+        let mut p = j;
+        let mut max = A::Real::zero();
+        for c in j..n {
+            let norm = a.slice(s![j.., c]).norm_l2();
+            if norm > max {
+                p = c;
+                max = norm;
+            }
+        }
+        if p != j {
+            let (mut left, mut right) = a.view_mut().split_at(Axis(1), p);
+            Zip::from(left.column_mut(j))
+                .and(right.column_mut(0))
+                .apply(std::mem::swap);
+            perm.swap(j, p);
+        }
+        tau[j] = reflect_column(a, j, n);
+    }
+    (tau, perm)
+}
+
+/// The result of [`.lstsq()`](../struct.ArrayBase.html#method.lstsq).
+#[derive(Clone, Debug)]
+pub struct LeastSquares<A, D>
+where
+    A: ComplexFloat,
+    D: Dimension,
+{
+    /// The solution `x`, with the same dimensionality as `b`.
+    pub solution: Array<A, D>,
+    /// The squared Euclidean norms of the residuals `b - A x` (one per column
+    /// of `b`), if `A` has full column rank and more rows than columns.
+    pub residuals: Option<Array<A::Real, D::Smaller>>,
+    /// The effective rank of `A`.
+    pub rank: usize,
+}
+
+/// # QR Factorization and Least Squares
+impl<A, S> ArrayBase<S, Ix2>
+where
+    S: Data<Elem = A>,
+    A: ComplexFloat,
+{
+    /// Compute the Householder QR factorization of `self`.
+    ///
+    /// The factorization is blocked, with the updates done by
+    /// [`general_mat_mul`](linalg/fn.general_mat_mul.html) (which uses BLAS
+    /// if it is enabled).
+    ///
+    /// ```
+    /// use ndarray::arr2;
+    /// use ndarray::linalg::QRMode;
+    ///
+    /// let a = arr2(&[[3., 1.],
+    ///                [4., 2.],
+    ///                [0., 2.]]);
+    /// let (q, r) = a.qr().q_r(QRMode::Reduced);
+    /// assert_eq!(q.dim(), (3, 2));
+    /// assert_eq!(r.dim(), (2, 2));
+    /// assert!((q.dot(&r) - &a).iter().all(|&x: &f64| x.abs() < 1e-12));
+    /// ```
+    pub fn qr(&self) -> QR<A> {
+        let mut qr = self.to_owned();
+        let tau = factorize(&mut qr);
+        QR { qr, tau }
+    }
+
+    /// Return the least squares solution `x` that minimizes the Euclidean
+    /// norm of `b - self x`.
+    ///
+    /// `b` may be one-dimensional or two-dimensional; in the latter case each
+    /// column is a separate right hand side. If `self` is rank deficient,
+    /// or has more columns than rows, the solution of minimum norm is
+    /// returned.
+    ///
+    /// The rank is determined from a QR factorization with column pivoting,
+    /// counting the diagonal elements of `R` whose magnitude is greater than
+    /// `ε · max(M, N) · |R₀₀|`.
+    ///
+    /// ***Errors*** if `b` is not one- or two-dimensional, or if its number
+    /// of rows does not match `self`.
+    ///
+    /// ```
+    /// use ndarray::{arr1, arr2};
+    ///
+    /// // fit y = c₀ + c₁ t
+    /// let a = arr2(&[[1., 0.],
+    ///                [1., 1.],
+    ///                [1., 2.]]);
+    /// let y = arr1(&[1., 2., 2.]);
+    /// let fit = a.lstsq(&y).unwrap();
+    /// assert_eq!(fit.rank, 2);
+    /// assert!((fit.solution - arr1(&[7. / 6., 0.5])).iter().all(|&x: &f64| x.abs() < 1e-12));
+    /// assert!((fit.residuals.unwrap().into_scalar() - 1. / 6.).abs() < 1e-12);
+    /// ```
+    pub fn lstsq<S2, D>(&self, b: &ArrayBase<S2, D>) -> Result<LeastSquares<A, D>, LinalgError>
+    where
+        S2: Data<Elem = A>,
+        D: Dimension,
+    {
+        let (m, n) = self.dim();
+        let ndim = b.ndim();
+        let b = rhs_matrix(b)?;
+        check_len(m, b.rows())?;
+
+        let mut r = self.to_owned();
+        let (tau, perm) = factorize_pivoted(&mut r);
+        let k = m.min(n);
+
+        // c = Qᴴ b
+        let mut c = b.to_owned();
+        for j in 0..k {
+            let v = householder::vector(&r.slice(s![j + 1.., j]));
+            apply_left(&v.view(), tau[j].conj(), c.slice_mut(s![j.., ..]));
+        }
+
+        let eps = A::Real::epsilon();
+        let size: A::Real = NumCast::from(m.max(n)).unwrap();
+        let tol = eps
+            * size
+            * if k > 0 {
+                r[(0, 0)].abs()
+            } else {
+                A::Real::zero()
+            };
+        let rank = (0..k).take_while(|&i| r[(i, i)].abs() > tol).count();
+
+        let mut z = Array2::zeros((n, c.cols()));
+        if rank == n {
+            let mut zr = z.view_mut();
+            zr.assign(&c.slice(s![..n, ..]));
+            solve_upper_inplace(&r.slice(s![..n, ..n]), zr, false);
+        } else if rank > 0 {
+            // [R₁₁ R₁₂] = [Uᴴ 0] Q₂ᴴ, from the QR factorization of its
+            // conjugate transpose; then x = P Q₂ [U⁻ᴴ c; 0]
+            let mut r1 = r.slice(s![..rank, ..]).to_owned();
+            for ((i, j), x) in r1.indexed_iter_mut() {
+                if j < i {
+                    *x = A::zero();
+                }
+            }
+            let qr2 = conj_t(&r1).qr();
+            let l = conj_t(&qr2.qr.slice(s![..rank, ..rank]));
+            let mut y = z.slice_mut(s![..rank, ..]);
+            y.assign(&c.slice(s![..rank, ..]));
+            solve_lower_inplace(&l.view(), y, false);
+            for j in (0..rank).rev() {
+                let v = householder::vector(&qr2.qr.slice(s![j + 1.., j]));
+                apply_left(&v.view(), qr2.tau[j], z.slice_mut(s![j.., ..]));
+            }
+        }
+        let mut x = Array2::zeros(z.raw_dim());
+        for (i, &p) in perm.iter().enumerate() {
+            x.row_mut(p).assign(&z.row(i));
+        }
+
+        let residuals = if rank == n && m > n {
+            let res = c
+                .slice(s![n.., ..])
+                .fold_axis(Axis(0), A::Real::zero(), |&acc, &x| acc + x.abs_sqr());
+            Some(if ndim == 1 {
+                res.index_axis_move(Axis(0), 0).into_dimensionality()?
+            } else {
+                res.into_dimensionality()?
+            })
+        } else {
+            None
+        };
+        Ok(LeastSquares {
+            solution: rhs_from_matrix(x, ndim)?,
+            residuals,
+            rank,
+        })
+    }
+}
@@ -0,0 +1,319 @@
+// Copyright 2019 bluss and ndarray developers.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use crate::imp_prelude::*;
+use crate::linalg::error::LinalgError;
+use crate::linalg::qr::QRMode;
+use crate::linalg::util::conj_t;
+use crate::{stack, ComplexFloat, Zip};
+
+use num_traits::{Float, NumCast, One, Zero};
+
+/// Maximum number of Jacobi sweeps.
+const MAX_SWEEPS: usize = 60;
+
+/// The shapes of the factors computed by
+/// [`.svd()`](../struct.ArrayBase.html#method.svd) for an *M* × *N* matrix,
+/// where *K* = min(*M*, *N*).
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum SVDMode {
+    /// `U` is *M* × *K* and `Vᴴ` is *K* × *N*.
+    Thin,
+    /// `U` is *M* × *M* and `Vᴴ` is *N* × *N*.
+    Full,
+}
+
+/// # Singular Value Decomposition
+impl<A, S> ArrayBase<S, Ix2>
+where
+    S: Data<Elem = A>,
+    A: ComplexFloat,
+{
+    /// Compute the singular value decomposition `self = U Σ Vᴴ`.
+    ///
+    /// Return `(u, s, vt)` where `u` and `vt` (that is, `Vᴴ`) have
+    /// orthonormal columns and rows respectively, with shapes selected by
+    /// `mode`, and the singular values `s` are real, non-negative and in
+    /// descending order.
+    ///
+    /// Tall matrices are first reduced with a QR factorization; the
+    /// decomposition is then computed with one-sided Jacobi rotations, which
+    /// determine even small singular values to high relative accuracy.
+    ///
+    /// ***Errors*** with `LinalgError::NotConverged` if the iteration does
+    /// not converge.
+    ///
+    /// ```
+    /// use ndarray::{arr1, arr2, Array2};
+    /// use ndarray::linalg::SVDMode;
+    ///
+    /// let a = arr2(&[[3., 0.],
+    ///                [4., 5.]]);
+    /// let (u, s, vt) = a.svd(SVDMode::Thin).unwrap();
+    /// let r5 = 5f64.sqrt();
+    /// assert!((&s - &arr1(&[3. * r5, r5])).iter().all(|&x: &f64| x.abs() < 1e-12));
+    /// let usvt = u.dot(&Array2::from_diag(&s)).dot(&vt);
+    /// assert!((usvt - &a).iter().all(|&x: &f64| x.abs() < 1e-12));
+    /// ```
+    #[allow(clippy::type_complexity)]
+    pub fn svd(
+        &self,
+        mode: SVDMode,
+    ) -> Result<(Array2<A>, Array1<A::Real>, Array2<A>), LinalgError> {
+        let (m, n) = self.dim();
+        if m >= n {
+            let svd = svd_tall(self.view())?;
+            let cols = if mode == SVDMode::Full { m } else { n };
+            let u = complete(svd.u, svd.rank, cols);
+            Ok((u, svd.s, conj_t(&svd.v)))
+        } else {
+            // Aᴴ = U Σ Vᴴ  ⇔  A = V Σ Uᴴ
+            let svd = svd_tall(conj_t(self).view())?;
+            let cols = if mode == SVDMode::Full { n } else { m };
+            let v = complete(svd.u, svd.rank, cols);
+            Ok((svd.v, svd.s, conj_t(&v)))
+        }
+    }
+
+    /// Return the Moore–Penrose pseudoinverse of `self`.
+    ///
+    /// Singular values less than or equal to `ε · max(M, N) · σ₀`, where
+    /// `σ₀` is the largest singular value, are treated as zero.
+    ///
+    /// ***Errors*** as [`.svd()`](#method.svd).
+    ///
+    /// ```
+    /// use ndarray::arr2;
+    ///
+    /// let a = arr2(&[[1., 2.],
+    ///                [2., 4.]]);
+    /// let p = a.pinv().unwrap();
+    /// assert!((p - arr2(&[[0.04, 0.08],
+    ///                     [0.08, 0.16]])).iter().all(|&x: &f64| x.abs() < 1e-12));
+    /// ```
+    pub fn pinv(&self) -> Result<Array2<A>, LinalgError> {
+        let (u, s, vt) = self.svd(SVDMode::Thin)?;
+        let tol = rank_tolerance(self.dim(), &s);
+        let mut vs = conj_t(&vt);
+        for (mut col, &sj) in vs.axis_iter_mut(Axis(1)).zip(&s) {
+            if sj > tol {
+                col.map_inplace(|x| *x = x.scale(sj.recip()));
+            } else {
+                col.fill(A::zero());
+            }
+        }
+        Ok(vs.dot(&conj_t(&u)))
+    }
+
+    /// Return the rank of `self`: the number of singular values greater
+    /// than `ε · max(M, N) · σ₀`, where `σ₀` is the largest singular value.
+    ///
+    /// ***Errors*** as [`.svd()`](#method.svd).
+    pub fn matrix_rank(&self) -> Result<usize, LinalgError> {
+        let s = singular_values(self.view())?;
+        let tol = rank_tolerance(self.dim(), &s);
+        Ok(s.iter().filter(|&&x| x > tol).count())
+    }
+
+    /// Return the condition number of `self` in the 2-norm, the ratio of its
+    /// largest to its smallest singular value.
+    ///
+    /// The result is infinite if `self` is singular.
+    ///
+    /// ***Errors*** as [`.svd()`](#method.svd).
+    pub fn cond(&self) -> Result<A::Real, LinalgError> {
+        let s = singular_values(self.view())?;
+        let zero = A::Real::zero();
+        let smax = s.iter().next().cloned().unwrap_or(zero);
+        let smin = s.iter().next_back().cloned().unwrap_or(zero);
+        Ok(smax / smin)
+    }
+
+    /// Return the 2-norm (spectral norm) of `self`, its largest singular
+    /// value.
+    ///
+    /// ***Errors*** as [`.svd()`](#method.svd).
+    pub fn norm_2(&self) -> Result<A::Real, LinalgError> {
+        let s = singular_values(self.view())?;
+        Ok(s.iter().next().cloned().unwrap_or_else(A::Real::zero))
+    }
+
+    /// Return the nuclear norm of `self`, the sum of its singular values.
+    ///
+    /// ***Errors*** as [`.svd()`](#method.svd).
+    pub fn norm_nuc(&self) -> Result<A::Real, LinalgError> {
+        Ok(singular_values(self.view())?.sum())
+    }
+}
+
+/// The decomposition of an *M* × *N* matrix, where *M* ≥ *N*.
+struct TallSVD<A: ComplexFloat> {
+    /// *M* × *N*; the columns after the first `rank` are zero
+    u: Array2<A>,
+    /// descending
+    s: Array1<A::Real>,
+    /// *N* × *N*
+    v: Array2<A>,
+    /// the number of columns of `u` that are set
+    rank: usize,
+}
+
+/// Return the singular values of `a`, in descending order.
+fn singular_values<A>(a: ArrayView2<'_, A>) -> Result<Array1<A::Real>, LinalgError>
+where
+    A: ComplexFloat,
+{
+    if a.rows() >= a.cols() {
+        Ok(svd_tall(a)?.s)
+    } else {
+        Ok(svd_tall(conj_t(&a).view())?.s)
+    }
+}
+
+/// Return the tolerance below which singular values are treated as zero.
+fn rank_tolerance<R: Float>((m, n): (usize, usize), s: &Array1<R>) -> R {
+    let smax = s.iter().next().cloned().unwrap_or_else(R::zero);
+    let size: R = NumCast::from(m.max(n)).unwrap();
+    R::epsilon() * size * smax
+}
+
+/// Compute the singular value decomposition of `a`, which must have at least
+/// as many rows as columns.
+fn svd_tall<A>(a: ArrayView2<'_, A>) -> Result<TallSVD<A>, LinalgError>
+where
+    A: ComplexFloat,
+{
+    let (m, n) = a.dim();
+    debug_assert!(m >= n);
+    if n == 0 {
+        return Ok(TallSVD {
+            u: Array2::zeros((m, 0)),
+            s: Array1::zeros(0),
+            v: Array2::zeros((0, 0)),
+            rank: 0,
+        });
+    }
+    let (q, mut w) = if m > n {
+        let (q, r) = a.qr().q_r(QRMode::Reduced);
+        (Some(q), r)
+    } else {
+        (None, a.to_owned())
+    };
+    let mut v = Array2::eye(n);
+    jacobi(&mut w, &mut v)?;
+
+    let norms = w
+        .axis_iter(Axis(1))
+        .map(|c| c.norm_l2())
+        .collect::<Vec<_>>();
+    let mut order = (0..n).collect::<Vec<_>>();
+    order.sort_by(|&i, &j| norms[j].partial_cmp(&norms[i]).unwrap());
+    let s = order.iter().map(|&i| norms[i]).collect::<Array1<_>>();
+    let mut w = w.select(Axis(1), &order);
+    let v = v.select(Axis(1), &order);
+
+    // columns of negligible norm have no reliable direction, and are
+    // replaced with an orthonormal completion instead
+    let tol = A::Real::epsilon() * s[0];
+    let rank = s.iter().filter(|&&x| x > tol).count();
+    for (j, mut col) in w.axis_iter_mut(Axis(1)).enumerate() {
+        if j < rank {
+            let r = s[j].recip();
+            col.map_inplace(|x| *x = x.scale(r));
+        } else {
+            col.fill(A::zero());
+        }
+    }
+    let u = match q {
+        Some(q) => q.dot(&w),
+        None => w,
+    };
+    Ok(TallSVD { u, s, v, rank })
+}
+
+/// Orthogonalize the columns of `w` with one-sided Jacobi rotations,
+/// applying the same rotations to the columns of `v`.
+fn jacobi<A>(w: &mut Array2<A>, v: &mut Array2<A>) -> Result<(), LinalgError>
+where
+    A: ComplexFloat,
+{
+    let n = w.cols();
+    let zero = A::Real::zero();
+    let one = A::Real::one();
+    let two = one + one;
+    let eps = A::Real::epsilon();
+    for _ in 0..MAX_SWEEPS {
+        let mut rotated = false;
+        for p in 0..n {
+            for q in p + 1..n {
+                let alpha = w.column(p).fold(zero, |acc, x| acc + x.abs_sqr());
+                let beta = w.column(q).fold(zero, |acc, x| acc + x.abs_sqr());
+                let gamma = w
+                    .column(p)
+                    .iter()
+                    .zip(w.column(q))
+                    .fold(A::zero(), |acc, (x, &y)| acc + x.conj() * y);
+                let g = gamma.abs();
+                if g.is_zero() || g <= eps * Float::sqrt(alpha) * Float::sqrt(beta) {
+                    continue;
+                }
+                rotated = true;
+                // make the inner product real by rotating the phase of
+                // column q, then apply a real plane rotation
+                let phase = gamma.scale(g.recip()).conj();
+                let zeta = (beta - alpha) / (two * g);
+                let t = Float::signum(zeta) / (Float::abs(zeta) + Float::hypot(one, zeta));
+                let c = Float::sqrt(one + t * t).recip();
+                let s = c * t;
+                rotate(w, p, q, phase, c, s);
+                rotate(v, p, q, phase, c, s);
+            }
+        }
+        if !rotated {
+            return Ok(());
+        }
+    }
+    Err(LinalgError::NotConverged)
+}
+
+/// Rotate columns `p` and `q` (where `p < q`) of `a`.
+fn rotate<A>(a: &mut Array2<A>, p: usize, q: usize, phase: A, c: A::Real, s: A::Real)
+where
+    A: ComplexFloat,
+{
+    let (left, right) = a.view_mut().split_at(Axis(1), q);
+    let mut ap = left.index_axis_move(Axis(1), p);
+    let mut aq = right.index_axis_move(Axis(1), 0);
+    Zip::from(&mut ap).and(&mut aq).apply(|x, y| {
+        let yp = *y * phase;
+        *y = x.scale(s) + yp.scale(c);
+        *x = x.scale(c) - yp.scale(s);
+    });
+}
+
+/// Return `u` with `cols` columns, replacing or adding the columns after the
+/// first `rank` (which are orthonormal) with an orthonormal completion.
+fn complete<A>(u: Array2<A>, rank: usize, cols: usize) -> Array2<A>
+where
+    A: ComplexFloat,
+{
+    if rank == cols {
+        return u;
+    }
+    let m = u.rows();
+    let basis = if rank == 0 {
+        Array2::eye(m)
+    } else {
+        u.slice(s![.., ..rank]).qr().q(QRMode::Complete)
+    };
+    stack(
+        Axis(1),
+        &[u.slice(s![.., ..rank]), basis.slice(s![.., rank..cols])],
+    )
+    .unwrap()
+}
@@ -0,0 +1,151 @@
+// Copyright 2019 bluss and ndarray developers.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use crate::error::{from_kind, ErrorKind, ShapeError};
+use crate::imp_prelude::*;
+use crate::linalg::impl_linalg::mat_mul_impl;
+use crate::LinalgScalar;
+
+/// Tensor contraction of `a` and `b` over the axes `axes_a` of `a` and
+/// `axes_b` of `b`.
+///
+/// The axes are contracted pairwise: `axes_a[i]` with `axes_b[i]`. The
+/// result has the remaining axes of `a` followed by the remaining axes of
+/// `b`, each in their original order.
+///
+/// The operands are permuted and reshaped so that the contraction is a
+/// single matrix multiplication, computed in the same way as
+/// [`general_mat_mul`](fn.general_mat_mul.html).
+///
+/// ***Errors*** if `axes_a` and `axes_b` have different lengths, if an axis
+/// is repeated, or if paired axes have different lengths (all
+/// `IncompatibleShape`), or if an axis is out of bounds (`OutOfBounds`).
+///
+/// ```
+/// use ndarray::{Array, Axis};
+/// use ndarray::linalg::tensordot;
+///
+/// let a = Array::from_shape_fn((3, 4, 5), |(i, j, k)| (i + 2 * j + 3 * k) as f64);
+/// let b = Array::from_shape_fn((4, 3, 2), |(j, i, l)| (i * j + l) as f64);
+///
+/// // c[k, l] = Σ_ij a[i, j, k] b[j, i, l]
+/// let c = tensordot(&a, &b, &[Axis(0), Axis(1)], &[Axis(1), Axis(0)]).unwrap();
+/// assert_eq!(c.shape(), &[5, 2]);
+/// let mut c01 = 0.;
+/// for i in 0..3 {
+///     for j in 0..4 {
+///         c01 += a[[i, j, 0]] * b[[j, i, 1]];
+///     }
+/// }
+/// assert_eq!(c[[0, 1]], c01);
+/// ```
+pub fn tensordot<A, S1, S2, D1, D2>(
+    a: &ArrayBase<S1, D1>,
+    b: &ArrayBase<S2, D2>,
+    axes_a: &[Axis],
+    axes_b: &[Axis],
+) -> Result<ArrayD<A>, ShapeError>
+where
+    S1: Data<Elem = A>,
+    S2: Data<Elem = A>,
+    D1: Dimension,
+    D2: Dimension,
+    A: LinalgScalar,
+{
+    if axes_a.len() != axes_b.len() {
+        return Err(from_kind(ErrorKind::IncompatibleShape));
+    }
+    let sum_a = check_axes(a.ndim(), axes_a)?;
+    let sum_b = check_axes(b.ndim(), axes_b)?;
+    for (&i, &j) in sum_a.iter().zip(&sum_b) {
+        if a.len_of(Axis(i)) != b.len_of(Axis(j)) {
+            return Err(from_kind(ErrorKind::IncompatibleShape));
+        }
+    }
+    Ok(contract(
+        a.view().into_dyn(),
+        &[],
+        &sum_a,
+        b.view().into_dyn(),
+        &[],
+        &sum_b,
+    ))
+}
+
+/// Return the indices of `axes`, checking that they are in bounds and
+/// distinct.
+fn check_axes(ndim: usize, axes: &[Axis]) -> Result<Vec<usize>, ShapeError> {
+    let mut indices = Vec::with_capacity(axes.len());
+    for axis in axes {
+        let i = axis.index();
+        if i >= ndim {
+            return Err(from_kind(ErrorKind::OutOfBounds));
+        }
+        if indices.contains(&i) {
+            return Err(from_kind(ErrorKind::IncompatibleShape));
+        }
+        indices.push(i);
+    }
+    Ok(indices)
+}
+
+/// Contract `a` and `b`, which must have matching lengths along the paired
+/// axes `batch_a`/`batch_b` and `sum_a`/`sum_b`.
+///
+/// The axes of the result are the batch axes, followed by the remaining
+/// axes of `a` and then the remaining axes of `b`. The sum axes are summed
+/// over, while the batch axes are kept, computing one matrix product for
+/// each of their indices.
+pub(crate) fn contract<A>(
+    a: ArrayViewD<'_, A>,
+    batch_a: &[usize],
+    sum_a: &[usize],
+    b: ArrayViewD<'_, A>,
+    batch_b: &[usize],
+    sum_b: &[usize],
+) -> ArrayD<A>
+where
+    A: LinalgScalar,
+{
+    let free = |ndim: usize, batch: &[usize], sum: &[usize]| {
+        (0..ndim)
+            .filter(|i| !batch.contains(i) && !sum.contains(i))
+            .collect::<Vec<_>>()
+    };
+    let free_a = free(a.ndim(), batch_a, sum_a);
+    let free_b = free(b.ndim(), batch_b, sum_b);
+    let lens = |v: &ArrayViewD<'_, A>, axes: &[usize]| {
+        axes.iter().map(|&i| v.len_of(Axis(i))).collect::<Vec<_>>()
+    };
+    let mut shape = lens(&a, batch_a);
+    shape.extend(lens(&a, &free_a));
+    shape.extend(lens(&b, &free_b));
+    let size = |lens: Vec<usize>| lens.iter().product::<usize>();
+    let nb = size(lens(&a, batch_a));
+    let m = size(lens(&a, &free_a));
+    let k = size(lens(&a, sum_a));
+    let n = size(lens(&b, &free_b));
+
+    let a_order = [batch_a, &free_a, sum_a].concat();
+    let b_order = [batch_b, sum_b, &free_b].concat();
+    let a = gather(a.permuted_axes(a_order), (nb, m, k));
+    let b = gather(b.permuted_axes(b_order), (nb, k, n));
+    let mut c = Array3::zeros((nb, m, n));
+    for ((a, b), mut c) in a.outer_iter().zip(b.outer_iter()).zip(c.outer_iter_mut()) {
+        mat_mul_impl(A::one(), &a, &b, A::zero(), &mut c);
+    }
+    c.into_shape(shape).unwrap()
+}
+
+/// Copy the elements of `a`, in logical order, into an array of `shape`.
+fn gather<A>(a: ArrayViewD<'_, A>, shape: (usize, usize, usize)) -> Array3<A>
+where
+    A: Clone,
+{
+    Array::from_shape_vec(shape, a.iter().cloned().collect()).unwrap()
+}
@@ -0,0 +1,157 @@
+// Copyright 2019 bluss and ndarray developers.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use crate::imp_prelude::*;
+use crate::linalg::error::{check_len, check_square, LinalgError};
+use crate::linalg::util::{conj_t, rhs_from_matrix, rhs_matrix};
+use crate::ComplexFloat;
+
+/// Which triangle of a matrix to use.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum UPLO {
+    /// The upper triangle, on and above the diagonal.
+    Upper,
+    /// The lower triangle, on and below the diagonal.
+    Lower,
+}
+
+/// The operation applied to a matrix before it is used.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Transpose {
+    /// Use the matrix as it is.
+    NoTrans,
+    /// Use the transpose.
+    Trans,
+    /// Use the conjugate transpose.
+    ConjTrans,
+}
+
+/// Whether a triangular matrix has an implicit unit diagonal.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Diag {
+    /// The diagonal is taken to be all ones, and is not read.
+    Unit,
+    /// The diagonal is read from the matrix.
+    NonUnit,
+}
+
+/// # Triangular Solvers
+impl<A, S> ArrayBase<S, Ix2>
+where
+    S: Data<Elem = A>,
+    A: ComplexFloat,
+{
+    /// Solve `op(T) x = b` where `T` is the `uplo` triangle of the square
+    /// matrix `self`, and `op` is selected by `trans`.
+    ///
+    /// Only the selected triangle of `self` is read, and with `Diag::Unit` its
+    /// diagonal is not read either. `b` may be one-dimensional or
+    /// two-dimensional; in the latter case each column is a separate right
+    /// hand side.
+    ///
+    /// ***Errors*** if `self` is not square, if `b` is not one- or
+    /// two-dimensional or its number of rows does not match, or if the
+    /// diagonal has a zero (`LinalgError::Singular`).
+    ///
+    /// ```
+    /// use ndarray::{arr1, arr2};
+    /// use ndarray::linalg::{Diag, Transpose, UPLO};
+    ///
+    /// let t = arr2(&[[2., 0.],
+    ///                [1., 1.]]);
+    /// let b = arr1(&[4., 3.]);
+    /// let x = t.solve_triangular(UPLO::Lower, Transpose::NoTrans, Diag::NonUnit, &b).unwrap();
+    /// assert_eq!(x, arr1(&[2., 1.]));
+    /// let x = t.solve_triangular(UPLO::Lower, Transpose::Trans, Diag::NonUnit, &b).unwrap();
+    /// assert_eq!(x, arr1(&[0.5, 3.]));
+    /// ```
+    pub fn solve_triangular<S2, D>(
+        &self,
+        uplo: UPLO,
+        trans: Transpose,
+        diag: Diag,
+        b: &ArrayBase<S2, D>,
+    ) -> Result<Array<A, D>, LinalgError>
+    where
+        S2: Data<Elem = A>,
+        D: Dimension,
+    {
+        let n = check_square(self.dim())?;
+        let ndim = b.ndim();
+        let mut x = rhs_matrix(b)?.to_owned();
+        check_len(n, x.rows())?;
+        let unit = diag == Diag::Unit;
+        if !unit && self.diag().iter().any(|d| d.is_zero()) {
+            return Err(LinalgError::Singular);
+        }
+        let conj;
+        let (t, uplo) = match trans {
+            Transpose::NoTrans => (self.view(), uplo),
+            Transpose::Trans => (self.t(), uplo.flip()),
+            Transpose::ConjTrans => {
+                conj = conj_t(self);
+                (conj.view(), uplo.flip())
+            }
+        };
+        match uplo {
+            UPLO::Upper => solve_upper_inplace(&t, x.view_mut(), unit),
+            UPLO::Lower => solve_lower_inplace(&t, x.view_mut(), unit),
+        }
+        rhs_from_matrix(x, ndim)
+    }
+}
+
+impl UPLO {
+    /// Return the opposite triangle.
+    fn flip(self) -> Self {
+        match self {
+            UPLO::Upper => UPLO::Lower,
+            UPLO::Lower => UPLO::Upper,
+        }
+    }
+}
+
+/// B ← A⁻¹ B where `A` is the upper triangle of the square matrix `a`.
+///
+/// If `unit` is true, the diagonal of `a` is not used and taken to be all ones.
+pub(crate) fn solve_upper_inplace<A>(a: &ArrayView2<'_, A>, mut b: ArrayViewMut2<'_, A>, unit: bool)
+where
+    A: ComplexFloat,
+{
+    for j in (0..a.rows()).rev() {
+        let (mut rest, mut done) = b.view_mut().split_at(Axis(0), j);
+        let mut xj = done.row_mut(0);
+        if !unit {
+            let d = a[(j, j)];
+            xj.map_inplace(|x| *x /= d);
+        }
+        for (i, mut row) in rest.outer_iter_mut().enumerate() {
+            row.scaled_add(-a[(i, j)], &xj);
+        }
+    }
+}
+
+/// B ← A⁻¹ B where `A` is the lower triangle of the square matrix `a`.
+///
+/// If `unit` is true, the diagonal of `a` is not used and taken to be all ones.
+pub(crate) fn solve_lower_inplace<A>(a: &ArrayView2<'_, A>, mut b: ArrayViewMut2<'_, A>, unit: bool)
+where
+    A: ComplexFloat,
+{
+    for j in 0..a.rows() {
+        let (mut done, mut rest) = b.view_mut().split_at(Axis(0), j + 1);
+        let mut xj = done.row_mut(j);
+        if !unit {
+            let d = a[(j, j)];
+            xj.map_inplace(|x| *x /= d);
+        }
+        for (i, mut row) in rest.outer_iter_mut().enumerate() {
+            row.scaled_add(-a[(j + 1 + i, j)], &xj);
+        }
+    }
+}
@@ -0,0 +1,48 @@
+// Copyright 2019 bluss and ndarray developers.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use crate::error::{from_kind, ErrorKind};
+use crate::imp_prelude::*;
+use crate::linalg::error::LinalgError;
+use crate::ComplexFloat;
+
+/// Return the conjugate transpose of `a`.
+pub(crate) fn conj_t<A, S>(a: &ArrayBase<S, Ix2>) -> Array2<A>
+where
+    S: Data<Elem = A>,
+    A: ComplexFloat,
+{
+    a.t().mapv(A::conj)
+}
+
+/// View a one- or two-dimensional right hand side `b` as a matrix with one
+/// column per system.
+pub(crate) fn rhs_matrix<A, S, D>(b: &ArrayBase<S, D>) -> Result<ArrayView2<'_, A>, LinalgError>
+where
+    S: Data<Elem = A>,
+    D: Dimension,
+{
+    match b.ndim() {
+        1 => Ok(b.view().into_dimensionality::<Ix1>()?.insert_axis(Axis(1))),
+        2 => Ok(b.view().into_dimensionality::<Ix2>()?),
+        _ => Err(from_kind(ErrorKind::IncompatibleShape).into()),
+    }
+}
+
+/// Convert the solutions `x` from `rhs_matrix` form back to the
+/// dimensionality `ndim` of the right hand side.
+pub(crate) fn rhs_from_matrix<A, D>(x: Array2<A>, ndim: usize) -> Result<Array<A, D>, LinalgError>
+where
+    D: Dimension,
+{
+    if ndim == 1 {
+        Ok(x.index_axis_move(Axis(1), 0).into_dimensionality()?)
+    } else {
+        Ok(x.into_dimensionality()?)
+    }
+}
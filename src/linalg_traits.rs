@@ -6,9 +6,10 @@
 // option. This file may not be copied, modified, or distributed
 // except according to those terms.
 use crate::ScalarOperand;
+use num_complex::Complex;
 use num_traits::{Float, One, Zero};
 use std::fmt;
-use std::ops::{Add, Div, Mul, Sub};
+use std::ops::{Add, Div, Mul, Neg, Sub};
 use std::ops::{AddAssign, DivAssign, MulAssign, RemAssign, SubAssign};
 
 /// Elements that support linear algebra operations.
@@ -67,3 +68,138 @@ pub trait NdFloat:
 
 impl NdFloat for f32 {}
 impl NdFloat for f64 {}
+
+/// Real and complex floating-point element types: `f32`, `f64`,
+/// `Complex<f32>` and `Complex<f64>`.
+///
+/// This is the element trait for the factorizations and solvers in
+/// [`linalg`](linalg/index.html). `Real` is the type of the real and imaginary
+/// parts; for real elements it is the element type itself.
+///
+/// This trait can only be implemented by the four types listed above.
+pub trait ComplexFloat:
+    LinalgScalar
+    + Neg<Output = Self>
+    + AddAssign
+    + SubAssign
+    + MulAssign
+    + DivAssign
+    + fmt::Display
+    + fmt::Debug
+    + ScalarOperand
+    + Send
+    + Sync
+{
+    /// The real type of the components.
+    type Real: NdFloat + ComplexFloat<Real = Self::Real>;
+
+    /// Create an element from its real part.
+    fn from_real(re: Self::Real) -> Self;
+    /// Return the real part.
+    fn re(self) -> Self::Real;
+    /// Return the imaginary part (zero for real elements).
+    fn im(self) -> Self::Real;
+    /// Return the complex conjugate (the identity for real elements).
+    fn conj(self) -> Self;
+    /// Return the absolute value (modulus).
+    fn abs(self) -> Self::Real;
+    /// Return the squared absolute value.
+    fn abs_sqr(self) -> Self::Real;
+    /// Return the principal square root.
+    fn sqrt(self) -> Self;
+    /// Multiply by a real scalar.
+    fn scale(self, r: Self::Real) -> Self;
+
+    private_decl! {}
+}
+
+macro_rules! impl_complex_float_real {
+    ($t:ty) => {
+        impl ComplexFloat for $t {
+            type Real = $t;
+
+            #[inline]
+            fn from_real(re: $t) -> Self {
+                re
+            }
+            #[inline]
+            fn re(self) -> $t {
+                self
+            }
+            #[inline]
+            fn im(self) -> $t {
+                0.
+            }
+            #[inline]
+            fn conj(self) -> Self {
+                self
+            }
+            #[inline]
+            fn abs(self) -> $t {
+                Float::abs(self)
+            }
+            #[inline]
+            fn abs_sqr(self) -> $t {
+                self * self
+            }
+            #[inline]
+            fn sqrt(self) -> Self {
+                Float::sqrt(self)
+            }
+            #[inline]
+            fn scale(self, r: $t) -> Self {
+                self * r
+            }
+
+            private_impl! {}
+        }
+    };
+}
+
+macro_rules! impl_complex_float_complex {
+    ($t:ty) => {
+        impl ComplexFloat for Complex<$t> {
+            type Real = $t;
+
+            #[inline]
+            fn from_real(re: $t) -> Self {
+                Complex::new(re, 0.)
+            }
+            #[inline]
+            fn re(self) -> $t {
+                self.re
+            }
+            #[inline]
+            fn im(self) -> $t {
+                self.im
+            }
+            #[inline]
+            fn conj(self) -> Self {
+                Complex::conj(&self)
+            }
+            #[inline]
+            fn abs(self) -> $t {
+                self.norm()
+            }
+            #[inline]
+            fn abs_sqr(self) -> $t {
+                self.norm_sqr()
+            }
+            #[inline]
+            fn sqrt(self) -> Self {
+                Complex::sqrt(&self)
+            }
+            #[inline]
+            fn scale(self, r: $t) -> Self {
+                Complex::scale(&self, r)
+            }
+
+            private_impl! {}
+        }
+    };
+}
+
+impl_complex_float_real!(f32);
+impl_complex_float_real!(f64);
+impl_complex_float_complex!(f32);
+impl_complex_float_complex!(f64);
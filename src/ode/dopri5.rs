@@ -0,0 +1,340 @@
+// Copyright 2019 bluss and ndarray developers.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+use crate::imp_prelude::*;
+use crate::NdFloat;
+
+use super::{stage, OdeError, Solution, Trajectory};
+
+// The Butcher tableau of the Dormand–Prince method, from E. Hairer,
+// S. P. Nørsett and G. Wanner, "Solving Ordinary Differential Equations I:
+// Nonstiff Problems", Sec. II.5. The last row of `STAGES` is the solution of
+// order five, and `k[6]` is the derivative at its end.
+const NODES: [f64; 7] = [0., 1. / 5., 3. / 10., 4. / 5., 8. / 9., 1., 1.];
+const STAGES: [[f64; 6]; 6] = [
+    [1. / 5., 0., 0., 0., 0., 0.],
+    [3. / 40., 9. / 40., 0., 0., 0., 0.],
+    [44. / 45., -56. / 15., 32. / 9., 0., 0., 0.],
+    [
+        19372. / 6561.,
+        -25360. / 2187.,
+        64448. / 6561.,
+        -212. / 729.,
+        0.,
+        0.,
+    ],
+    [
+        9017. / 3168.,
+        -355. / 33.,
+        46732. / 5247.,
+        49. / 176.,
+        -5103. / 18656.,
+        0.,
+    ],
+    [
+        35. / 384.,
+        0.,
+        500. / 1113.,
+        125. / 192.,
+        -2187. / 6784.,
+        11. / 84.,
+    ],
+];
+/// The difference of the solutions of order five and four.
+const ERROR: [f64; 7] = [
+    71. / 57600.,
+    0.,
+    -71. / 16695.,
+    71. / 1920.,
+    -17253. / 339200.,
+    22. / 525.,
+    -1. / 40.,
+];
+/// The coefficients of the dense output of order four.
+const DENSE: [f64; 7] = [
+    -12715105075. / 11282082432.,
+    0.,
+    87487479700. / 32700410799.,
+    -10690763975. / 1880347072.,
+    701980252875. / 199316789632.,
+    -1453857185. / 822651844.,
+    69997945. / 29380423.,
+];
+
+const SAFETY: f64 = 0.9;
+const MIN_FACTOR: f64 = 0.2;
+const MAX_FACTOR: f64 = 10.;
+
+/// The adaptive Dormand–Prince method of order five.
+///
+/// Each step is accepted if the error estimated with the embedded method of
+/// order four is within the tolerances, that is, if the root mean square of
+///
+/// ```text
+/// error[i] / (atol + rtol * |y[i]|)
+/// ```
+///
+/// is at most one. The step size is then adjusted for the next step. The
+/// solution is interpolated between the steps with the dense output of
+/// order four of the method.
+///
+/// ```
+/// use ndarray::{arr2, Array};
+/// use ndarray::ode::Dopri5;
+///
+/// // a matrix state: Y' = -Y solved for both columns at once
+/// let y0 = arr2(&[[1., 2.], [3., 4.]]);
+/// let sol = Dopri5::new()
+///     .rtol(1e-6)
+///     .solve(|_t, y, mut dy| dy.assign(&-&y), (0., 1.), &y0)
+///     .unwrap();
+/// let end = sol.eval(1.).unwrap();
+/// assert!((&end - &(&y0 * (-1f64).exp())).iter().all(|d| d.abs() < 1e-5));
+///
+/// // sample the solution at fixed times
+/// let samples = sol.eval_points(&Array::linspace(0., 1., 11)).unwrap();
+/// assert_eq!(samples.dim(), (11, 4));
+/// ```
+#[derive(Copy, Clone, Debug)]
+pub struct Dopri5<A> {
+    rtol: A,
+    atol: A,
+    first_step: Option<A>,
+    max_step: Option<A>,
+    max_steps: usize,
+}
+
+impl<A: NdFloat> Default for Dopri5<A> {
+    fn default() -> Self {
+        Dopri5 {
+            rtol: A::from(1e-3).unwrap(),
+            atol: A::from(1e-6).unwrap(),
+            first_step: None,
+            max_step: None,
+            max_steps: 100_000,
+        }
+    }
+}
+
+impl<A: NdFloat> Dopri5<A> {
+    /// Create a solver with relative tolerance `1e-3`, absolute tolerance
+    /// `1e-6`, and at most 100 000 steps.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the relative tolerance.
+    pub fn rtol(mut self, rtol: A) -> Self {
+        self.rtol = rtol;
+        self
+    }
+
+    /// Set the absolute tolerance.
+    pub fn atol(mut self, atol: A) -> Self {
+        self.atol = atol;
+        self
+    }
+
+    /// Set the size of the first step attempted; by default it is estimated
+    /// from the derivatives at the start.
+    pub fn first_step(mut self, h: A) -> Self {
+        self.first_step = Some(h.abs());
+        self
+    }
+
+    /// Set the largest step size; by default it is the length of the
+    /// interval.
+    pub fn max_step(mut self, h: A) -> Self {
+        self.max_step = Some(h.abs());
+        self
+    }
+
+    /// Set the largest number of steps attempted, including rejected steps.
+    pub fn max_steps(mut self, n: usize) -> Self {
+        self.max_steps = n;
+        self
+    }
+
+    /// Solve `dy/dt = f(t, y)` from `t_span.0` to `t_span.1`, with the
+    /// initial state `y0`.
+    ///
+    /// `f(t, y, dy)` must set `dy` to the derivative at time `t` and state
+    /// `y`. The interval may run backwards in time.
+    ///
+    /// ***Errors*** if the step size becomes too small to meet the
+    /// tolerances, or if the maximum number of steps is exceeded.
+    pub fn solve<F, S, D>(
+        &self,
+        mut f: F,
+        t_span: (A, A),
+        y0: &ArrayBase<S, D>,
+    ) -> Result<Solution<A, D>, OdeError>
+    where
+        F: FnMut(A, ArrayView<'_, A, D>, ArrayViewMut<'_, A, D>),
+        S: Data<Elem = A>,
+        D: Dimension,
+    {
+        let cast = |x: f64| A::from(x).unwrap();
+        let c: Vec<A> = NODES.iter().map(|&x| cast(x)).collect();
+        let a: Vec<Vec<A>> = STAGES
+            .iter()
+            .map(|row| row.iter().map(|&x| cast(x)).collect())
+            .collect();
+        let e: Vec<A> = ERROR.iter().map(|&x| cast(x)).collect();
+        let d: Vec<A> = DENSE.iter().map(|&x| cast(x)).collect();
+        let (safety, min_factor) = (cast(SAFETY), cast(MIN_FACTOR));
+        let exponent = cast(-0.2);
+
+        let (t0, t_end) = t_span;
+        let dim = y0.raw_dim();
+        let zeros = || Array::<A, D>::zeros(dim.clone());
+        let mut y = zeros();
+        y.assign(y0);
+        let mut y_new = zeros();
+        let mut r5 = zeros();
+        let mut k: Vec<_> = (0..7).map(|_| zeros()).collect();
+        let mut trajectory = Trajectory::new(t0, &y);
+
+        f(t0, y.view(), k[0].view_mut());
+        if t0 == t_end {
+            return Ok(trajectory.finish(dim));
+        }
+        let dir = (t_end - t0).signum();
+        let max_step = self.max_step.unwrap_or_else(|| (t_end - t0).abs());
+        let mut h = match self.first_step {
+            Some(h) => h,
+            None => self.initial_step(&mut f, t0, dir, &y, &mut k, &mut y_new),
+        }
+        .min(max_step);
+
+        let mut t = t0;
+        let mut rejected = false;
+        for _ in 0..self.max_steps {
+            if h <= cast(10.) * A::epsilon() * t.abs() {
+                return Err(OdeError::StepSizeTooSmall);
+            }
+            let last = (t + dir * h - t_end) * dir >= A::zero();
+            if last {
+                h = (t_end - t).abs();
+            }
+            let hd = dir * h;
+            for s in 1..7 {
+                let (done, rest) = k.split_at_mut(s);
+                stage(&mut y_new, &y, hd, &a[s - 1], done);
+                f(t + c[s] * hd, y_new.view(), rest[0].view_mut());
+            }
+
+            let err = self.error_norm(hd, &e, &k, &y, &y_new);
+            let mut factor = (safety * err.powf(exponent)).max(min_factor);
+            if err <= A::one() {
+                r5.fill(A::zero());
+                for (&d, k) in d.iter().zip(&k) {
+                    r5.scaled_add(hd * d, k);
+                }
+                let t1 = if last { t_end } else { t + hd };
+                trajectory.push(t1, hd, (&y, &y_new), (&k[0], &k[6]), Some(&r5));
+                if last {
+                    return Ok(trajectory.finish(dim));
+                }
+                t = t1;
+                std::mem::swap(&mut y, &mut y_new);
+                k.swap(0, 6);
+                factor = factor.min(if rejected { A::one() } else { cast(MAX_FACTOR) });
+                h = (h * factor).min(max_step);
+                rejected = false;
+            } else {
+                h *= factor.min(A::one());
+                rejected = true;
+            }
+        }
+        Err(OdeError::TooManySteps)
+    }
+
+    /// Return the root mean square of the estimated error of the step from
+    /// `y` to `y_new`, relative to the tolerances.
+    fn error_norm<D: Dimension>(
+        &self,
+        h: A,
+        e: &[A],
+        k: &[Array<A, D>],
+        y: &Array<A, D>,
+        y_new: &Array<A, D>,
+    ) -> A {
+        let k: Vec<_> = k.iter().map(|k| k.as_slice().unwrap()).collect();
+        let y = y.as_slice().unwrap();
+        let y_new = y_new.as_slice().unwrap();
+        let mut sum = A::zero();
+        for i in 0..y.len() {
+            let err = h * e
+                .iter()
+                .zip(&k)
+                .fold(A::zero(), |acc, (&e, k)| acc + e * k[i]);
+            let scale = self.atol + self.rtol * y[i].abs().max(y_new[i].abs());
+            sum += (err / scale) * (err / scale);
+        }
+        rms(sum, y.len())
+    }
+
+    /// Estimate a first step size from the state and derivative at the start,
+    /// with the algorithm of Hairer, Nørsett and Wanner.
+    ///
+    /// `k[0]` holds the derivative at the start; `k[1]` and `y_new` are used
+    /// as scratch space.
+    fn initial_step<F, D>(
+        &self,
+        f: &mut F,
+        t0: A,
+        dir: A,
+        y: &Array<A, D>,
+        k: &mut [Array<A, D>],
+        y_new: &mut Array<A, D>,
+    ) -> A
+    where
+        F: FnMut(A, ArrayView<'_, A, D>, ArrayViewMut<'_, A, D>),
+        D: Dimension,
+    {
+        let cast = |x: f64| A::from(x).unwrap();
+        let n = y.len();
+        let scale = y.mapv(|y| self.atol + self.rtol * y.abs());
+        let norm = |x: &Array<A, D>| {
+            let sum = x
+                .iter()
+                .zip(&scale)
+                .fold(A::zero(), |acc, (&x, &s)| acc + (x / s) * (x / s));
+            rms(sum, n)
+        };
+        let (d0, d1) = (norm(y), norm(&k[0]));
+        let mut h0 = if d0 < cast(1e-5) || d1 < cast(1e-5) {
+            cast(1e-6)
+        } else {
+            cast(0.01) * d0 / d1
+        };
+        if let Some(max_step) = self.max_step {
+            h0 = h0.min(max_step);
+        }
+        stage(y_new, y, dir * h0, &[A::one()], &k[..1]);
+        let (k0, k1) = k.split_at_mut(1);
+        f(t0 + dir * h0, y_new.view(), k1[0].view_mut());
+        let d2 = norm(&(&k1[0] - &k0[0])) / h0;
+        let d = d1.max(d2);
+        let h1 = if d <= cast(1e-15) {
+            cast(1e-6).max(h0 * cast(1e-3))
+        } else {
+            (cast(0.01) / d).powf(cast(0.2))
+        };
+        (cast(100.) * h0).min(h1)
+    }
+}
+
+/// Return the root mean square of `n` values whose squares sum to `sum`.
+fn rms<A: NdFloat>(sum: A, n: usize) -> A {
+    if n == 0 {
+        A::zero()
+    } else {
+        (sum / A::from(n).unwrap()).sqrt()
+    }
+}
@@ -0,0 +1,50 @@
+// Copyright 2019 bluss and ndarray developers.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+use std::error::Error;
+use std::fmt;
+
+/// An error from solving an ordinary differential equation, or from
+/// evaluating its solution.
+///
+/// This enumeration is not exhaustive.
+#[derive(Clone, Debug, PartialEq)]
+pub enum OdeError {
+    /// the step size needed to meet the tolerances is too small compared to
+    /// the time, which happens near a singularity of the solution
+    StepSizeTooSmall,
+    /// the maximum number of steps was taken before reaching the end of the
+    /// interval
+    TooManySteps,
+    /// a time is outside the interval of the solution
+    OutOfRange,
+    #[doc(hidden)]
+    __Incomplete,
+}
+
+impl OdeError {
+    fn message(&self) -> &'static str {
+        match *self {
+            OdeError::StepSizeTooSmall => "the step size is too small",
+            OdeError::TooManySteps => "the maximum number of steps was taken",
+            OdeError::OutOfRange => "a time is outside the interval of the solution",
+            OdeError::__Incomplete => "this error variant is not in use",
+        }
+    }
+}
+
+impl Error for OdeError {
+    fn description(&self) -> &str {
+        self.message()
+    }
+}
+
+impl fmt::Display for OdeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "OdeError: {}", self.message())
+    }
+}
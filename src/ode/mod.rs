@@ -0,0 +1,251 @@
+// Copyright 2019 bluss and ndarray developers.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Solvers for initial value problems of ordinary differential equations.
+//!
+//! The state `y` of the system is an array of any dimension, and the
+//! right-hand side of `dy/dt = f(t, y)` is a closure that writes `f(t, y)`
+//! into an array of the same shape:
+//!
+//! ```text
+//! FnMut(A, ArrayView<A, D>, ArrayViewMut<A, D>)
+//! ```
+//!
+//! - [`Rk4`](struct.Rk4.html) takes a fixed number of steps of the classic
+//!   fourth order Runge–Kutta method.
+//! - [`Dopri5`](struct.Dopri5.html) is the adaptive Dormand–Prince method of
+//!   order five, with an embedded method of order four for error control.
+//!
+//! The solvers allocate their buffers once per solve, and return a
+//! [`Solution`](struct.Solution.html) with the states at each step,
+//! stacked as the rows of an `Array2`, and dense output to evaluate the
+//! solution at any time in between.
+//!
+//! ```
+//! use ndarray::arr1;
+//! use ndarray::ode::Dopri5;
+//!
+//! // the harmonic oscillator y'' = -y
+//! let sol = Dopri5::new()
+//!     .rtol(1e-8)
+//!     .atol(1e-10)
+//!     .solve(|_t, y, mut dy| {
+//!                dy[0] = y[1];
+//!                dy[1] = -y[0];
+//!            },
+//!            (0., 3.),
+//!            &arr1(&[0., 1.]))
+//!     .unwrap();
+//!
+//! let end = sol.state(sol.t().len() - 1);
+//! assert!((end[0] - 3f64.sin()).abs() < 1e-7);
+//! let y = sol.eval(1.).unwrap();
+//! assert!((y[0] - 1f64.sin()).abs() < 1e-7);
+//! ```
+
+pub use self::dopri5::Dopri5;
+pub use self::error::OdeError;
+pub use self::rk4::Rk4;
+
+use crate::imp_prelude::*;
+use crate::{NdFloat, Zip};
+
+mod dopri5;
+mod error;
+mod rk4;
+
+/// The solution of an initial value problem.
+///
+/// It holds the times `t` of the steps of the solver, the states at those
+/// times, and the coefficients of an interpolating polynomial on each step,
+/// which are used by [`.eval()`](#method.eval).
+#[derive(Clone, Debug)]
+pub struct Solution<A, D>
+where
+    D: Dimension,
+{
+    t: Array1<A>,
+    y: Array2<A>,
+    /// Three coefficients of the interpolating polynomial for each step.
+    dense: Array3<A>,
+    dim: D,
+}
+
+impl<A, D> Solution<A, D>
+where
+    A: NdFloat,
+    D: Dimension,
+{
+    /// Return the times of the steps, from the start of the interval to its
+    /// end.
+    pub fn t(&self) -> &Array1<A> {
+        &self.t
+    }
+
+    /// Return the states at the times of the steps, one in each row.
+    ///
+    /// The rows hold the elements of the states in logical order; use
+    /// [`.state()`](#method.state) to view a row with the shape of the
+    /// state.
+    pub fn y(&self) -> &Array2<A> {
+        &self.y
+    }
+
+    /// Return a view of the state at the time of step `index`.
+    ///
+    /// **Panics** if `index` is out of bounds.
+    pub fn state(&self, index: usize) -> ArrayView<'_, A, D> {
+        self.y.row(index).into_shape(self.dim.clone()).unwrap()
+    }
+
+    /// Return the state at time `t`, interpolated with a polynomial of
+    /// degree four (for [`Dopri5`](struct.Dopri5.html)) or three (for
+    /// [`Rk4`](struct.Rk4.html)) between the steps.
+    ///
+    /// ***Errors*** if `t` is outside the interval of the solution.
+    pub fn eval(&self, t: A) -> Result<Array<A, D>, OdeError> {
+        let mut out = Array1::zeros(self.y.cols());
+        self.eval_into(t, out.view_mut())?;
+        Ok(out.into_shape(self.dim.clone()).unwrap())
+    }
+
+    /// Return the states at the times `t`, one in each row, interpolated
+    /// as by [`.eval()`](#method.eval).
+    ///
+    /// ***Errors*** if any time is outside the interval of the solution.
+    pub fn eval_points<S>(&self, t: &ArrayBase<S, Ix1>) -> Result<Array2<A>, OdeError>
+    where
+        S: Data<Elem = A>,
+    {
+        let mut out = Array2::zeros((t.len(), self.y.cols()));
+        for (&t, row) in t.iter().zip(out.outer_iter_mut()) {
+            self.eval_into(t, row)?;
+        }
+        Ok(out)
+    }
+
+    fn eval_into(&self, t: A, mut out: ArrayViewMut1<'_, A>) -> Result<(), OdeError> {
+        let ts = self.t.as_slice().unwrap();
+        let (first, last) = (ts[0], ts[ts.len() - 1]);
+        let forward = first <= last;
+        let inside = if forward {
+            first <= t && t <= last
+        } else {
+            last <= t && t <= first
+        };
+        if !inside {
+            return Err(OdeError::OutOfRange);
+        }
+        if ts.len() == 1 {
+            out.assign(&self.y.row(0));
+            return Ok(());
+        }
+        // the step whose interval contains t
+        let (mut i, mut hi) = (0, ts.len() - 1);
+        while hi - i > 1 {
+            let mid = (i + hi) / 2;
+            if (forward && ts[mid] <= t) || (!forward && ts[mid] >= t) {
+                i = mid;
+            } else {
+                hi = mid;
+            }
+        }
+        let theta = (t - ts[i]) / (ts[i + 1] - ts[i]);
+        let theta1 = A::one() - theta;
+        Zip::from(&mut out)
+            .and(self.y.row(i))
+            .and(self.y.row(i + 1))
+            .and(self.dense.slice(s![i, 0, ..]))
+            .and(self.dense.slice(s![i, 1, ..]))
+            .and(self.dense.slice(s![i, 2, ..]))
+            .apply(|out, &y0, &y1, &r3, &r4, &r5| {
+                *out = y0 + theta * ((y1 - y0) + theta1 * (r3 + theta * (r4 + theta1 * r5)));
+            });
+        Ok(())
+    }
+}
+
+/// The steps of a solver, collected while solving.
+struct Trajectory<A> {
+    t: Vec<A>,
+    y: Vec<A>,
+    dense: Vec<A>,
+}
+
+impl<A: NdFloat> Trajectory<A> {
+    fn new<D: Dimension>(t0: A, y0: &Array<A, D>) -> Self {
+        Trajectory {
+            t: vec![t0],
+            y: y0.iter().cloned().collect(),
+            dense: Vec::new(),
+        }
+    }
+
+    /// Add the step of size `h` from state `y0` to state `y1` at time `t1`,
+    /// with derivatives `f0` and `f1` at its ends.
+    ///
+    /// The state on the step is interpolated with the polynomial
+    ///
+    /// ```text
+    /// y0 + θ (y1 - y0 + (1 - θ) (r3 + θ (r4 + (1 - θ) r5)))
+    /// ```
+    ///
+    /// in the fraction `θ` of the step, where `r3` and `r4` make it the cubic
+    /// Hermite interpolant, and `r5`, if any, raises its order to four.
+    fn push<D: Dimension>(
+        &mut self,
+        t1: A,
+        h: A,
+        (y0, y1): (&Array<A, D>, &Array<A, D>),
+        (f0, f1): (&Array<A, D>, &Array<A, D>),
+        r5: Option<&Array<A, D>>,
+    ) {
+        self.t.push(t1);
+        self.y.extend(y1.iter().cloned());
+        let n = y1.len();
+        let start = self.dense.len();
+        self.dense.resize(start + 3 * n, A::zero());
+        let (r3, rest) = self.dense[start..].split_at_mut(n);
+        let (r4, rest) = rest.split_at_mut(n);
+        for (i, (((&y0, &y1), &f0), &f1)) in y0.iter().zip(y1).zip(f0).zip(f1).enumerate() {
+            let r2 = y1 - y0;
+            r3[i] = h * f0 - r2;
+            r4[i] = r2 - h * f1 - r3[i];
+        }
+        if let Some(r5) = r5 {
+            for (elt, &r) in rest.iter_mut().zip(r5) {
+                *elt = r;
+            }
+        }
+    }
+
+    fn finish<D: Dimension>(self, dim: D) -> Solution<A, D> {
+        let n = dim.size();
+        let steps = self.t.len() - 1;
+        Solution {
+            t: Array1::from(self.t),
+            y: Array2::from_shape_vec((steps + 1, n), self.y).unwrap(),
+            dense: Array3::from_shape_vec((steps, 3, n), self.dense).unwrap(),
+            dim,
+        }
+    }
+}
+
+/// Set `out` to `y + h Σ coeffs[i] k[i]`.
+fn stage<A, D>(out: &mut Array<A, D>, y: &Array<A, D>, h: A, coeffs: &[A], k: &[Array<A, D>])
+where
+    A: NdFloat,
+    D: Dimension,
+{
+    out.assign(y);
+    for (&c, k) in coeffs.iter().zip(k) {
+        if c != A::zero() {
+            out.scaled_add(h * c, k);
+        }
+    }
+}
@@ -0,0 +1,103 @@
+// Copyright 2019 bluss and ndarray developers.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+use crate::imp_prelude::*;
+use crate::NdFloat;
+
+use super::{stage, Solution, Trajectory};
+
+/// The classic fourth order Runge–Kutta method, with a fixed number of
+/// equal steps.
+///
+/// The solution is interpolated between the steps with cubic Hermite
+/// polynomials.
+///
+/// ```
+/// use ndarray::arr1;
+/// use ndarray::ode::Rk4;
+///
+/// // exponential decay y' = -y
+/// let sol = Rk4::new(20).solve(|_t, y, mut dy| dy.assign(&-&y), (0., 2.), &arr1(&[1.]));
+/// assert_eq!(sol.y().dim(), (21, 1));
+/// assert!((sol.state(20)[0] - (-2f64).exp()).abs() < 1e-5);
+/// ```
+#[derive(Copy, Clone, Debug)]
+pub struct Rk4 {
+    steps: usize,
+}
+
+impl Rk4 {
+    /// Create a solver that divides the interval into `steps` steps.
+    ///
+    /// **Panics** if `steps` is zero.
+    pub fn new(steps: usize) -> Self {
+        assert!(steps > 0, "Rk4::new: the number of steps must be positive");
+        Rk4 { steps }
+    }
+
+    /// Solve `dy/dt = f(t, y)` from `t_span.0` to `t_span.1`, with the
+    /// initial state `y0`.
+    ///
+    /// `f(t, y, dy)` must set `dy` to the derivative at time `t` and state
+    /// `y`. The interval may run backwards in time.
+    pub fn solve<A, F, S, D>(
+        &self,
+        mut f: F,
+        t_span: (A, A),
+        y0: &ArrayBase<S, D>,
+    ) -> Solution<A, D>
+    where
+        A: NdFloat,
+        F: FnMut(A, ArrayView<'_, A, D>, ArrayViewMut<'_, A, D>),
+        S: Data<Elem = A>,
+        D: Dimension,
+    {
+        let (t0, t_end) = t_span;
+        let dim = y0.raw_dim();
+        let zeros = || Array::<A, D>::zeros(dim.clone());
+        let (half, two, six) = (
+            A::from(0.5).unwrap(),
+            A::from(2).unwrap(),
+            A::from(6).unwrap(),
+        );
+        let h = (t_end - t0) / A::from(self.steps).unwrap();
+        let (hh, h6) = (h * half, h / six);
+
+        let mut y = zeros();
+        y.assign(y0);
+        let mut y_new = zeros();
+        let mut y_stage = zeros();
+        // the stages, and the derivative at the end of the step
+        let mut k = [zeros(), zeros(), zeros(), zeros(), zeros()];
+        let mut trajectory = Trajectory::new(t0, &y);
+
+        f(t0, y.view(), k[0].view_mut());
+        if t0 == t_end {
+            return trajectory.finish(dim);
+        }
+        for i in 0..self.steps {
+            let t = t0 + h * A::from(i).unwrap();
+            let t1 = if i + 1 == self.steps {
+                t_end
+            } else {
+                t0 + h * A::from(i + 1).unwrap()
+            };
+            for &(s, c) in &[(1, hh), (2, hh), (3, h)] {
+                let (done, rest) = k.split_at_mut(s);
+                stage(&mut y_stage, &y, c, &[A::one()], &done[s - 1..]);
+                f(t + c, y_stage.view(), rest[0].view_mut());
+            }
+            stage(&mut y_new, &y, h6, &[A::one(), two, two, A::one()], &k[..4]);
+            let (stages, end) = k.split_at_mut(4);
+            f(t1, y_new.view(), end[0].view_mut());
+            trajectory.push(t1, h, (&y, &y_new), (&stages[0], &end[0]), None);
+            std::mem::swap(&mut y, &mut y_new);
+            k.swap(0, 4);
+        }
+        trajectory.finish(dim)
+    }
+}
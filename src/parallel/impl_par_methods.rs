@@ -1,7 +1,20 @@
-use crate::{ArrayBase, DataMut, Dimension, NdProducer, Zip};
+use num_traits::{FromPrimitive, Zero};
+use std::mem::MaybeUninit;
+use std::ops::{Add, Div};
+
+use crate::convolution::{flipped, par_correlate, Mode};
+use crate::linalg::MatMul;
+use crate::{
+    Array, ArrayBase, ArrayView1, Axis, Data, DataMut, Dimension, LinalgScalar, NdProducer,
+    RemoveAxis, Zip,
+};
 
 use crate::parallel::prelude::*;
 
+/// The array methods don't split their elements into pieces shorter than
+/// this, so they stay sequential for small arrays.
+const MIN_ELEMENTS_PER_PIECE: usize = 1024;
+
 /// # Parallel methods
 ///
 /// These methods require crate feature `rayon`.
@@ -15,12 +28,16 @@ where
     ///
     /// Modify the array in place by calling `f` by mutable reference on each element.
     ///
-    /// Elements are visited in arbitrary order.
+    /// Elements are visited in arbitrary order. Small arrays are processed
+    /// without splitting them.
     pub fn par_map_inplace<F>(&mut self, f: F)
     where
         F: Fn(&mut A) + Sync + Send,
     {
-        self.view_mut().into_par_iter().for_each(f)
+        self.view_mut()
+            .into_par_iter()
+            .with_min_len(MIN_ELEMENTS_PER_PIECE)
+            .for_each(f)
     }
 
     /// Parallel version of `mapv_inplace`.
@@ -28,7 +45,8 @@ where
     /// Modify the array in place by calling `f` by **v**alue on each element.
     /// The array is updated with the new values.
     ///
-    /// Elements are visited in arbitrary order.
+    /// Elements are visited in arbitrary order. Small arrays are processed
+    /// without splitting them.
     pub fn par_mapv_inplace<F>(&mut self, f: F)
     where
         F: Fn(A) -> A + Sync + Send,
@@ -36,10 +54,192 @@ where
     {
         self.view_mut()
             .into_par_iter()
+            .with_min_len(MIN_ELEMENTS_PER_PIECE)
             .for_each(move |x| *x = f(x.clone()))
     }
 }
 
+/// # Parallel methods
+///
+/// These methods require crate feature `rayon`.
+impl<A, S, D> ArrayBase<S, D>
+where
+    S: Data<Elem = A>,
+    D: Dimension,
+    A: Send + Sync,
+{
+    /// Parallel version of [`.sum()`](#method.sum).
+    ///
+    /// The array is split into pieces that are summed in parallel, so for
+    /// floating point elements the result may be rounded differently. Small
+    /// arrays are summed without splitting them.
+    ///
+    /// ```
+    /// use ndarray::Array;
+    ///
+    /// let a = Array::linspace(0f64, 1., 1001);
+    /// assert!((a.par_sum() - 500.5).abs() < 1e-9);
+    /// ```
+    pub fn par_sum(&self) -> A
+    where
+        A: Clone + Add<Output = A> + Zero,
+    {
+        Zip::from(self.view())
+            .par_split(MIN_ELEMENTS_PER_PIECE)
+            .map(|zip| zip.into_producer().sum())
+            .reduce(A::zero, A::add)
+    }
+
+    /// Parallel version of [`.mean()`](#method.mean).
+    ///
+    /// If the array is empty, `None` is returned.
+    ///
+    /// **Panics** if `A::from_usize()` fails to convert the number of elements in the array.
+    pub fn par_mean(&self) -> Option<A>
+    where
+        A: Clone + FromPrimitive + Add<Output = A> + Div<Output = A> + Zero,
+    {
+        let n_elements = self.len();
+        if n_elements == 0 {
+            None
+        } else {
+            let n_elements = A::from_usize(n_elements)
+                .expect("Converting number of elements to `A` must not fail.");
+            Some(self.par_sum() / n_elements)
+        }
+    }
+
+    /// Parallel version of [`.sum()`](#method.sum) with a reproducible result.
+    ///
+    /// The array is split into blocks of a fixed size and the sums of the
+    /// blocks are added in a fixed order, both independent of the number of
+    /// threads, so the result is the same for every run and thread pool.
+    ///
+    /// ```
+    /// use ndarray::Array;
+    ///
+    /// let a = Array::linspace(0f64, 1., 100_001).mapv(f64::sin);
+    /// let pool = rayon::ThreadPoolBuilder::new().num_threads(1).build().unwrap();
+    /// let sum = pool.install(|| a.par_sum_reproducible());
+    /// assert_eq!(sum.to_bits(), a.par_sum_reproducible().to_bits());
+    /// ```
+    pub fn par_sum_reproducible(&self) -> A
+    where
+        A: Clone + Add<Output = A> + Zero,
+    {
+        Zip::from(self.view()).par_reduce_blocks(&|zip| zip.into_producer().sum(), &A::add)
+    }
+
+    /// Parallel version of [`.mean()`](#method.mean) with a reproducible
+    /// result, like [`.par_sum_reproducible()`](#method.par_sum_reproducible).
+    ///
+    /// If the array is empty, `None` is returned.
+    ///
+    /// **Panics** if `A::from_usize()` fails to convert the number of elements in the array.
+    pub fn par_mean_reproducible(&self) -> Option<A>
+    where
+        A: Clone + FromPrimitive + Add<Output = A> + Div<Output = A> + Zero,
+    {
+        let n_elements = self.len();
+        if n_elements == 0 {
+            None
+        } else {
+            let n_elements = A::from_usize(n_elements)
+                .expect("Converting number of elements to `A` must not fail.");
+            Some(self.par_sum_reproducible() / n_elements)
+        }
+    }
+
+    /// Parallel version of [`.fold_axis()`](#method.fold_axis).
+    ///
+    /// The lanes along `axis` are folded in parallel; the elements of each
+    /// lane are folded in order.
+    ///
+    /// **Panics** if `axis` is out of bounds.
+    pub fn par_fold_axis<B, F>(&self, axis: Axis, init: B, fold: F) -> Array<B, D::Smaller>
+    where
+        D: RemoveAxis,
+        F: Fn(&B, &A) -> B + Sync + Send,
+        B: Clone + Send + Sync,
+    {
+        Zip::from(self.lanes(axis))
+            .par_map_collect(|lane| lane.iter().fold(init.clone(), |acc, elt| fold(&acc, elt)))
+    }
+
+    /// Parallel version of [`.map_axis()`](#method.map_axis).
+    ///
+    /// The lanes along `axis` are mapped in parallel.
+    ///
+    /// **Panics** if `axis` is out of bounds.
+    ///
+    /// ```
+    /// use ndarray::{array, Axis};
+    ///
+    /// let a = array![[1, 5, 3], [4, 2, 6]];
+    /// let max = a.par_map_axis(Axis(1), |row| *row.iter().max().unwrap());
+    /// assert_eq!(max, array![5, 6]);
+    /// ```
+    pub fn par_map_axis<'a, B, F>(&'a self, axis: Axis, mapping: F) -> Array<B, D::Smaller>
+    where
+        D: RemoveAxis,
+        F: Fn(ArrayView1<'a, A>) -> B + Sync + Send,
+        B: Send,
+        A: 'a,
+    {
+        Zip::from(self.lanes(axis)).par_map_collect(mapping)
+    }
+}
+
+/// # Parallel methods
+///
+/// These methods require crate feature `rayon`.
+impl<A, S, D> ArrayBase<S, D>
+where
+    S: Data<Elem = A>,
+    D: Dimension,
+    A: LinalgScalar + Send + Sync,
+{
+    /// Parallel version of [`.convolve()`](#method.convolve).
+    ///
+    /// The elements of the result are computed in parallel.
+    pub fn par_convolve<S2>(&self, kernel: &ArrayBase<S2, D>, mode: Mode) -> Array<A, D>
+    where
+        S2: Data<Elem = A>,
+    {
+        par_correlate(&self.view(), &flipped(kernel), mode)
+    }
+
+    /// Parallel version of [`.correlate()`](#method.correlate).
+    ///
+    /// The elements of the result are computed in parallel.
+    pub fn par_correlate<S2>(&self, kernel: &ArrayBase<S2, D>, mode: Mode) -> Array<A, D>
+    where
+        S2: Data<Elem = A>,
+    {
+        par_correlate(&self.view(), &kernel.view(), mode)
+    }
+}
+
+/// # Parallel methods
+///
+/// These methods require crate feature `rayon`.
+impl<A, S, D> ArrayBase<S, D>
+where
+    S: Data<Elem = A>,
+    D: Dimension,
+{
+    /// Parallel version of [`.matmul()`](#method.matmul).
+    ///
+    /// The matrix products of the different batches are computed in
+    /// parallel.
+    pub fn par_matmul<Rhs>(&self, rhs: &Rhs) -> <Self as MatMul<Rhs>>::Output
+    where
+        Self: MatMul<Rhs>,
+    {
+        MatMul::par_matmul(self, rhs)
+    }
+}
+
 // Zip
 
 macro_rules! zip_impl {
@@ -63,6 +263,116 @@ macro_rules! zip_impl {
             {
                 self.into_par_iter().for_each(move |($($p,)*)| function($($p),*))
             }
+
+            /// Parallel version of [`fold`](#method.fold).
+            ///
+            /// The Zip is split into pieces that are folded in parallel, each
+            /// starting from a value made by `identity`; the results of the
+            /// pieces are then combined with `reduce`. The value made by
+            /// `identity` must be neutral for `reduce`, since the number of
+            /// pieces is not specified.
+            ///
+            /// Requires crate feature `rayon`.
+            ///
+            /// ```
+            /// use ndarray::{Array, Zip};
+            ///
+            /// let a = Array::from_shape_fn((64, 64), |(i, j)| (i + j) as i64);
+            /// let b = Array::from_shape_fn((64, 64), |(i, j)| (i * j) as i64);
+            /// let dot = Zip::from(&a).and(&b).par_fold(|| 0, |acc, &a, &b| acc + a * b, |x, y| x + y);
+            /// assert_eq!(dot, (&a * &b).sum());
+            /// ```
+            pub fn par_fold<ID, F, R, T>(self, identity: ID, fold: F, reduce: R) -> T
+                where ID: Fn() -> T + Sync + Send,
+                      F: Fn(T, $($p::Item),*) -> T + Sync + Send,
+                      R: Fn(T, T) -> T + Sync + Send,
+                      T: Send,
+            {
+                self.par_split(1)
+                    .map(|zip| zip.fold(identity(), &fold))
+                    .reduce(&identity, reduce)
+            }
+
+            /// Parallel version of [`fold`](#method.fold) with a reproducible
+            /// result.
+            ///
+            /// The Zip is split into blocks of a fixed size, which are folded
+            /// in parallel, each starting from a value made by `identity`; the
+            /// results of the blocks are then combined with `reduce` in a fixed
+            /// order. The blocks and the order depend only on the shape and
+            /// layout of the inputs, not on the number of threads, so the
+            /// result is the same for every run and thread pool.
+            ///
+            /// Requires crate feature `rayon`.
+            pub fn par_fold_reproducible<ID, F, R, T>(self, identity: ID, fold: F, reduce: R) -> T
+                where ID: Fn() -> T + Sync + Send,
+                      F: Fn(T, $($p::Item),*) -> T + Sync + Send,
+                      R: Fn(T, T) -> T + Sync + Send,
+                      T: Send,
+            {
+                self.par_reduce_blocks(&|zip: Self| zip.fold(identity(), &fold), &reduce)
+            }
+
+            /// Parallel version of [`all`](#method.all).
+            ///
+            /// Returns `true` if `predicate` evaluates to `true` for all
+            /// elements, and `true` if the input arrays are empty.
+            ///
+            /// Requires crate feature `rayon`.
+            pub fn par_all<F>(self, predicate: F) -> bool
+                where F: Fn($($p::Item),*) -> bool + Sync + Send
+            {
+                self.par_split(1).all(|zip| zip.all(&predicate))
+            }
+
+            /// Returns `true` if `predicate` evaluates to `true` for any
+            /// element, and `false` if the input arrays are empty.
+            ///
+            /// The pieces of the Zip are tested in parallel.
+            ///
+            /// Requires crate feature `rayon`.
+            pub fn par_any<F>(self, predicate: F) -> bool
+                where F: Fn($($p::Item),*) -> bool + Sync + Send
+            {
+                self.par_split(1)
+                    .any(|zip| !zip.all(|$($p),*| !predicate($($p),*)))
+            }
+
+            /// Parallel version of [`map_collect`](#method.map_collect).
+            ///
+            /// The elements of the result are computed in parallel.
+            ///
+            /// Requires crate feature `rayon`.
+            pub fn par_map_collect<R, F>(self, f: F) -> Array<R, D>
+                where F: Fn($($p::Item),*) -> R + Sync + Send,
+                      R: Send,
+            {
+                let mut output = self.uninitialized_output();
+                self.and_output(output.view_mut())
+                    .par_apply_core(move |($($p,)* out,)| *out = MaybeUninit::new(f($($p),*)));
+                unsafe { output.assume_init() }
+            }
+
+            /// Parallel version of [`map_collect2`](#method.map_collect2).
+            ///
+            /// The elements of the results are computed in parallel.
+            ///
+            /// Requires crate feature `rayon`.
+            pub fn par_map_collect2<R1, R2, F>(self, f: F) -> (Array<R1, D>, Array<R2, D>)
+                where F: Fn($($p::Item),*) -> (R1, R2) + Sync + Send,
+                      R1: Send,
+                      R2: Send,
+            {
+                let mut output1 = self.uninitialized_output();
+                let mut output2 = self.uninitialized_output();
+                self.and_output2(output1.view_mut(), output2.view_mut())
+                    .par_apply_core(move |($($p,)* out1, out2,)| {
+                        let (r1, r2) = f($($p),*);
+                        *out1 = MaybeUninit::new(r1);
+                        *out2 = MaybeUninit::new(r2);
+                    });
+                unsafe { (output1.assume_init(), output2.assume_init()) }
+            }
         }
         )+
     }
@@ -14,13 +14,21 @@
 //! - [`ArrayView`](ArrayView): `.into_par_iter()`
 //! - [`ArrayViewMut`](ArrayViewMut): `.into_par_iter()`
 //! - [`AxisIter`](iter::AxisIter), [`AxisIterMut`](iter::AxisIterMut): `.into_par_iter()`
+//! - [`Windows`](iter::Windows), [`AxisWindows`](iter::AxisWindows): `.into_par_iter()`
+//! - [`Chunks`](iter::Chunks), [`ChunksMut`](iter::ChunksMut): `.into_par_iter()`
 //! - [`Zip`] `.into_par_iter()`
 //!
 //! The following other parallelized methods exist:
 //!
 //! - [`ArrayBase::par_map_inplace()`]
 //! - [`ArrayBase::par_mapv_inplace()`]
+//! - [`ArrayBase::par_sum()`], [`ArrayBase::par_mean()`]
+//! - [`ArrayBase::par_sum_reproducible()`], [`ArrayBase::par_mean_reproducible()`]
+//! - [`ArrayBase::par_fold_axis()`], [`ArrayBase::par_map_axis()`]
 //! - [`Zip::par_apply()`] (all arities)
+//! - [`Zip::par_map_collect()`] and [`Zip::par_map_collect2()`] (all arities)
+//! - [`Zip::par_fold()`], [`Zip::par_all()`] and [`Zip::par_any()`] (all arities)
+//! - [`Zip::par_fold_reproducible()`] (all arities)
 //!
 //! Note that you can use the parallel iterator for [Zip] to access all other
 //! rayon parallel iterator methods.
@@ -29,6 +37,13 @@
 //! “unindexed”. Use ndarray’s [Zip] for lock step parallel iteration of
 //! multiple arrays or producers at a time.
 //!
+//! The parallel reductions split their work adaptively, depending on the
+//! number of threads and on which threads are idle, so the rounding of a
+//! floating point result can vary from run to run. The `_reproducible`
+//! reductions instead split the data into blocks of a fixed size and combine
+//! the results of the blocks in a fixed order, so they give the same result
+//! for any number of threads.
+//!
 //! # Examples
 //!
 //! ## Arrays and array views
@@ -102,6 +117,47 @@
 //!         });
 //! }
 //! ```
+//!
+//! ## Splitting and thread pools
+//!
+//! The parallel iterators split their work into pieces for the threads as
+//! needed, down to single elements or items. Use
+//! [`.with_min_len()`](struct.Parallel.html#method.with_min_len) and
+//! [`.with_max_len()`](struct.Parallel.html#method.with_max_len) to bound the
+//! length of the pieces, for example to avoid splitting cheap work into tasks
+//! that cost more than the work itself. The element-wise array methods, like
+//! `par_map_inplace` and `par_sum`, don't split small arrays at all.
+//!
+//! All the parallel methods and iterators run in the current rayon thread
+//! pool; use
+//! [`ThreadPool::install()`](https://docs.rs/rayon/1/rayon/struct.ThreadPool.html#method.install)
+//! to run them in a pool of your own.
+//!
+//! ```
+//! extern crate ndarray;
+//! extern crate rayon;
+//!
+//! use ndarray::Array2;
+//! use ndarray::Zip;
+//! use ndarray::parallel::prelude::*;
+//!
+//! fn main() {
+//!     let mut a = Array2::<f64>::zeros((64, 64));
+//!     let b = Array2::<f64>::ones((64, 64));
+//!
+//!     let pool = rayon::ThreadPoolBuilder::new().num_threads(2).build().unwrap();
+//!     pool.install(|| {
+//!         Zip::from(&mut a)
+//!             .and(&b)
+//!             .into_par_iter()
+//!             .with_min_len(512)
+//!             .for_each(|(a, &b)| *a += b);
+//!
+//!         Zip::from(&mut a).par_apply(|a| *a *= 2.);
+//!     });
+//!     assert_eq!(a, Array2::from_elem((64, 64), 2.));
+//! }
+//! ```
 
 /// Into- traits for creating parallelized iterators and/or using [`par_azip!`]
 pub mod prelude {
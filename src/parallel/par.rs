@@ -1,8 +1,8 @@
 use rayon::iter::plumbing::bridge;
-use rayon::iter::plumbing::bridge_unindexed;
 use rayon::iter::plumbing::Folder;
 use rayon::iter::plumbing::Producer;
 use rayon::iter::plumbing::ProducerCallback;
+use rayon::iter::plumbing::Reducer;
 use rayon::iter::plumbing::UnindexedProducer;
 use rayon::iter::plumbing::{Consumer, UnindexedConsumer};
 use rayon::iter::IndexedParallelIterator;
@@ -11,18 +11,133 @@ use rayon::prelude::IntoParallelIterator;
 
 use crate::iter::AxisIter;
 use crate::iter::AxisIterMut;
-use crate::Dimension;
+use crate::iter::{AxisWindows, Chunks, ChunksMut, Windows};
 use crate::{ArrayView, ArrayViewMut};
+use crate::{Axis, Dimension};
 
 /// Parallel iterator wrapper.
 #[derive(Copy, Clone, Debug)]
 pub struct Parallel<I> {
     iter: I,
+    split_len: SplitLen,
+}
+
+impl<I> Parallel<I> {
+    fn new(iter: I) -> Self {
+        Parallel {
+            iter,
+            split_len: SplitLen {
+                min: 1,
+                max: std::usize::MAX,
+            },
+        }
+    }
+
+    /// Set the minimum length of the pieces the iterator is split into for
+    /// the threads: a piece is not split if its halves would be shorter.
+    ///
+    /// The length is the number of elements for arrays, array views and
+    /// `Zip`, and the number of items for the other iterators. Use it to keep
+    /// small workloads from being split into tasks that cost more than the
+    /// work they do.
+    pub fn with_min_len(mut self, min_len: usize) -> Self {
+        self.split_len.min = min_len;
+        self
+    }
+
+    /// Set the maximum length of the pieces the iterator is split into for
+    /// the threads: longer pieces are always split, unless that would break
+    /// the minimum length.
+    ///
+    /// The length is counted like for
+    /// [`.with_min_len()`](#method.with_min_len).
+    pub fn with_max_len(mut self, max_len: usize) -> Self {
+        self.split_len.max = max_len;
+        self
+    }
+}
+
+/// The limits of the lengths of the pieces a parallel iterator is split into.
+#[derive(Copy, Clone, Debug)]
+struct SplitLen {
+    min: usize,
+    max: usize,
+}
+
+impl SplitLen {
+    /// Return true if a piece of length `len` may be split in two.
+    fn can_split(self, len: usize) -> bool {
+        len > 1 && len / 2 >= self.min
+    }
+
+    /// Return true if a piece of length `len` must be split in two.
+    fn must_split(self, len: usize) -> bool {
+        len > self.max && self.can_split(len)
+    }
 }
 
 /// Parallel producer wrapper.
 #[derive(Copy, Clone, Debug)]
-struct ParallelProducer<I>(I);
+struct ParallelProducer<I>(I, SplitLen);
+
+/// An unindexed producer that knows the length it is split by.
+trait UnindexedLen: UnindexedProducer {
+    fn len(&self) -> usize;
+    fn split_len(&self) -> SplitLen;
+}
+
+/// Drive `consumer` with `producer`, like rayon's `bridge_unindexed`, but
+/// keep splitting pieces that are longer than their maximum length.
+fn bridge_unindexed_len<P, C>(producer: P, consumer: C) -> C::Result
+where
+    P: UnindexedLen,
+    C: UnindexedConsumer<P::Item>,
+{
+    bridge_unindexed_len_helper(rayon::current_num_threads(), false, producer, consumer)
+}
+
+fn bridge_unindexed_len_helper<P, C>(
+    mut splits: usize,
+    migrated: bool,
+    producer: P,
+    consumer: C,
+) -> C::Result
+where
+    P: UnindexedLen,
+    C: UnindexedConsumer<P::Item>,
+{
+    if consumer.full() {
+        return consumer.into_folder().complete();
+    }
+    // Split adaptively like rayon: once per thread, and again when a piece
+    // was stolen by another thread
+    let adaptive = if migrated {
+        splits = ::std::cmp::max(rayon::current_num_threads(), splits / 2);
+        true
+    } else if splits > 0 {
+        splits /= 2;
+        true
+    } else {
+        false
+    };
+    let producer = if adaptive || producer.split_len().must_split(producer.len()) {
+        match producer.split() {
+            (left, Some(right)) => {
+                let reducer = consumer.to_reducer();
+                let left_consumer = consumer.split_off_left();
+                let (left, right) = rayon::join_context(
+                    |ctx| bridge_unindexed_len_helper(splits, ctx.migrated(), left, left_consumer),
+                    |ctx| bridge_unindexed_len_helper(splits, ctx.migrated(), right, consumer),
+                );
+                return reducer.reduce(left, right);
+            }
+            (producer, None) => producer,
+        }
+    } else {
+        producer
+    };
+    producer.fold_with(consumer.into_folder()).complete()
+}
 
 macro_rules! par_iter_wrapper {
     // thread_bounds are either Sync or Send + Sync
@@ -35,9 +150,7 @@ macro_rules! par_iter_wrapper {
         type Item = <Self as Iterator>::Item;
         type Iter = Parallel<Self>;
         fn into_par_iter(self) -> Self::Iter {
-            Parallel {
-                iter: self,
-            }
+            Parallel::new(self)
         }
     }
 
@@ -64,7 +177,7 @@ macro_rules! par_iter_wrapper {
         fn with_producer<Cb>(self, callback: Cb) -> Cb::Output
             where Cb: ProducerCallback<Self::Item>
         {
-            callback.callback(ParallelProducer(self.iter))
+            callback.callback(ParallelProducer(self.iter, self.split_len))
         }
 
         fn len(&self) -> usize {
@@ -101,9 +214,17 @@ macro_rules! par_iter_wrapper {
             self.0
         }
 
+        fn min_len(&self) -> usize {
+            self.1.min
+        }
+
+        fn max_len(&self) -> usize {
+            self.1.max
+        }
+
         fn split_at(self, i: usize) -> (Self, Self) {
             let (a, b) = self.0.split_at(i);
-            (ParallelProducer(a), ParallelProducer(b))
+            (ParallelProducer(a, self.1), ParallelProducer(b, self.1))
         }
     }
 
@@ -124,9 +245,7 @@ macro_rules! par_iter_view_wrapper {
         type Item = <Self as IntoIterator>::Item;
         type Iter = Parallel<Self>;
         fn into_par_iter(self) -> Self::Iter {
-            Parallel {
-                iter: self,
-            }
+            Parallel::new(self)
         }
     }
 
@@ -139,7 +258,7 @@ macro_rules! par_iter_view_wrapper {
         fn drive_unindexed<C>(self, consumer: C) -> C::Result
             where C: UnindexedConsumer<Self::Item>
         {
-            bridge_unindexed(ParallelProducer(self.iter), consumer)
+            bridge_unindexed_len(ParallelProducer(self.iter, self.split_len), consumer)
         }
 
         fn opt_len(&self) -> Option<usize> {
@@ -153,14 +272,14 @@ macro_rules! par_iter_view_wrapper {
     {
         type Item = <$view_name<'a, A, D> as IntoIterator>::Item;
         fn split(self) -> (Self, Option<Self>) {
-            if self.0.len() <= 1 {
+            if !self.1.can_split(self.0.len()) {
                 return (self, None)
             }
             let array = self.0;
             let max_axis = array.max_stride_axis();
             let mid = array.len_of(max_axis) / 2;
             let (a, b) = array.split_at(max_axis, mid);
-            (ParallelProducer(a), Some(ParallelProducer(b)))
+            (ParallelProducer(a, self.1), Some(ParallelProducer(b, self.1)))
         }
 
         fn fold_with<F>(self, folder: F) -> F
@@ -170,6 +289,19 @@ macro_rules! par_iter_view_wrapper {
         }
     }
 
+    impl<'a, A, D> UnindexedLen for ParallelProducer<$view_name<'a, A, D>>
+        where D: Dimension,
+              A: $($thread_bounds)*,
+    {
+        fn len(&self) -> usize {
+            self.0.len()
+        }
+
+        fn split_len(&self) -> SplitLen {
+            self.1
+        }
+    }
+
     impl<'a, A, D> IntoIterator for ParallelProducer<$view_name<'a, A, D>>
         where D: Dimension,
               A: $($thread_bounds)*,
@@ -187,8 +319,96 @@ macro_rules! par_iter_view_wrapper {
 par_iter_view_wrapper!(ArrayView, [Sync]);
 par_iter_view_wrapper!(ArrayViewMut, [Sync + Send]);
 
+macro_rules! par_iter_producer_wrapper {
+    // thread_bounds are either Sync or Send + Sync; shape and split are the
+    // methods that return the shape of the producer and split it
+    ($producer_name:ident, $view_name:ident, [$($thread_bounds:tt)*], $shape:ident, $split:ident) => {
+        /// Requires crate feature `rayon`.
+        impl<'a, A, D> IntoParallelIterator for $producer_name<'a, A, D>
+        where
+            D: Dimension,
+            A: $($thread_bounds)*,
+        {
+            type Item = $view_name<'a, A, D>;
+            type Iter = Parallel<Self>;
+            fn into_par_iter(self) -> Self::Iter {
+                Parallel::new(self)
+            }
+        }
+
+        impl<'a, A, D> ParallelIterator for Parallel<$producer_name<'a, A, D>>
+        where
+            D: Dimension,
+            A: $($thread_bounds)*,
+        {
+            type Item = $view_name<'a, A, D>;
+            fn drive_unindexed<C>(self, consumer: C) -> C::Result
+            where
+                C: UnindexedConsumer<Self::Item>,
+            {
+                bridge_unindexed_len(ParallelProducer(self.iter, self.split_len), consumer)
+            }
+
+            fn opt_len(&self) -> Option<usize> {
+                None
+            }
+        }
+
+        impl<'a, A, D> UnindexedProducer for ParallelProducer<$producer_name<'a, A, D>>
+        where
+            D: Dimension,
+            A: $($thread_bounds)*,
+        {
+            type Item = $view_name<'a, A, D>;
+            fn split(self) -> (Self, Option<Self>) {
+                let dim = self.0.$shape();
+                if !self.1.can_split(dim.size()) {
+                    return (self, None);
+                }
+                // split the longest axis of the producer
+                let axis = (0..dim.ndim()).max_by_key(|&i| dim[i]).unwrap();
+                let (a, b) = self.0.$split(Axis(axis), dim[axis] / 2);
+                (ParallelProducer(a, self.1), Some(ParallelProducer(b, self.1)))
+            }
+
+            fn fold_with<F>(self, folder: F) -> F
+            where
+                F: Folder<Self::Item>,
+            {
+                self.0
+                    .into_iter()
+                    .fold(folder, move |f, elt| f.consume(elt))
+            }
+        }
+
+        impl<'a, A, D> UnindexedLen for ParallelProducer<$producer_name<'a, A, D>>
+        where
+            D: Dimension,
+            A: $($thread_bounds)*,
+        {
+            fn len(&self) -> usize {
+                self.0.$shape().size()
+            }
+
+            fn split_len(&self) -> SplitLen {
+                self.1
+            }
+        }
+    };
+}
+
+par_iter_producer_wrapper!(Windows, ArrayView, [Sync], raw_dim, split_at);
+par_iter_producer_wrapper!(AxisWindows, ArrayView, [Sync], raw_dim, split_at);
+par_iter_producer_wrapper!(Chunks, ArrayView, [Sync], grid, split_chunks);
+par_iter_producer_wrapper!(ChunksMut, ArrayViewMut, [Send + Sync], grid, split_chunks);
+
+use crate::zip::ZippableTuple;
 use crate::{FoldWhile, NdProducer, Zip};
 
+/// The largest number of element tuples in a block of the reproducible
+/// reductions.
+const REPRODUCIBLE_BLOCK_LEN: usize = 4096;
+
 macro_rules! zip_impl {
     ($([$($p:ident)*],)+) => {
         $(
@@ -203,9 +423,7 @@ macro_rules! zip_impl {
             type Item = ($($p::Item ,)*);
             type Iter = Parallel<Self>;
             fn into_par_iter(self) -> Self::Iter {
-                Parallel {
-                    iter: self,
-                }
+                Parallel::new(self)
             }
         }
 
@@ -221,7 +439,7 @@ macro_rules! zip_impl {
             fn drive_unindexed<Cons>(self, consumer: Cons) -> Cons::Result
                 where Cons: UnindexedConsumer<Self::Item>
             {
-                bridge_unindexed(ParallelProducer(self.iter), consumer)
+                bridge_unindexed_len(ParallelProducer(self.iter, self.split_len), consumer)
             }
 
             fn opt_len(&self) -> Option<usize> {
@@ -239,11 +457,11 @@ macro_rules! zip_impl {
             type Item = ($($p::Item ,)*);
 
             fn split(self) -> (Self, Option<Self>) {
-                if self.0.size() <= 1 {
+                if !self.1.can_split(self.0.size()) {
                     return (self, None)
                 }
                 let (a, b) = self.0.split();
-                (ParallelProducer(a), Some(ParallelProducer(b)))
+                (ParallelProducer(a, self.1), Some(ParallelProducer(b, self.1)))
             }
 
             fn fold_with<Fold>(self, folder: Fold) -> Fold
@@ -259,6 +477,22 @@ macro_rules! zip_impl {
                 }).into_inner()
             }
         }
+
+        #[allow(non_snake_case)]
+        impl<D, $($p),*> UnindexedLen for ParallelProducer<Zip<($($p,)*), D>>
+            where $($p : Send , )*
+                  $($p::Item : Send , )*
+                  D: Dimension,
+                  $($p: NdProducer<Dim=D> ,)*
+        {
+            fn len(&self) -> usize {
+                self.0.size()
+            }
+
+            fn split_len(&self) -> SplitLen {
+                self.1
+            }
+        }
         )+
     }
 }
@@ -271,3 +505,70 @@ zip_impl! {
     [P1 P2 P3 P4 P5],
     [P1 P2 P3 P4 P5 P6],
 }
+
+impl<P, D> Zip<P, D>
+where
+    D: Dimension,
+{
+    /// Return a parallel iterator of pieces of the Zip.
+    ///
+    /// The Zip is split in two, along the axis that best preserves element
+    /// locality, as long as rayon asks for more pieces and the halves have at
+    /// least `min_len` element tuples.
+    pub(crate) fn par_split(self, min_len: usize) -> impl ParallelIterator<Item = Self>
+    where
+        P: ZippableTuple<Dim = D> + Send,
+    {
+        let split_len = SplitLen {
+            min: min_len,
+            max: std::usize::MAX,
+        };
+        rayon::iter::split(self, move |zip| {
+            if !split_len.can_split(zip.size()) {
+                return (zip, None);
+            }
+            let (a, b) = zip.split_tuple();
+            (a, Some(b))
+        })
+    }
+
+    /// Split the Zip into blocks, map each block with `map` and combine the
+    /// results with `reduce`, in parallel.
+    ///
+    /// The Zip is split in two until the pieces have at most
+    /// `REPRODUCIBLE_BLOCK_LEN` element tuples, and the results of the two
+    /// halves are always combined as `reduce(first, second)`. The blocks and
+    /// the order of the reductions depend only on the shape and layout of
+    /// the Zip, not on the thread pool.
+    pub(crate) fn par_reduce_blocks<T, M, R>(self, map: &M, reduce: &R) -> T
+    where
+        P: ZippableTuple<Dim = D> + Send,
+        M: Fn(Self) -> T + Sync,
+        R: Fn(T, T) -> T + Sync,
+        T: Send,
+    {
+        if self.size() <= REPRODUCIBLE_BLOCK_LEN {
+            return map(self);
+        }
+        let (a, b) = self.split_tuple();
+        let (x, y) = rayon::join(
+            move || a.par_reduce_blocks(map, reduce),
+            move || b.par_reduce_blocks(map, reduce),
+        );
+        reduce(x, y)
+    }
+
+    /// Apply `function` to all element tuples of the Zip, in parallel.
+    pub(crate) fn par_apply_core<F>(self, function: F)
+    where
+        F: Fn(P::Item) + Sync + Send,
+        P: ZippableTuple<Dim = D> + Send,
+    {
+        self.par_split(1).for_each(|mut zip| {
+            zip.apply_core((), |(), item| {
+                function(item);
+                FoldWhile::Continue(())
+            });
+        });
+    }
+}
@@ -0,0 +1,185 @@
+// Copyright 2019 bluss and ndarray developers.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Polynomials.
+//!
+//! A polynomial of degree `n` is represented by the one-dimensional array of
+//! its `n + 1` coefficients, from the highest power to the constant term:
+//! `p[0] xⁿ + p[1] xⁿ⁻¹ + … + p[n]`.
+//!
+//! ```
+//! use ndarray::arr1;
+//! use ndarray::polynomial::{polyder, polyfit, polyint, polyval};
+//!
+//! // 2 x² - 3 x + 1
+//! let p = arr1(&[2., -3., 1.]);
+//! assert_eq!(polyval(&p, &arr1(&[0., 1., 2.])), arr1(&[1., 0., 3.]));
+//! assert_eq!(polyder(&p, 1), arr1(&[4., -3.]));
+//! assert_eq!(polyint(&polyder(&p, 1), 1), arr1(&[2., -3., 0.]));
+//!
+//! let x = arr1(&[-1., 0., 1., 2., 3.]);
+//! let fit = polyfit(&x, &polyval(&p, &x), 2).unwrap();
+//! assert!((fit - &p).iter().all(|d: &f64| d.abs() < 1e-12));
+//! ```
+
+use crate::imp_prelude::*;
+use crate::linalg::LinalgError;
+use crate::{ComplexFloat, LinalgScalar};
+
+use num_traits::{One, Zero};
+
+/// Return the Vandermonde matrix of `x` with `n` columns, whose column `j`
+/// is `x` to the power `n - 1 - j`.
+///
+/// This is the matrix of a least squares fit of a polynomial of degree
+/// `n - 1` to data points with coordinates `x`.
+///
+/// ```
+/// use ndarray::{arr1, arr2};
+/// use ndarray::polynomial::vander;
+///
+/// assert_eq!(vander(&arr1(&[1, 2, 3]), 3), arr2(&[[1, 1, 1],
+///                                                 [4, 2, 1],
+///                                                 [9, 3, 1]]));
+/// ```
+pub fn vander<A, S>(x: &ArrayBase<S, Ix1>, n: usize) -> Array2<A>
+where
+    A: LinalgScalar,
+    S: Data<Elem = A>,
+{
+    let mut v = Array2::zeros((x.len(), n));
+    for (mut row, &x) in v.outer_iter_mut().zip(x) {
+        let mut power = A::one();
+        for elt in row.iter_mut().rev() {
+            *elt = power;
+            power = power * x;
+        }
+    }
+    v
+}
+
+/// Return the coefficients of the polynomial of degree `deg` that fits the
+/// data points `(x[i], y[i])` best in the least squares sense.
+///
+/// `y` may be one-dimensional, or two-dimensional with a data set in each
+/// column, in which case each column of the result holds the coefficients
+/// for the corresponding data set.
+///
+/// The fit is computed with [`.lstsq()`](../struct.ArrayBase.html#method.lstsq)
+/// on the Vandermonde matrix, with its columns scaled to unit norm for
+/// better conditioning. If there are fewer distinct points than
+/// coefficients, the coefficients of minimum norm are returned.
+///
+/// ***Errors*** if `y` is not one- or two-dimensional, or if the number of
+/// data points in `y` is not the length of `x`.
+pub fn polyfit<A, S1, S2, D>(
+    x: &ArrayBase<S1, Ix1>,
+    y: &ArrayBase<S2, D>,
+    deg: usize,
+) -> Result<Array<A, D>, LinalgError>
+where
+    A: ComplexFloat,
+    S1: Data<Elem = A>,
+    S2: Data<Elem = A>,
+    D: Dimension,
+{
+    let mut v = vander(x, deg + 1);
+    let mut scale = Vec::with_capacity(deg + 1);
+    for mut column in v.gencolumns_mut() {
+        let mut norm = column.norm_l2();
+        if norm.is_zero() {
+            norm = A::Real::one();
+        }
+        let inv = A::Real::one() / norm;
+        column.mapv_inplace(|c| c.scale(inv));
+        scale.push(inv);
+    }
+    let mut p = v.lstsq(y)?.solution;
+    for mut lane in p.lanes_mut(Axis(0)) {
+        for (c, &s) in lane.iter_mut().zip(&scale) {
+            *c = c.scale(s);
+        }
+    }
+    Ok(p)
+}
+
+/// Return the value of the polynomial `p` at each element of `x`, computed
+/// with Horner's method.
+///
+/// An empty `p` is the zero polynomial.
+///
+/// ```
+/// use ndarray::{arr1, arr2};
+/// use ndarray::polynomial::polyval;
+///
+/// // x³ - 1
+/// let p = arr1(&[1, 0, 0, -1]);
+/// assert_eq!(polyval(&p, &arr2(&[[0, 1], [2, -1]])), arr2(&[[-1, 0], [7, -2]]));
+/// ```
+pub fn polyval<A, S1, S2, D>(p: &ArrayBase<S1, Ix1>, x: &ArrayBase<S2, D>) -> Array<A, D>
+where
+    A: LinalgScalar,
+    S1: Data<Elem = A>,
+    S2: Data<Elem = A>,
+    D: Dimension,
+{
+    x.map(|&x| p.iter().fold(A::zero(), |acc, &c| acc * x + c))
+}
+
+/// Return the `m`-th derivative of the polynomial `p`.
+///
+/// The result has `m` fewer coefficients than `p`, or is the zero
+/// polynomial `[0]` if the degree of `p` is less than `m`.
+pub fn polyder<A, S>(p: &ArrayBase<S, Ix1>, m: usize) -> Array1<A>
+where
+    A: LinalgScalar,
+    S: Data<Elem = A>,
+{
+    if p.len() <= m {
+        return Array1::zeros(1);
+    }
+    let mut p = p.to_owned();
+    for _ in 0..m {
+        // multiply each coefficient by its power, from the constant term up
+        let n = p.len() - 1;
+        let mut power = A::zero();
+        let mut der = Array1::zeros(n);
+        for (d, &c) in der.iter_mut().rev().zip(p.iter().rev().skip(1)) {
+            power = power + A::one();
+            *d = c * power;
+        }
+        p = der;
+    }
+    p
+}
+
+/// Return the `m`-th antiderivative of the polynomial `p`, with all
+/// integration constants zero.
+///
+/// The result has `m` more coefficients than `p`; the last `m` are zero.
+/// The coefficients are floating point, real or complex, since integrating
+/// divides them.
+pub fn polyint<A, S>(p: &ArrayBase<S, Ix1>, m: usize) -> Array1<A>
+where
+    A: ComplexFloat,
+    S: Data<Elem = A>,
+{
+    let mut p = p.to_owned();
+    for _ in 0..m {
+        // divide each coefficient by its power plus one, from the constant
+        // term up
+        let mut power = A::zero();
+        let mut int = Array1::zeros(p.len() + 1);
+        for (i, &c) in int.iter_mut().rev().skip(1).zip(p.iter().rev()) {
+            power += A::one();
+            *i = c / power;
+        }
+        p = int;
+    }
+    p
+}
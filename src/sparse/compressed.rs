@@ -0,0 +1,738 @@
+// Copyright 2019 bluss and ndarray developers.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use std::ops::{Add, Mul, Neg, Sub};
+
+use num_traits::Zero;
+
+use crate::error::{from_kind, ErrorKind, ShapeError};
+use crate::imp_prelude::*;
+use crate::linalg::Dot;
+use crate::sparse::CooMatrix;
+use crate::{LinalgScalar, ScalarOperand};
+
+/// The storage shared by the CSR and CSC formats.
+///
+/// The stored elements of *lane* `i` (a row for CSR, a column for CSC) are
+/// at `indptr[i]..indptr[i + 1]` in `indices` and `data`, and the indices
+/// within each lane are strictly increasing.
+#[derive(Clone, Debug, PartialEq)]
+pub(crate) struct Compressed<A> {
+    /// The number of lanes.
+    outer: usize,
+    /// The length of each lane.
+    inner: usize,
+    indptr: Vec<usize>,
+    indices: Vec<usize>,
+    data: Vec<A>,
+}
+
+impl<A> Compressed<A> {
+    fn new(
+        outer: usize,
+        inner: usize,
+        indptr: Vec<usize>,
+        indices: Vec<usize>,
+        data: Vec<A>,
+    ) -> Result<Self, ShapeError> {
+        if indptr.len() != outer + 1
+            || indices.len() != data.len()
+            || indptr[0] != 0
+            || indptr[outer] != indices.len()
+            || indptr.windows(2).any(|w| w[0] > w[1])
+        {
+            return Err(from_kind(ErrorKind::IncompatibleShape));
+        }
+        for w in indptr.windows(2) {
+            let lane = &indices[w[0]..w[1]];
+            if lane.iter().any(|&j| j >= inner) {
+                return Err(from_kind(ErrorKind::OutOfBounds));
+            }
+            if lane.windows(2).any(|p| p[0] >= p[1]) {
+                return Err(from_kind(ErrorKind::IncompatibleLayout));
+            }
+        }
+        Ok(Compressed {
+            outer,
+            inner,
+            indptr,
+            indices,
+            data,
+        })
+    }
+
+    /// Build the storage from `(lane, index, value)` triplets, which must be
+    /// in bounds, summing the values of duplicate entries.
+    pub(crate) fn from_triplets(
+        outer: usize,
+        inner: usize,
+        lanes: &[usize],
+        indices: &[usize],
+        data: &[A],
+    ) -> Self
+    where
+        A: Clone + Add<Output = A>,
+    {
+        // counting sort of the entries by lane
+        let mut starts = vec![0; outer + 1];
+        for &i in lanes {
+            starts[i + 1] += 1;
+        }
+        for i in 0..outer {
+            starts[i + 1] += starts[i];
+        }
+        let mut next = starts.clone();
+        let mut order = vec![0; lanes.len()];
+        for (k, &i) in lanes.iter().enumerate() {
+            order[next[i]] = k;
+            next[i] += 1;
+        }
+
+        let mut indptr = Vec::with_capacity(outer + 1);
+        let mut new_indices = Vec::with_capacity(lanes.len());
+        let mut new_data: Vec<A> = Vec::with_capacity(lanes.len());
+        indptr.push(0);
+        for i in 0..outer {
+            let lane = &mut order[starts[i]..starts[i + 1]];
+            lane.sort_by_key(|&k| indices[k]);
+            let lane_start = new_indices.len();
+            for &k in lane.iter() {
+                if new_indices.len() > lane_start && new_indices.last() == Some(&indices[k]) {
+                    let last = new_data.last_mut().unwrap();
+                    *last = last.clone() + data[k].clone();
+                } else {
+                    new_indices.push(indices[k]);
+                    new_data.push(data[k].clone());
+                }
+            }
+            indptr.push(new_indices.len());
+        }
+        Compressed {
+            outer,
+            inner,
+            indptr,
+            indices: new_indices,
+            data: new_data,
+        }
+    }
+
+    /// Build the storage from the nonzero elements of `a`, with the rows of
+    /// `a` as lanes.
+    fn from_dense(a: ArrayView2<'_, A>) -> Self
+    where
+        A: Clone + Zero,
+    {
+        let (outer, inner) = a.dim();
+        let mut indptr = Vec::with_capacity(outer + 1);
+        let mut indices = Vec::new();
+        let mut data = Vec::new();
+        indptr.push(0);
+        for row in a.outer_iter() {
+            for (j, x) in row.indexed_iter() {
+                if !x.is_zero() {
+                    indices.push(j);
+                    data.push(x.clone());
+                }
+            }
+            indptr.push(indices.len());
+        }
+        Compressed {
+            outer,
+            inner,
+            indptr,
+            indices,
+            data,
+        }
+    }
+
+    /// Return the dense matrix with the lanes as rows.
+    fn to_dense(&self) -> Array2<A>
+    where
+        A: Clone + Zero,
+    {
+        let mut a = Array2::zeros((self.outer, self.inner));
+        for (i, mut row) in a.outer_iter_mut().enumerate() {
+            let (indices, data) = self.lane(i);
+            for (&j, x) in indices.iter().zip(data) {
+                row[j] = x.clone();
+            }
+        }
+        a
+    }
+
+    /// Return the `(lane, index)` coordinates and values of the stored
+    /// elements.
+    fn triplets(&self) -> (Vec<usize>, Vec<usize>, Vec<A>)
+    where
+        A: Clone,
+    {
+        let mut lanes = Vec::with_capacity(self.indices.len());
+        for i in 0..self.outer {
+            let len = self.indptr[i + 1] - self.indptr[i];
+            lanes.extend((0..len).map(|_| i));
+        }
+        (lanes, self.indices.clone(), self.data.clone())
+    }
+
+    /// Return the storage of the same matrix with the roles of lanes and
+    /// indices swapped, which converts between CSR and CSC.
+    fn swap_order(&self) -> Self
+    where
+        A: Clone,
+    {
+        let mut indptr = vec![0; self.inner + 1];
+        for &j in &self.indices {
+            indptr[j + 1] += 1;
+        }
+        for j in 0..self.inner {
+            indptr[j + 1] += indptr[j];
+        }
+        let mut next = indptr.clone();
+        let mut order = vec![0; self.indices.len()];
+        let mut indices = vec![0; self.indices.len()];
+        for i in 0..self.outer {
+            for k in self.indptr[i]..self.indptr[i + 1] {
+                let j = self.indices[k];
+                indices[next[j]] = i;
+                order[next[j]] = k;
+                next[j] += 1;
+            }
+        }
+        let data = order.iter().map(|&k| self.data[k].clone()).collect();
+        Compressed {
+            outer: self.inner,
+            inner: self.outer,
+            indptr,
+            indices,
+            data,
+        }
+    }
+
+    fn lane(&self, i: usize) -> (ArrayView1<'_, usize>, ArrayView1<'_, A>) {
+        let range = self.indptr[i]..self.indptr[i + 1];
+        (
+            aview1(&self.indices[range.clone()]),
+            aview1(&self.data[range]),
+        )
+    }
+
+    /// y ← y + S x, where lane `i` of the storage `S` is its row `i`.
+    fn gather_into(&self, x: ArrayView1<'_, A>, mut y: ArrayViewMut1<'_, A>)
+    where
+        A: LinalgScalar,
+    {
+        for (i, y) in y.iter_mut().enumerate() {
+            let (indices, data) = self.lane(i);
+            *y = indices
+                .iter()
+                .zip(data)
+                .fold(*y, |acc, (&j, &a)| acc + a * x[j]);
+        }
+    }
+
+    /// y ← y + Sᵀ x, where lane `i` of the storage `S` is its row `i`.
+    fn scatter_into(&self, x: ArrayView1<'_, A>, mut y: ArrayViewMut1<'_, A>)
+    where
+        A: LinalgScalar,
+    {
+        for (i, &xi) in x.iter().enumerate() {
+            let (indices, data) = self.lane(i);
+            for (&j, &a) in indices.iter().zip(data) {
+                y[j] = y[j] + a * xi;
+            }
+        }
+    }
+
+    fn get(&self, i: usize, j: usize) -> Option<&A> {
+        if i >= self.outer || j >= self.inner {
+            return None;
+        }
+        let start = self.indptr[i];
+        let lane = &self.indices[start..self.indptr[i + 1]];
+        lane.binary_search(&j).ok().map(|k| &self.data[start + k])
+    }
+
+    fn map<B, F>(&self, f: F) -> Compressed<B>
+    where
+        F: FnMut(&A) -> B,
+    {
+        Compressed {
+            outer: self.outer,
+            inner: self.inner,
+            indptr: self.indptr.clone(),
+            indices: self.indices.clone(),
+            data: self.data.iter().map(f).collect(),
+        }
+    }
+
+    /// Combine the elements of `self` and `other`, which must have the same
+    /// shape, with `f`, over the union of their stored elements if `union`
+    /// is true and their intersection otherwise. Missing elements are zero,
+    /// and zero results are not stored.
+    fn zip_with<F>(&self, other: &Self, union: bool, mut f: F) -> Self
+    where
+        A: Clone + Zero,
+        F: FnMut(A, A) -> A,
+    {
+        debug_assert_eq!((self.outer, self.inner), (other.outer, other.inner));
+        let mut indptr = Vec::with_capacity(self.outer + 1);
+        let mut indices = Vec::new();
+        let mut data = Vec::new();
+        fn push<A: Zero>(indices: &mut Vec<usize>, data: &mut Vec<A>, j: usize, x: A) {
+            if !x.is_zero() {
+                indices.push(j);
+                data.push(x);
+            }
+        }
+        indptr.push(0);
+        for i in 0..self.outer {
+            let (ai, ad) = self.lane(i);
+            let (bi, bd) = other.lane(i);
+            let (mut p, mut q) = (0, 0);
+            while p < ai.len() || q < bi.len() {
+                let ja = ai.get(p).cloned().unwrap_or(std::usize::MAX);
+                let jb = bi.get(q).cloned().unwrap_or(std::usize::MAX);
+                if ja == jb {
+                    push(&mut indices, &mut data, ja, f(ad[p].clone(), bd[q].clone()));
+                    p += 1;
+                    q += 1;
+                } else if ja < jb {
+                    if union {
+                        push(&mut indices, &mut data, ja, f(ad[p].clone(), A::zero()));
+                    }
+                    p += 1;
+                } else {
+                    if union {
+                        push(&mut indices, &mut data, jb, f(A::zero(), bd[q].clone()));
+                    }
+                    q += 1;
+                }
+            }
+            indptr.push(indices.len());
+        }
+        Compressed {
+            outer: self.outer,
+            inner: self.inner,
+            indptr,
+            indices,
+            data,
+        }
+    }
+}
+
+/// A sparse matrix in compressed sparse row (CSR) format.
+///
+/// The stored elements of row `i` are at `indptr[i]..indptr[i + 1]` in the
+/// arrays of column indices and values, sorted by column, and each element
+/// is stored at most once. CSR is the format for fast row access and
+/// matrix-vector products; use [`CooMatrix`](struct.CooMatrix.html) to
+/// assemble a matrix.
+#[derive(Clone, Debug, PartialEq)]
+pub struct CsrMatrix<A> {
+    storage: Compressed<A>,
+}
+
+/// A sparse matrix in compressed sparse column (CSC) format.
+///
+/// The stored elements of column `j` are at `indptr[j]..indptr[j + 1]` in
+/// the arrays of row indices and values, sorted by row, and each element is
+/// stored at most once. CSC is the format for fast column access; use
+/// [`CooMatrix`](struct.CooMatrix.html) to assemble a matrix.
+#[derive(Clone, Debug, PartialEq)]
+pub struct CscMatrix<A> {
+    storage: Compressed<A>,
+}
+
+macro_rules! impl_compressed {
+    ($name:ident, $other:ident, $fmt:expr, $lane:ident, $lane_name:expr, $index_name:expr,
+     $to_other:ident, $to_coo_order:ident) => {
+        impl<A> $name<A> {
+            #[doc = "Create a matrix of `shape` from its "]
+            #[doc = $fmt]
+            #[doc = " arrays: the "]
+            #[doc = $lane_name]
+            #[doc = " pointers `indptr`, the "]
+            #[doc = $index_name]
+            #[doc = " indices `indices` and the values `data`."]
+            ///
+            /// ***Errors*** if the lengths of the arrays are inconsistent with
+            /// each other or with `shape` (`IncompatibleShape`), if an index is
+            /// out of bounds (`OutOfBounds`), or if the indices within a lane
+            /// are not strictly increasing (`IncompatibleLayout`).
+            pub fn new(
+                shape: (usize, usize),
+                indptr: Vec<usize>,
+                indices: Vec<usize>,
+                data: Vec<A>,
+            ) -> Result<Self, ShapeError> {
+                let (outer, inner) = Self::storage_shape(shape);
+                Ok($name {
+                    storage: Compressed::new(outer, inner, indptr, indices, data)?,
+                })
+            }
+
+            pub(crate) fn from_storage(storage: Compressed<A>) -> Self {
+                $name { storage }
+            }
+
+            /// Return the shape of the matrix as (rows, columns).
+            pub fn shape(&self) -> (usize, usize) {
+                Self::storage_shape((self.storage.outer, self.storage.inner))
+            }
+
+            /// Return the number of rows.
+            pub fn rows(&self) -> usize {
+                self.shape().0
+            }
+
+            /// Return the number of columns.
+            pub fn cols(&self) -> usize {
+                self.shape().1
+            }
+
+            /// Return the number of stored elements.
+            pub fn nnz(&self) -> usize {
+                self.storage.data.len()
+            }
+
+            #[doc = "Return a view of the "]
+            #[doc = $lane_name]
+            #[doc = " pointers."]
+            pub fn indptr(&self) -> ArrayView1<'_, usize> {
+                aview1(&self.storage.indptr)
+            }
+
+            #[doc = "Return a view of the "]
+            #[doc = $index_name]
+            #[doc = " indices of the stored elements."]
+            pub fn indices(&self) -> ArrayView1<'_, usize> {
+                aview1(&self.storage.indices)
+            }
+
+            /// Return a view of the values of the stored elements.
+            pub fn data(&self) -> ArrayView1<'_, A> {
+                aview1(&self.storage.data)
+            }
+
+            #[doc = "Return views of the "]
+            #[doc = $index_name]
+            #[doc = " indices and values of the stored elements of "]
+            #[doc = $lane_name]
+            #[doc = " `i`."]
+            ///
+            /// **Panics** if `i` is out of bounds.
+            pub fn $lane(&self, i: usize) -> (ArrayView1<'_, usize>, ArrayView1<'_, A>) {
+                assert!(i < self.storage.outer, "ndarray: index out of bounds");
+                self.storage.lane(i)
+            }
+
+            /// Return a reference to the element at `index`, or `None` if it
+            /// is not stored or out of bounds.
+            pub fn get(&self, (i, j): (usize, usize)) -> Option<&A> {
+                let (outer, inner) = Self::storage_shape((i, j));
+                self.storage.get(outer, inner)
+            }
+
+            /// Return the diagonal of the matrix, with zeros where no element
+            /// is stored.
+            pub fn diagonal(&self) -> Array1<A>
+            where
+                A: Clone + Zero,
+            {
+                let (m, n) = self.shape();
+                (0..m.min(n))
+                    .map(|i| self.get((i, i)).cloned().unwrap_or_else(A::zero))
+                    .collect()
+            }
+
+            /// Return the transpose, which has the same storage in the other
+            /// compressed format.
+            pub fn transpose(&self) -> $other<A>
+            where
+                A: Clone,
+            {
+                $other::from_storage(self.storage.clone())
+            }
+
+            /// Return the transpose, reusing the storage of `self`.
+            pub fn into_transpose(self) -> $other<A> {
+                $other::from_storage(self.storage)
+            }
+
+            /// Return the matrix converted to the other compressed format.
+            pub fn $to_other(&self) -> $other<A>
+            where
+                A: Clone,
+            {
+                $other::from_storage(self.storage.swap_order())
+            }
+
+            /// Return the matrix with the nonzero elements of `a`.
+            pub fn from_dense<S>(a: &ArrayBase<S, Ix2>) -> Self
+            where
+                S: Data<Elem = A>,
+                A: Clone + Zero,
+            {
+                let storage = Compressed::from_dense(Self::storage_view(a.view()));
+                $name { storage }
+            }
+
+            /// Return the dense matrix.
+            pub fn to_dense(&self) -> Array2<A>
+            where
+                A: Clone + Zero,
+            {
+                Self::from_storage_dense(self.storage.to_dense())
+            }
+
+            /// Return the matrix converted to coordinate format.
+            pub fn to_coo(&self) -> CooMatrix<A>
+            where
+                A: Clone,
+            {
+                let (lanes, indices, data) = self.storage.triplets();
+                let (rows, cols) = $to_coo_order(lanes, indices);
+                CooMatrix::from_parts(self.shape(), rows, cols, data)
+            }
+
+            /// Return a matrix with the same sparsity pattern, and values
+            /// computed by `f` from the stored values.
+            pub fn map<B, F>(&self, f: F) -> $name<B>
+            where
+                F: FnMut(&A) -> B,
+            {
+                $name::from_storage(self.storage.map(f))
+            }
+        }
+
+        /// Perform the product of the sparse matrix and the vector `rhs`.
+        ///
+        /// **Panics** if the shapes are incompatible.
+        impl<A, S> Dot<ArrayBase<S, Ix1>> for $name<A>
+        where
+            S: Data<Elem = A>,
+            A: LinalgScalar,
+        {
+            type Output = Array1<A>;
+            fn dot(&self, rhs: &ArrayBase<S, Ix1>) -> Array1<A> {
+                let (m, n) = self.shape();
+                assert_eq!(
+                    n,
+                    rhs.len(),
+                    "ndarray: sparse matrix and vector are not compatible for multiplication"
+                );
+                let mut c = Array1::zeros(m);
+                self.dot_vec_into(rhs.view(), c.view_mut());
+                c
+            }
+        }
+
+        /// Perform the product of the row vector `self` and the sparse
+        /// matrix `rhs`, which is the product of the transpose of `rhs` and
+        /// `self`.
+        ///
+        /// **Panics** if the shapes are incompatible.
+        impl<A, S> Dot<$name<A>> for ArrayBase<S, Ix1>
+        where
+            S: Data<Elem = A>,
+            A: LinalgScalar,
+        {
+            type Output = Array1<A>;
+            fn dot(&self, rhs: &$name<A>) -> Array1<A> {
+                let (m, n) = rhs.shape();
+                assert_eq!(
+                    m,
+                    self.len(),
+                    "ndarray: vector and sparse matrix are not compatible for multiplication"
+                );
+                let mut c = Array1::zeros(n);
+                rhs.vec_dot_into(self.view(), c.view_mut());
+                c
+            }
+        }
+
+        /// Perform the product of the sparse matrix and the dense matrix
+        /// `rhs`.
+        ///
+        /// **Panics** if the shapes are incompatible.
+        impl<A, S> Dot<ArrayBase<S, Ix2>> for $name<A>
+        where
+            S: Data<Elem = A>,
+            A: LinalgScalar,
+        {
+            type Output = Array2<A>;
+            fn dot(&self, rhs: &ArrayBase<S, Ix2>) -> Array2<A> {
+                let (m, n) = self.shape();
+                assert_eq!(
+                    n,
+                    rhs.rows(),
+                    "ndarray: sparse and dense matrix are not compatible for multiplication"
+                );
+                let mut c = Array2::zeros((m, rhs.cols()));
+                self.dot_into(rhs.view(), c.view_mut());
+                c
+            }
+        }
+
+        impl<'a, A> Add<&'a $name<A>> for &'a $name<A>
+        where
+            A: Clone + Zero + Add<Output = A>,
+        {
+            type Output = $name<A>;
+            /// Elementwise addition; **panics** if the shapes differ.
+            fn add(self, rhs: &'a $name<A>) -> $name<A> {
+                assert_eq!(self.shape(), rhs.shape(), "ndarray: sparse shapes differ");
+                $name::from_storage(self.storage.zip_with(&rhs.storage, true, |x, y| x + y))
+            }
+        }
+
+        impl<'a, A> Sub<&'a $name<A>> for &'a $name<A>
+        where
+            A: Clone + Zero + Sub<Output = A>,
+        {
+            type Output = $name<A>;
+            /// Elementwise subtraction; **panics** if the shapes differ.
+            fn sub(self, rhs: &'a $name<A>) -> $name<A> {
+                assert_eq!(self.shape(), rhs.shape(), "ndarray: sparse shapes differ");
+                $name::from_storage(self.storage.zip_with(&rhs.storage, true, |x, y| x - y))
+            }
+        }
+
+        impl<'a, A> Mul<&'a $name<A>> for &'a $name<A>
+        where
+            A: Clone + Zero + Mul<Output = A>,
+        {
+            type Output = $name<A>;
+            /// Elementwise multiplication; **panics** if the shapes differ.
+            fn mul(self, rhs: &'a $name<A>) -> $name<A> {
+                assert_eq!(self.shape(), rhs.shape(), "ndarray: sparse shapes differ");
+                $name::from_storage(self.storage.zip_with(&rhs.storage, false, |x, y| x * y))
+            }
+        }
+
+        impl<'a, A> Mul<A> for &'a $name<A>
+        where
+            A: ScalarOperand + Mul<Output = A>,
+        {
+            type Output = $name<A>;
+            /// Multiply each stored element by the scalar `rhs`.
+            fn mul(self, rhs: A) -> $name<A> {
+                self.map(|x| x.clone() * rhs.clone())
+            }
+        }
+
+        impl<'a, A> Neg for &'a $name<A>
+        where
+            A: Clone + Neg<Output = A>,
+        {
+            type Output = $name<A>;
+            fn neg(self) -> $name<A> {
+                self.map(|x| -x.clone())
+            }
+        }
+    };
+}
+
+/// Return the `(rows, cols)` coordinates for lanes that are rows.
+fn rows_first(lanes: Vec<usize>, indices: Vec<usize>) -> (Vec<usize>, Vec<usize>) {
+    (lanes, indices)
+}
+
+/// Return the `(rows, cols)` coordinates for lanes that are columns.
+fn cols_first(lanes: Vec<usize>, indices: Vec<usize>) -> (Vec<usize>, Vec<usize>) {
+    (indices, lanes)
+}
+
+impl_compressed!(CsrMatrix, CscMatrix, "CSR", row, "row", "column", to_csc, rows_first);
+impl_compressed!(CscMatrix, CsrMatrix, "CSC", column, "column", "row", to_csr, cols_first);
+
+impl<A> CsrMatrix<A> {
+    fn storage_shape((rows, cols): (usize, usize)) -> (usize, usize) {
+        (rows, cols)
+    }
+
+    fn storage_view(a: ArrayView2<'_, A>) -> ArrayView2<'_, A> {
+        a
+    }
+
+    fn from_storage_dense(a: Array2<A>) -> Array2<A> {
+        a
+    }
+
+    /// y ← y + self · x
+    fn dot_vec_into(&self, x: ArrayView1<'_, A>, y: ArrayViewMut1<'_, A>)
+    where
+        A: LinalgScalar,
+    {
+        self.storage.gather_into(x, y)
+    }
+
+    /// y ← y + selfᵀ · x
+    fn vec_dot_into(&self, x: ArrayView1<'_, A>, y: ArrayViewMut1<'_, A>)
+    where
+        A: LinalgScalar,
+    {
+        self.storage.scatter_into(x, y)
+    }
+
+    /// C ← C + self · B
+    fn dot_into(&self, b: ArrayView2<'_, A>, mut c: ArrayViewMut2<'_, A>)
+    where
+        A: LinalgScalar,
+    {
+        for (i, mut c_row) in c.outer_iter_mut().enumerate() {
+            let (indices, data) = self.storage.lane(i);
+            for (&j, &x) in indices.iter().zip(data) {
+                c_row.scaled_add(x, &b.row(j));
+            }
+        }
+    }
+}
+
+impl<A> CscMatrix<A> {
+    fn storage_shape((rows, cols): (usize, usize)) -> (usize, usize) {
+        (cols, rows)
+    }
+
+    fn storage_view(a: ArrayView2<'_, A>) -> ArrayView2<'_, A> {
+        a.reversed_axes()
+    }
+
+    fn from_storage_dense(a: Array2<A>) -> Array2<A> {
+        a.reversed_axes()
+    }
+
+    /// y ← y + self · x
+    fn dot_vec_into(&self, x: ArrayView1<'_, A>, y: ArrayViewMut1<'_, A>)
+    where
+        A: LinalgScalar,
+    {
+        self.storage.scatter_into(x, y)
+    }
+
+    /// y ← y + selfᵀ · x
+    fn vec_dot_into(&self, x: ArrayView1<'_, A>, y: ArrayViewMut1<'_, A>)
+    where
+        A: LinalgScalar,
+    {
+        self.storage.gather_into(x, y)
+    }
+
+    /// C ← C + self · B
+    fn dot_into(&self, b: ArrayView2<'_, A>, mut c: ArrayViewMut2<'_, A>)
+    where
+        A: LinalgScalar,
+    {
+        for (j, b_row) in b.outer_iter().enumerate() {
+            let (indices, data) = self.storage.lane(j);
+            for (&i, &x) in indices.iter().zip(data) {
+                c.row_mut(i).scaled_add(x, &b_row);
+            }
+        }
+    }
+}
@@ -0,0 +1,185 @@
+// Copyright 2019 bluss and ndarray developers.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use std::ops::Add;
+
+use num_traits::Zero;
+
+use crate::error::{from_kind, ErrorKind, ShapeError};
+use crate::imp_prelude::*;
+use crate::sparse::compressed::Compressed;
+use crate::sparse::{CscMatrix, CsrMatrix};
+
+/// A sparse matrix in coordinate (COO) format, a list of
+/// `(row, column, value)` entries.
+///
+/// COO is the format for assembling a matrix: entries can be pushed in any
+/// order, and entries with the same coordinates are summed when the matrix is
+/// converted, as in finite element assembly. Convert to
+/// [`CsrMatrix`](struct.CsrMatrix.html) or
+/// [`CscMatrix`](struct.CscMatrix.html) for computation.
+///
+/// ```
+/// use ndarray::arr2;
+/// use ndarray::sparse::CooMatrix;
+///
+/// let mut a = CooMatrix::new((2, 3));
+/// a.push(0, 0, 1.);
+/// a.push(1, 2, 2.);
+/// a.push(0, 0, 3.);
+/// let csr = a.to_csr();
+/// assert_eq!(csr.nnz(), 2);
+/// assert_eq!(csr.to_dense(), arr2(&[[4., 0., 0.],
+///                                   [0., 0., 2.]]));
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct CooMatrix<A> {
+    shape: (usize, usize),
+    rows: Vec<usize>,
+    cols: Vec<usize>,
+    data: Vec<A>,
+}
+
+impl<A> CooMatrix<A> {
+    /// Create an empty matrix of `shape`.
+    pub fn new(shape: (usize, usize)) -> Self {
+        Self::with_capacity(shape, 0)
+    }
+
+    /// Create an empty matrix of `shape`, with space for `capacity` entries.
+    pub fn with_capacity(shape: (usize, usize), capacity: usize) -> Self {
+        CooMatrix {
+            shape,
+            rows: Vec::with_capacity(capacity),
+            cols: Vec::with_capacity(capacity),
+            data: Vec::with_capacity(capacity),
+        }
+    }
+
+    /// Create a matrix of `shape` from the row indices, column indices and
+    /// values of its entries.
+    ///
+    /// ***Errors*** if the three vectors have different lengths
+    /// (`IncompatibleShape`) or if an index is out of bounds (`OutOfBounds`).
+    pub fn from_triplets(
+        shape: (usize, usize),
+        rows: Vec<usize>,
+        cols: Vec<usize>,
+        data: Vec<A>,
+    ) -> Result<Self, ShapeError> {
+        if rows.len() != data.len() || cols.len() != data.len() {
+            return Err(from_kind(ErrorKind::IncompatibleShape));
+        }
+        if rows.iter().any(|&i| i >= shape.0) || cols.iter().any(|&j| j >= shape.1) {
+            return Err(from_kind(ErrorKind::OutOfBounds));
+        }
+        Ok(Self::from_parts(shape, rows, cols, data))
+    }
+
+    pub(crate) fn from_parts(
+        shape: (usize, usize),
+        rows: Vec<usize>,
+        cols: Vec<usize>,
+        data: Vec<A>,
+    ) -> Self {
+        CooMatrix {
+            shape,
+            rows,
+            cols,
+            data,
+        }
+    }
+
+    /// Add the entry `value` at (`row`, `col`).
+    ///
+    /// **Panics** if the coordinates are out of bounds.
+    pub fn push(&mut self, row: usize, col: usize, value: A) {
+        assert!(
+            row < self.shape.0 && col < self.shape.1,
+            "ndarray: index out of bounds"
+        );
+        self.rows.push(row);
+        self.cols.push(col);
+        self.data.push(value);
+    }
+
+    /// Return the shape of the matrix as (rows, columns).
+    pub fn shape(&self) -> (usize, usize) {
+        self.shape
+    }
+
+    /// Return the number of entries, counting duplicates separately.
+    pub fn nnz(&self) -> usize {
+        self.data.len()
+    }
+
+    /// Return a view of the row indices of the entries.
+    pub fn row_indices(&self) -> ArrayView1<'_, usize> {
+        aview1(&self.rows)
+    }
+
+    /// Return a view of the column indices of the entries.
+    pub fn col_indices(&self) -> ArrayView1<'_, usize> {
+        aview1(&self.cols)
+    }
+
+    /// Return a view of the values of the entries.
+    pub fn data(&self) -> ArrayView1<'_, A> {
+        aview1(&self.data)
+    }
+
+    /// Return the matrix in CSR format, summing duplicate entries.
+    pub fn to_csr(&self) -> CsrMatrix<A>
+    where
+        A: Clone + Add<Output = A>,
+    {
+        let (m, n) = self.shape;
+        CsrMatrix::from_storage(Compressed::from_triplets(
+            m, n, &self.rows, &self.cols, &self.data,
+        ))
+    }
+
+    /// Return the matrix in CSC format, summing duplicate entries.
+    pub fn to_csc(&self) -> CscMatrix<A>
+    where
+        A: Clone + Add<Output = A>,
+    {
+        let (m, n) = self.shape;
+        CscMatrix::from_storage(Compressed::from_triplets(
+            n, m, &self.cols, &self.rows, &self.data,
+        ))
+    }
+
+    /// Return the matrix with the nonzero elements of `a`, in row major
+    /// order.
+    pub fn from_dense<S>(a: &ArrayBase<S, Ix2>) -> Self
+    where
+        S: Data<Elem = A>,
+        A: Clone + Zero,
+    {
+        let mut coo = CooMatrix::new(a.dim());
+        for ((i, j), x) in a.indexed_iter() {
+            if !x.is_zero() {
+                coo.push(i, j, x.clone());
+            }
+        }
+        coo
+    }
+
+    /// Return the dense matrix, summing duplicate entries.
+    pub fn to_dense(&self) -> Array2<A>
+    where
+        A: Clone + Zero + Add<Output = A>,
+    {
+        let mut a = Array2::<A>::zeros(self.shape);
+        for ((&i, &j), x) in self.rows.iter().zip(&self.cols).zip(&self.data) {
+            a[(i, j)] = a[(i, j)].clone() + x.clone();
+        }
+        a
+    }
+}
@@ -0,0 +1,50 @@
+// Copyright 2019 bluss and ndarray developers.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Sparse matrices.
+//!
+//! - [`CooMatrix`](struct.CooMatrix.html) stores a list of entries, and is
+//!   used to assemble a matrix.
+//! - [`CsrMatrix`](struct.CsrMatrix.html) and
+//!   [`CscMatrix`](struct.CscMatrix.html) store the elements compressed by
+//!   row or by column, and are used for computation.
+//!
+//! The compressed formats multiply dense vectors and matrices through the
+//! [`Dot`](../linalg/trait.Dot.html) trait, support elementwise arithmetic
+//! with the operators `+`, `-` and `*` on references, and give access to
+//! their rows (or columns) and stored arrays as `ArrayView`s.
+//!
+//! ```
+//! use ndarray::arr1;
+//! use ndarray::linalg::Dot;
+//! use ndarray::sparse::CooMatrix;
+//!
+//! // the 1-D Laplacian
+//! let n = 5;
+//! let mut a = CooMatrix::new((n, n));
+//! for i in 0..n {
+//!     a.push(i, i, 2.);
+//!     if i > 0 {
+//!         a.push(i, i - 1, -1.);
+//!         a.push(i - 1, i, -1.);
+//!     }
+//! }
+//! let a = a.to_csr();
+//! let x = arr1(&[1., 2., 3., 4., 5.]);
+//! assert_eq!(a.dot(&x), arr1(&[0., 0., 0., 0., 6.]));
+//!
+//! let (cols, values) = a.row(2);
+//! assert_eq!(cols, arr1(&[1, 2, 3]));
+//! assert_eq!(values, arr1(&[-1., 2., -1.]));
+//! ```
+
+pub use self::compressed::{CscMatrix, CsrMatrix};
+pub use self::coo::CooMatrix;
+
+mod compressed;
+mod coo;
@@ -6,8 +6,12 @@
 // option. This file may not be copied, modified, or distributed
 // except according to those terms.
 
+use std::mem::{ManuallyDrop, MaybeUninit};
+
+use crate::dimension::size_of_shape_checked;
 use crate::error::{from_kind, ErrorKind, ShapeError};
 use crate::imp_prelude::*;
+use crate::{Slice, Zip};
 
 /// Stack arrays along the given axis.
 ///
@@ -109,3 +113,190 @@ macro_rules! stack {
         $crate::stack($axis, &[ $($crate::ArrayView::from(&$array) ),* ]).unwrap()
     }
 }
+
+/// A grid of arrays, nested to any depth, to be assembled by [`block!`][1].
+///
+/// [1]: macro.block.html
+#[doc(hidden)]
+pub enum BlockGrid<'a, A, D> {
+    Block(ArrayView<'a, A, D>),
+    Grid(Vec<BlockGrid<'a, A, D>>),
+}
+
+/// Assemble an array from the blocks of `grid`, where the *k*-th level of
+/// nesting of the grid runs along axis *k*. See [`block!`][1].
+///
+/// [1]: macro.block.html
+///
+/// ***Errors*** if the grid is not rectangular, if it is nested deeper than
+/// the arrays have axes, or if the lengths of the blocks are not consistent
+/// along each row and column of the grid (all `IncompatibleShape`).<br>
+/// ***Errors*** if a level of the grid is empty (`Unsupported`).
+#[doc(hidden)]
+pub fn block_from_grid<A, D>(grid: BlockGrid<'_, A, D>) -> Result<Array<A, D>, ShapeError>
+where
+    A: Copy,
+    D: Dimension,
+{
+    let mut grid_shape = Vec::new();
+    let mut blocks = Vec::new();
+    flatten_grid(grid, 0, &mut grid_shape, &mut blocks)?;
+    let depth = grid_shape.len();
+    let first = &blocks[0];
+    if depth > first.ndim() || blocks.iter().any(|b| b.ndim() != first.ndim()) {
+        return Err(from_kind(ErrorKind::IncompatibleShape));
+    }
+
+    // the lengths of the blocks along each axis of the grid, read from the
+    // first row (column, ...) of blocks along that axis
+    let mut grid_strides = vec![1; depth];
+    for k in (1..depth).rev() {
+        grid_strides[k - 1] = grid_strides[k] * grid_shape[k];
+    }
+    let lens: Vec<Vec<usize>> = (0..depth)
+        .map(|k| {
+            (0..grid_shape[k])
+                .map(|i| blocks[i * grid_strides[k]].len_of(Axis(k)))
+                .collect()
+        })
+        .collect();
+    let grid_index = |n: usize, k: usize| n / grid_strides[k] % grid_shape[k];
+    for (n, block) in blocks.iter().enumerate() {
+        for (k, &len) in block.shape().iter().enumerate() {
+            let expected = if k < depth {
+                lens[k][grid_index(n, k)]
+            } else {
+                first.len_of(Axis(k))
+            };
+            if len != expected {
+                return Err(from_kind(ErrorKind::IncompatibleShape));
+            }
+        }
+    }
+
+    let mut res_dim = first.raw_dim();
+    for (k, lens) in lens.iter().enumerate() {
+        res_dim[k] = lens.iter().sum();
+    }
+    let size = size_of_shape_checked(&res_dim)?;
+    let mut elements = Vec::with_capacity(size);
+    // Safe because `MaybeUninit` needs no initialization
+    unsafe {
+        elements.set_len(size);
+    }
+    let mut res = Array::<MaybeUninit<A>, _>::from_shape_vec(res_dim.clone(), elements)?;
+    for (n, block) in blocks.iter().enumerate() {
+        let mut view = res.view_mut();
+        for (k, lens) in lens.iter().enumerate() {
+            let i = grid_index(n, k);
+            let start = lens[..i].iter().sum::<usize>();
+            view.slice_axis_inplace(Axis(k), Slice::from(start..start + lens[i]));
+        }
+        Zip::from(view)
+            .and(block)
+            .apply(|out, &x| *out = MaybeUninit::new(x));
+    }
+    // the blocks cover the whole result, so every element has been written
+    let mut elements = ManuallyDrop::new(res.into_raw_vec());
+    unsafe {
+        let elements = Vec::from_raw_parts(
+            elements.as_mut_ptr() as *mut A,
+            elements.len(),
+            elements.capacity(),
+        );
+        Ok(Array::from_shape_vec_unchecked(res_dim, elements))
+    }
+}
+
+/// Collect the blocks of `grid` in row major order, and its shape in
+/// `grid_shape`.
+fn flatten_grid<'a, A, D>(
+    grid: BlockGrid<'a, A, D>,
+    level: usize,
+    grid_shape: &mut Vec<usize>,
+    blocks: &mut Vec<ArrayView<'a, A, D>>,
+) -> Result<(), ShapeError> {
+    match grid {
+        BlockGrid::Block(block) => {
+            if level != grid_shape.len() {
+                return Err(from_kind(ErrorKind::IncompatibleShape));
+            }
+            blocks.push(block);
+        }
+        BlockGrid::Grid(cells) => {
+            if cells.is_empty() {
+                return Err(from_kind(ErrorKind::Unsupported));
+            }
+            if level == grid_shape.len() {
+                // a new, deeper level is only allowed until the first block
+                if !blocks.is_empty() {
+                    return Err(from_kind(ErrorKind::IncompatibleShape));
+                }
+                grid_shape.push(cells.len());
+            } else if grid_shape[level] != cells.len() {
+                return Err(from_kind(ErrorKind::IncompatibleShape));
+            }
+            for cell in cells {
+                flatten_grid(cell, level + 1, grid_shape, blocks)?;
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Assemble an array from a grid of blocks.
+///
+/// The grid is written as nested lists in brackets, and the *k*-th level of
+/// nesting places the blocks along axis *k*: `block![[a, b], [c, d]]` puts
+/// `a` and `b` side by side along axis 1, on top of `c` and `d` along
+/// axis 0. A flat list `block![a, b]` joins the blocks along axis 0, and a
+/// grid nested three deep assembles a 3-D array, and so on. The blocks can be
+/// arrays or views of the same dimensionality, with at least as many axes as
+/// the grid has levels.
+///
+/// The shapes are checked when the array is assembled: the blocks in the
+/// same row must have the same number of rows, the blocks in the same column
+/// the same number of columns (and likewise for deeper grids), and all
+/// blocks must have the same lengths along any axes beyond the grid.
+///
+/// ***Panics*** if the grid is not rectangular or the shapes of the blocks are
+/// not consistent.
+///
+/// ```
+/// extern crate ndarray;
+///
+/// use ndarray::{arr2, block, Array2};
+///
+/// # fn main() {
+/// let a = Array2::<i32>::eye(2);
+/// let b = arr2(&[[5], [6]]);
+/// let c = arr2(&[[7, 8]]);
+/// let d = arr2(&[[9]]);
+/// assert_eq!(
+///     block![[a, b],
+///            [c, d]],
+///     arr2(&[[1, 0, 5],
+///            [0, 1, 6],
+///            [7, 8, 9]])
+/// );
+/// # }
+/// ```
+#[macro_export]
+macro_rules! block {
+    ($($grid:tt)+) => {
+        $crate::block_from_grid($crate::__block_grid!($($grid)+)).unwrap()
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __block_grid {
+    ($([$($cell:tt)+]),+ $(,)*) => {
+        $crate::BlockGrid::Grid(vec![$($crate::__block_grid!($($cell)+)),+])
+    };
+    ($($block:expr),+ $(,)*) => {
+        $crate::BlockGrid::Grid(vec![
+            $($crate::BlockGrid::Block($crate::ArrayView::from(&$block))),+
+        ])
+    };
+}
@@ -8,16 +8,22 @@
 
 #[macro_use]
 mod zipmacro;
+mod output;
+
+use std::mem::MaybeUninit;
 
 use crate::imp_prelude::*;
 use crate::IntoDimension;
 use crate::Layout;
 use crate::NdIndex;
+use crate::ShapeBuilder;
 
 use crate::indexes::{indices, Indices};
 use crate::layout::LayoutPriv;
 use crate::layout::{CORDER, FORDER};
 
+pub(crate) use self::output::Output;
+
 /// Return if the expression is a break value.
 macro_rules! fold_while {
     ($e:expr) => {
@@ -200,7 +206,7 @@ impl<T> Offset for *mut T {
     private_impl! {}
 }
 
-trait ZippableTuple: Sized {
+pub(crate) trait ZippableTuple: Sized {
     type Item;
     type Ptr: OffsetTuple<Args = Self::Stride> + Copy;
     type Dim: Dimension;
@@ -485,6 +491,12 @@ where
             parts: (array,),
         }
     }
+
+    /// Return the producer of the Zip.
+    #[cfg(feature = "rayon")]
+    pub(crate) fn into_producer(self) -> P {
+        self.parts.0
+    }
 }
 impl<P, D> Zip<(Indices<D>, P), D>
 where
@@ -562,7 +574,7 @@ impl<P, D> Zip<P, D>
 where
     D: Dimension,
 {
-    fn apply_core<F, Acc>(&mut self, acc: Acc, function: F) -> FoldWhile<Acc>
+    pub(crate) fn apply_core<F, Acc>(&mut self, acc: Acc, function: F) -> FoldWhile<Acc>
     where
         F: FnMut(Acc, P::Item) -> FoldWhile<Acc>,
         P: ZippableTuple<Dim = D>,
@@ -573,6 +585,39 @@ where
             self.apply_core_strided(acc, function)
         }
     }
+    /// Split the `Zip` evenly in two.
+    pub(crate) fn split_tuple(self) -> (Self, Self)
+    where
+        P: ZippableTuple<Dim = D>,
+    {
+        debug_assert_ne!(self.size(), 0, "Attempt to split empty zip");
+        debug_assert_ne!(self.size(), 1, "Attempt to split zip with 1 elem");
+        // Always split in a way that preserves layout (if any)
+        let axis = self.max_stride_axis();
+        let index = self.len_of(axis) / 2;
+        let (p1, p2) = self.parts.split_at(axis, index);
+        let (d1, d2) = self.dimension.split_at(axis, index);
+        (
+            Zip {
+                dimension: d1,
+                layout: self.layout,
+                parts: p1,
+            },
+            Zip {
+                dimension: d2,
+                layout: self.layout,
+                parts: p2,
+            },
+        )
+    }
+
+    /// Return an uninitialized array of the Zip's shape, in the memory layout
+    /// that best matches the inputs.
+    pub(crate) fn uninitialized_output<R>(&self) -> Output<R, D> {
+        let f_order = self.layout.is(FORDER) && !self.layout.is(CORDER);
+        Output::new(self.dimension.clone().set_f(f_order))
+    }
+
     fn apply_core_contiguous<F, Acc>(&mut self, mut acc: Acc, mut function: F) -> FoldWhile<Acc>
     where
         F: FnMut(Acc, P::Item) -> FoldWhile<Acc>,
@@ -637,7 +682,7 @@ impl<T> Offset for *mut T {
 }
 */
 
-trait OffsetTuple {
+pub(crate) trait OffsetTuple {
     type Args;
     unsafe fn stride_offset(self, stride: Self::Args, index: usize) -> Self;
 }
@@ -672,6 +717,8 @@ offset_impl! {
     [A B C D][ a b c d],
     [A B C D E][ a b c d e],
     [A B C D E F][ a b c d e f],
+    [A B C D E F G][ a b c d e f g],
+    [A B C D E F G H][ a b c d e f g h],
 }
 
 macro_rules! zipt_impl {
@@ -731,6 +778,8 @@ zipt_impl! {
     [A B C D][ a b c d],
     [A B C D E][ a b c d e],
     [A B C D E F][ a b c d e f],
+    [A B C D E F G][ a b c d e f g],
+    [A B C D E F G H][ a b c d e f g h],
 }
 
 macro_rules! map_impl {
@@ -829,6 +878,101 @@ macro_rules! map_impl {
                 }).into_inner()
             }
 
+            /// Map a function over all elements of the input arrays, visiting
+            /// elements in lock step, and collect the results into a new array.
+            ///
+            /// The new array is allocated without initializing its elements
+            /// first, in the memory layout (c- or f-order) that best matches
+            /// the inputs.
+            ///
+            /// ```
+            /// use ndarray::{array, Zip};
+            ///
+            /// let a = array![[1., 2.], [3., 4.]];
+            /// let b = array![[4., 3.], [2., 1.]];
+            ///
+            /// let c = Zip::from(&a).and(&b).map_collect(|&a, &b| a * b + 1.);
+            /// assert_eq!(c, array![[5., 7.], [7., 5.]]);
+            /// ```
+            pub fn map_collect<R, F>(self, mut f: F) -> Array<R, D>
+                where F: FnMut($($p::Item),*) -> R
+            {
+                let mut output = self.uninitialized_output();
+                self.and_output(output.view_mut()).apply_core((), move |(), args| {
+                    let ($($p,)* out,) = args;
+                    *out = MaybeUninit::new(f($($p),*));
+                    FoldWhile::Continue(())
+                });
+                unsafe { output.assume_init() }
+            }
+
+            /// Map a function that returns a pair of values over all elements
+            /// of the input arrays, visiting elements in lock step, and
+            /// collect the results into two new arrays.
+            ///
+            /// The new arrays are allocated like the one of `map_collect`.
+            ///
+            /// ```
+            /// use ndarray::{array, Zip};
+            ///
+            /// let a = array![7, 8, 9];
+            /// let b = array![2, 3, 4];
+            ///
+            /// let (q, r) = Zip::from(&a).and(&b).map_collect2(|&a, &b| (a / b, a % b));
+            /// assert_eq!(q, array![3, 2, 2]);
+            /// assert_eq!(r, array![1, 2, 1]);
+            /// ```
+            pub fn map_collect2<R1, R2, F>(self, mut f: F) -> (Array<R1, D>, Array<R2, D>)
+                where F: FnMut($($p::Item),*) -> (R1, R2)
+            {
+                let mut output1 = self.uninitialized_output();
+                let mut output2 = self.uninitialized_output();
+                self.and_output2(output1.view_mut(), output2.view_mut())
+                    .apply_core((), move |(), args| {
+                        let ($($p,)* out1, out2,) = args;
+                        let (r1, r2) = f($($p),*);
+                        *out1 = MaybeUninit::new(r1);
+                        *out2 = MaybeUninit::new(r2);
+                        FoldWhile::Continue(())
+                    });
+                unsafe { (output1.assume_init(), output2.assume_init()) }
+            }
+
+            /// Include the uninitialized `output`, created by
+            /// `uninitialized_output`, in the Zip.
+            pub(crate) fn and_output<'o, R>(self, output: ArrayViewMut<'o, MaybeUninit<R>, D>)
+                -> Zip<($($p,)* ArrayViewMut<'o, MaybeUninit<R>, D>, ), D>
+            {
+                debug_assert!(output.equal_dim(&self.dimension));
+                let part_layout = output.layout();
+                let ($($p,)*) = self.parts;
+                Zip {
+                    parts: ($($p,)* output, ),
+                    layout: self.layout.and(part_layout),
+                    dimension: self.dimension,
+                }
+            }
+
+            /// Include the uninitialized outputs `output1` and `output2`,
+            /// created by `uninitialized_output`, in the Zip.
+            pub(crate) fn and_output2<'o, R1, R2>(
+                self,
+                output1: ArrayViewMut<'o, MaybeUninit<R1>, D>,
+                output2: ArrayViewMut<'o, MaybeUninit<R2>, D>,
+            ) -> Zip<($($p,)* ArrayViewMut<'o, MaybeUninit<R1>, D>,
+                      ArrayViewMut<'o, MaybeUninit<R2>, D>, ), D>
+            {
+                debug_assert!(output1.equal_dim(&self.dimension));
+                debug_assert!(output2.equal_dim(&self.dimension));
+                let part_layout = output1.layout().and(output2.layout());
+                let ($($p,)*) = self.parts;
+                Zip {
+                    parts: ($($p,)* output1, output2, ),
+                    layout: self.layout.and(part_layout),
+                    dimension: self.dimension,
+                }
+            }
+
             expand_if!(@bool [$notlast]
 
             /// Include the producer `p` in the Zip.
@@ -873,23 +1017,7 @@ macro_rules! map_impl {
             ///
             /// It will be split in the way that best preserves element locality.
             pub fn split(self) -> (Self, Self) {
-                debug_assert_ne!(self.size(), 0, "Attempt to split empty zip");
-                debug_assert_ne!(self.size(), 1, "Attempt to split zip with 1 elem");
-                // Always split in a way that preserves layout (if any)
-                let axis = self.max_stride_axis();
-                let index = self.len_of(axis) / 2;
-                let (p1, p2) = self.parts.split_at(axis, index);
-                let (d1, d2) = self.dimension.split_at(axis, index);
-                (Zip {
-                    dimension: d1,
-                    layout: self.layout,
-                    parts: p1,
-                },
-                Zip {
-                    dimension: d2,
-                    layout: self.layout,
-                    parts: p2,
-                })
+                self.split_tuple()
             }
         }
         )+
@@ -0,0 +1,54 @@
+// Copyright 2019 bluss and ndarray developers.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+use std::mem::{ManuallyDrop, MaybeUninit};
+
+use crate::imp_prelude::*;
+use crate::Shape;
+
+/// An uninitialized array that the results of `Zip::map_collect` are
+/// written to.
+///
+/// If it is dropped before all its elements are written, the elements that
+/// were written are leaked, not dropped.
+pub(crate) struct Output<R, D> {
+    elements: Vec<MaybeUninit<R>>,
+    shape: Shape<D>,
+}
+
+impl<R, D> Output<R, D>
+where
+    D: Dimension,
+{
+    pub(crate) fn new(shape: Shape<D>) -> Self {
+        let len = shape.dim.size();
+        let mut elements = Vec::with_capacity(len);
+        // Safe because `MaybeUninit` needs no initialization
+        unsafe {
+            elements.set_len(len);
+        }
+        Output { elements, shape }
+    }
+
+    /// Return a mutable view of the elements, with the shape of the output.
+    pub(crate) fn view_mut(&mut self) -> ArrayViewMut<'_, MaybeUninit<R>, D> {
+        unsafe { ArrayViewMut::from_shape_ptr(self.shape.clone(), self.elements.as_mut_ptr()) }
+    }
+
+    /// Convert into an array of the written elements.
+    ///
+    /// **Safety:** every element must have been written.
+    pub(crate) unsafe fn assume_init(self) -> Array<R, D> {
+        let mut elements = ManuallyDrop::new(self.elements);
+        let elements = Vec::from_raw_parts(
+            elements.as_mut_ptr() as *mut R,
+            elements.len(),
+            elements.capacity(),
+        );
+        Array::from_shape_vec_unchecked(self.shape, elements)
+    }
+}
@@ -302,3 +302,65 @@ fn test_zip_all_empty_array() {
     assert_eq!(true, Zip::from(&a).and(&b).all(|&_x, &_y| true));
     assert_eq!(true, Zip::from(&a).and(&b).all(|&_x, &_y| false));
 }
+
+#[test]
+fn test_zip_map_collect() {
+    let a = Array::from_shape_fn((5, 7), |(i, j)| (i * 10 + j) as f32);
+    let b = Array::from_shape_fn((5, 7), |(i, j)| (i + j) as f32);
+    let c = Zip::from(&a).and(&b).map_collect(|&a, &b| a - b);
+    assert_eq!(c, &a - &b);
+    assert!(c.is_standard_layout());
+
+    // f-order inputs give an f-order output
+    let at = a.t();
+    let bt = b.t();
+    let ct = Zip::from(at).and(bt).map_collect(|&a, &b| a - b);
+    assert_eq!(ct, c.t());
+    assert!(ct.t().is_standard_layout());
+
+    // mixed layouts give a c-order output
+    let bf = Array::from_shape_fn((5, 7).f(), |(i, j)| (i + j) as f32);
+    let cm = Zip::from(&a).and(&bf).map_collect(|&a, &b| a - b);
+    assert_eq!(cm, c);
+    assert!(cm.is_standard_layout());
+
+    let s = a.slice(s![..;2, ..;-3]);
+    assert_eq!(Zip::from(s).map_collect(|&x| x * 2.), &s * 2.);
+}
+
+#[test]
+fn test_zip_map_collect_all_arities() {
+    let a = Array::from_iter(0..12).into_shape((3, 4)).unwrap();
+    let c = Zip::from(&a)
+        .and(&a)
+        .and(&a)
+        .and(&a)
+        .and(&a)
+        .and(&a)
+        .map_collect(|&a, &b, &c, &d, &e, &f| a + b + c + d + e + f);
+    assert_eq!(c, &a * 6);
+    let c = Zip::indexed(&a).map_collect(|(i, j), &x| i * 4 + j == x);
+    assert!(c.iter().all(|&x| x));
+}
+
+#[test]
+fn test_zip_map_collect_non_copy() {
+    let a = Array::from_iter(0..6).into_shape((2, 3)).unwrap();
+    let c = Zip::from(&a).map_collect(|x| x.to_string());
+    assert_eq!(c[[1, 2]], "5");
+    let e = Zip::from(&a.slice(s![.., ..0])).map_collect(|x| x.to_string());
+    assert_eq!(e.shape(), &[2, 0]);
+}
+
+#[test]
+fn test_zip_map_collect2() {
+    let a = Array::from_shape_fn((4, 6).f(), |(i, j)| (i * 7 + j) as i32);
+    let b = Array::from_elem((4, 6).f(), 4);
+    let (q, r) = Zip::from(&a)
+        .and(&b)
+        .map_collect2(|&a, &b| (a / b, (a % b).to_string()));
+    assert_eq!(q, &a / 4);
+    assert_eq!(r, a.mapv(|a| (a % 4).to_string()));
+    assert!(q.t().is_standard_layout());
+    assert!(r.t().is_standard_layout());
+}
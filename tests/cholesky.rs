@@ -0,0 +1,150 @@
+#![cfg(feature = "approx")]
+
+use ndarray::linalg::{Diag, LinalgError, Transpose, UPLO};
+use ndarray::prelude::*;
+use num_complex::Complex;
+
+use approx::assert_abs_diff_eq;
+use std::f64;
+
+mod common;
+
+/// A reproducible symmetric positive definite test matrix.
+fn spd_matrix(n: usize) -> Array2<f64> {
+    let b = common::test_matrix(n, n);
+    let mut a = b.dot(&b.t());
+    for i in 0..n {
+        a[(i, i)] += 1.;
+    }
+    a
+}
+
+#[test]
+fn cholesky_reconstruct() {
+    for &n in &[1, 2, 5, 31, 32, 33, 70] {
+        let a = spd_matrix(n);
+        let l = a.cholesky(UPLO::Lower).unwrap();
+        for ((i, j), &x) in l.indexed_iter() {
+            if j > i {
+                assert_eq!(x, 0.);
+            }
+        }
+        assert_abs_diff_eq!(l.dot(&l.t()), a, epsilon = 1e-10);
+        let u = a.cholesky(UPLO::Upper).unwrap();
+        assert_abs_diff_eq!(u, l.t(), epsilon = 1e-12);
+    }
+}
+
+#[test]
+fn cholesky_reads_one_triangle() {
+    let a = spd_matrix(6);
+    let mut lower = a.clone();
+    let mut upper = a.clone();
+    for i in 0..6 {
+        for j in 0..6 {
+            if j > i {
+                lower[(i, j)] = f64::NAN;
+            } else if j < i {
+                upper[(i, j)] = f64::NAN;
+            }
+        }
+    }
+    let l = a.cholesky(UPLO::Lower).unwrap();
+    assert_abs_diff_eq!(lower.cholesky(UPLO::Lower).unwrap(), l, epsilon = 1e-12);
+    assert_abs_diff_eq!(upper.cholesky(UPLO::Upper).unwrap(), l.t(), epsilon = 1e-12);
+}
+
+#[test]
+fn cholesky_not_positive_definite() {
+    let mut a = spd_matrix(40);
+    a[(35, 35)] = -1e3;
+    assert_eq!(
+        a.cholesky(UPLO::Lower),
+        Err(LinalgError::NotPositiveDefinite { minor: 36 })
+    );
+    assert_eq!(
+        a.solve_cholesky(UPLO::Upper, &Array1::ones(40)),
+        Err(LinalgError::NotPositiveDefinite { minor: 36 })
+    );
+    let b = Array2::<f64>::zeros((2, 3));
+    assert_eq!(
+        b.cholesky(UPLO::Lower),
+        Err(LinalgError::NotSquare { rows: 2, cols: 3 })
+    );
+}
+
+#[test]
+fn cholesky_solve_and_inv() {
+    let n = 45;
+    let a = spd_matrix(n);
+    let x = Array::linspace(-1., 1., n);
+    let b = a.dot(&x);
+    for &uplo in &[UPLO::Lower, UPLO::Upper] {
+        assert_abs_diff_eq!(a.solve_cholesky(uplo, &b).unwrap(), x, epsilon = 1e-9);
+        let inv = a.inv_cholesky(uplo).unwrap();
+        assert_abs_diff_eq!(inv.dot(&a), Array2::eye(n), epsilon = 1e-9);
+    }
+    let xs = Array::from_shape_fn((n, 3), |(i, j)| (i + j) as f64);
+    let bs = a.dot(&xs);
+    assert_abs_diff_eq!(
+        a.solve_cholesky(UPLO::Lower, &bs).unwrap(),
+        xs,
+        epsilon = 1e-8
+    );
+}
+
+#[test]
+fn cholesky_complex() {
+    let c = Complex::new;
+    let a = arr2(&[[c(4., 0.), c(1., 2.)], [c(1., -2.), c(6., 0.)]]);
+    let l = a.cholesky(UPLO::Lower).unwrap();
+    let lh = l.t().mapv(|x| x.conj());
+    for (x, y) in l.dot(&lh).iter().zip(&a) {
+        assert!((x - y).norm() < 1e-12);
+    }
+    let x = arr1(&[c(1., -1.), c(0.5, 2.)]);
+    let y = a.solve_cholesky(UPLO::Upper, &a.dot(&x)).unwrap();
+    for (y, x) in y.iter().zip(&x) {
+        assert!((y - x).norm() < 1e-12);
+    }
+}
+
+#[test]
+fn solve_triangular_variants() {
+    let a = arr2(&[[2., 9., 9.], [1., 3., 9.], [4., 5., 4.]]);
+    let b = arr1(&[1., 2., 3.]);
+    let lower = arr2(&[[2., 0., 0.], [1., 3., 0.], [4., 5., 4.]]);
+    let upper = arr2(&[[2., 9., 9.], [0., 3., 9.], [0., 0., 4.]]);
+    let cases = [
+        (UPLO::Lower, Transpose::NoTrans, lower.clone()),
+        (UPLO::Lower, Transpose::Trans, lower.t().to_owned()),
+        (UPLO::Upper, Transpose::NoTrans, upper.clone()),
+        (UPLO::Upper, Transpose::ConjTrans, upper.t().to_owned()),
+    ];
+    for &(uplo, trans, ref t) in &cases {
+        let x = a.solve_triangular(uplo, trans, Diag::NonUnit, &b).unwrap();
+        assert_abs_diff_eq!(t.dot(&x), b, epsilon = 1e-12);
+    }
+
+    let mut unit = lower.clone();
+    unit.diag_mut().fill(1.);
+    let x = a
+        .solve_triangular(UPLO::Lower, Transpose::NoTrans, Diag::Unit, &b)
+        .unwrap();
+    assert_abs_diff_eq!(unit.dot(&x), b, epsilon = 1e-12);
+
+    let mut singular = a.clone();
+    singular[(1, 1)] = 0.;
+    assert_eq!(
+        singular.solve_triangular(UPLO::Upper, Transpose::NoTrans, Diag::NonUnit, &b),
+        Err(LinalgError::Singular)
+    );
+    assert!(a
+        .solve_triangular(
+            UPLO::Upper,
+            Transpose::NoTrans,
+            Diag::NonUnit,
+            &arr1(&[1., 2.])
+        )
+        .is_err());
+}
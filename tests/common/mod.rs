@@ -0,0 +1,9 @@
+use ndarray::prelude::*;
+
+/// A reproducible `m × n` test matrix with entries in `[-0.5, 0.5)`.
+pub fn test_matrix(m: usize, n: usize) -> Array2<f64> {
+    Array::from_shape_fn((m, n), |(i, j)| {
+        let x = ((i * 37 + j * 11) % 23) as f64;
+        x / 23. - 0.5
+    })
+}
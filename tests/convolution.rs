@@ -0,0 +1,181 @@
+use ndarray::convolution::Mode;
+use ndarray::prelude::*;
+use ndarray::IxDyn;
+
+/// The correlation of `a` with `kernel` in `mode`, computed directly from
+/// the definition.
+fn reference<D: Dimension>(a: &Array<i64, D>, kernel: &Array<i64, D>, mode: Mode) -> Array<i64, D> {
+    let a = a.view().into_dyn();
+    let kernel = kernel.view().into_dyn();
+    let mut offsets = vec![0; a.ndim()];
+    let mut out_dim = a.raw_dim();
+    for axis in 0..a.ndim() {
+        let (n, k) = (a.shape()[axis] as isize, kernel.shape()[axis] as isize);
+        let (off, len) = match mode {
+            Mode::Full => (-(k - 1), n + k - 1),
+            Mode::Same => (-(k / 2), n),
+            Mode::Valid => (0, (n - k + 1).max(0)),
+        };
+        offsets[axis] = off;
+        out_dim[axis] = len as usize;
+    }
+    let mut out = Array::zeros(out_dim);
+    for (index, o) in out.indexed_iter_mut() {
+        for (kindex, &w) in kernel.indexed_iter() {
+            let mut pos = a.raw_dim();
+            let mut inside = true;
+            for axis in 0..a.ndim() {
+                let p = index[axis] as isize + offsets[axis] + kindex[axis] as isize;
+                if p < 0 || p >= a.shape()[axis] as isize {
+                    inside = false;
+                }
+                pos[axis] = p as usize;
+            }
+            if inside {
+                *o += a[pos] * w;
+            }
+        }
+    }
+    out.into_dimensionality().unwrap()
+}
+
+fn flip<D: Dimension>(kernel: &Array<i64, D>) -> Array<i64, D> {
+    let mut k = kernel.view();
+    for axis in 0..k.ndim() {
+        k.invert_axis(Axis(axis));
+    }
+    k.to_owned()
+}
+
+fn check<D: Dimension>(a: &Array<i64, D>, kernel: &Array<i64, D>) {
+    for &mode in &[Mode::Full, Mode::Same, Mode::Valid] {
+        let expected = reference(a, kernel, mode);
+        assert_eq!(a.correlate(kernel, mode), expected, "{:?}", mode);
+        assert_eq!(a.convolve(&flip(kernel), mode), expected, "{:?}", mode);
+    }
+}
+
+fn sample<D: Dimension>(shape: D, seed: i64) -> Array<i64, D> {
+    let n = shape.size();
+    let mut x = seed;
+    let v = (0..n)
+        .map(|_| {
+            x = (x * 37 + 11) % 19;
+            x - 9
+        })
+        .collect();
+    Array::from_shape_vec(shape, v).unwrap()
+}
+
+#[test]
+fn one_dimensional() {
+    let a = arr1(&[1, 2, 3, 4]);
+    let k = arr1(&[1, 0, -1]);
+    assert_eq!(a.convolve(&k, Mode::Full), arr1(&[1, 2, 2, 2, -3, -4]));
+    assert_eq!(a.convolve(&k, Mode::Same), arr1(&[2, 2, 2, -3]));
+    assert_eq!(a.convolve(&k, Mode::Valid), arr1(&[2, 2]));
+    assert_eq!(a.correlate(&k, Mode::Valid), arr1(&[-2, -2]));
+    for n in 1..9 {
+        for m in 1..6 {
+            check(&sample(Ix1(n), n as i64), &sample(Ix1(m), m as i64 + 3));
+        }
+    }
+    // a kernel longer than the input
+    assert_eq!(
+        arr1(&[1, 2]).convolve(&arr1(&[1, 1, 1]), Mode::Valid).len(),
+        0
+    );
+    assert_eq!(
+        arr1(&[1, 2]).convolve(&arr1(&[1, 1, 1]), Mode::Same),
+        arr1(&[3, 3])
+    );
+}
+
+#[test]
+fn two_and_three_dimensional() {
+    for &(shape, kshape) in &[((5, 7), (3, 3)), ((4, 6), (2, 5)), ((3, 3), (4, 1))] {
+        check(
+            &sample(Ix2(shape.0, shape.1), 1),
+            &sample(Ix2(kshape.0, kshape.1), 2),
+        );
+    }
+    check(&sample(Ix3(4, 5, 3), 4), &sample(Ix3(3, 2, 2), 5));
+    check(
+        &sample(IxDyn(&[3, 4, 2, 3]), 6),
+        &sample(IxDyn(&[2, 2, 1, 3]), 7),
+    );
+
+    // non-contiguous input and kernel
+    let a = sample(Ix2(8, 9), 8);
+    let k = sample(Ix2(6, 3), 9);
+    let av = a.slice(s![..;2, ..;-1]).to_owned();
+    let kv = k.slice(s![..;2, ..]);
+    assert_eq!(
+        a.slice(s![..;2, ..;-1]).correlate(&kv, Mode::Same),
+        reference(&av, &kv.to_owned(), Mode::Same)
+    );
+}
+
+#[test]
+fn large_kernels() {
+    // kernels with many elements use im2col and a matrix product
+    check(&sample(Ix2(20, 23), 10), &sample(Ix2(9, 9), 11));
+    check(&sample(Ix1(300), 12), &sample(Ix1(70), 13));
+    let a = sample(Ix2(30, 40), 14).mapv(|x| x as f64);
+    let k = sample(Ix2(11, 7), 15).mapv(|x| x as f64);
+    let ai = a.mapv(|x| x as i64);
+    let ki = k.mapv(|x| x as i64);
+    for &mode in &[Mode::Full, Mode::Same, Mode::Valid] {
+        assert_eq!(
+            a.convolve(&k, mode),
+            ai.convolve(&ki, mode).mapv(|x| x as f64)
+        );
+    }
+}
+
+#[test]
+fn axis_and_separable() {
+    let a = sample(Ix3(5, 6, 4), 16);
+    let k = arr1(&[1, -2, 3]);
+    for axis in 0..3 {
+        let mut shape = [1; 3];
+        shape[axis] = 3;
+        let full_kernel = k.clone().into_shape(shape).unwrap();
+        for &mode in &[Mode::Full, Mode::Same, Mode::Valid] {
+            assert_eq!(
+                a.convolve_axis(Axis(axis), &k, mode),
+                a.convolve(&full_kernel, mode)
+            );
+            assert_eq!(
+                a.correlate_axis(Axis(axis), &k, mode),
+                a.correlate(&full_kernel, mode)
+            );
+        }
+    }
+
+    let kernels = [arr1(&[1, 2, 1]), arr1(&[1, 0, -1]), arr1(&[2, 1])];
+    let mut full_kernel = Array3::zeros((3, 3, 2));
+    for ((i, j, l), x) in full_kernel.indexed_iter_mut() {
+        *x = kernels[0][i] * kernels[1][j] * kernels[2][l];
+    }
+    let views = [kernels[0].view(), kernels[1].view(), kernels[2].view()];
+    for &mode in &[Mode::Full, Mode::Same, Mode::Valid] {
+        assert_eq!(
+            a.convolve_separable(&views, mode),
+            a.convolve(&full_kernel, mode)
+        );
+    }
+}
+
+#[test]
+#[should_panic]
+fn empty_kernel() {
+    arr1(&[1., 2.]).convolve(&Array1::zeros(0), Mode::Full);
+}
+
+#[test]
+#[should_panic]
+fn kernel_axes_mismatch() {
+    let a = Array::zeros(IxDyn(&[3, 3]));
+    a.convolve(&Array::<f64, _>::zeros(IxDyn(&[2])), Mode::Full);
+}
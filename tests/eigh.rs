@@ -0,0 +1,83 @@
+#![cfg(feature = "approx")]
+
+use ndarray::linalg::{LinalgError, UPLO};
+use ndarray::prelude::*;
+use num_complex::Complex;
+
+use approx::assert_abs_diff_eq;
+use std::f64;
+
+mod common;
+
+/// A reproducible symmetric test matrix.
+fn sym_matrix(n: usize) -> Array2<f64> {
+    let b = common::test_matrix(n, n);
+    &b + &b.t()
+}
+
+#[test]
+fn eigh_reconstruct() {
+    for &n in &[0, 1, 2, 3, 10, 40] {
+        let a = sym_matrix(n);
+        let (w, v) = a.eigh(UPLO::Lower).unwrap();
+        assert_eq!(w.len(), n);
+        for i in 1..n {
+            assert!(w[i - 1] <= w[i]);
+        }
+        assert_abs_diff_eq!(v.t().dot(&v), Array2::eye(n), epsilon = 1e-12);
+        assert_abs_diff_eq!(a.dot(&v), &v * &w, epsilon = 1e-10);
+    }
+}
+
+#[test]
+fn eigh_known_values() {
+    let a = arr2(&[[2., -1., 0.], [-1., 2., -1.], [0., -1., 2.]]);
+    let (w, _) = a.eigh(UPLO::Upper).unwrap();
+    let r = 2f64.sqrt();
+    assert_abs_diff_eq!(w, arr1(&[2. - r, 2., 2. + r]), epsilon = 1e-12);
+
+    // repeated eigenvalues
+    let (w, v) = Array2::<f64>::eye(4).eigh(UPLO::Lower).unwrap();
+    assert_abs_diff_eq!(w, Array1::ones(4), epsilon = 1e-15);
+    assert_abs_diff_eq!(v, Array2::eye(4), epsilon = 1e-15);
+}
+
+#[test]
+fn eigh_reads_one_triangle() {
+    let a = sym_matrix(7);
+    let mut lower = a.clone();
+    for i in 0..7 {
+        for j in i + 1..7 {
+            lower[(i, j)] = f64::NAN;
+        }
+    }
+    let (w, _) = a.eigh(UPLO::Lower).unwrap();
+    let (wl, _) = lower.eigh(UPLO::Lower).unwrap();
+    assert_abs_diff_eq!(w, wl, epsilon = 1e-12);
+    let (wu, _) = lower.t().eigh(UPLO::Upper).unwrap();
+    assert_abs_diff_eq!(w, wu, epsilon = 1e-12);
+}
+
+#[test]
+fn eigh_hermitian() {
+    let n = 12;
+    let b = common::test_matrix(n, n);
+    let a = Array::from_shape_fn((n, n), |(i, j)| Complex::new(b[(i, j)], b[(n - 1 - i, j)]));
+    let a = &a + &a.t().mapv(|x| x.conj());
+    let (w, v) = a.eigh(UPLO::Lower).unwrap();
+    let vh = v.t().mapv(|x| x.conj());
+    let err = vh.dot(&v) - Array2::<Complex<f64>>::eye(n);
+    assert!(err.iter().all(|x| x.norm() < 1e-12));
+    let vw = &v * &w.mapv(|x| Complex::new(x, 0.));
+    let err = a.dot(&v) - vw;
+    assert!(err.iter().all(|x| x.norm() < 1e-10));
+}
+
+#[test]
+fn eigh_errors() {
+    let a = Array2::<f64>::zeros((2, 3));
+    assert_eq!(
+        a.eigh(UPLO::Lower),
+        Err(LinalgError::NotSquare { rows: 2, cols: 3 })
+    );
+}
@@ -0,0 +1,157 @@
+use ndarray::linalg::{einsum, tensordot};
+use ndarray::prelude::*;
+use ndarray::ErrorKind;
+
+fn range_array<D: Dimension, Sh: ShapeBuilder<Dim = D>>(shape: Sh) -> Array<i64, D> {
+    let mut a = Array::zeros(shape);
+    for (i, x) in a.iter_mut().enumerate() {
+        *x = (i as i64 * 7) % 11 - 5;
+    }
+    a
+}
+
+#[test]
+fn tensordot_contracts_axes() {
+    let a = range_array((3, 4, 5));
+    let b = range_array((5, 2, 3));
+    let c = tensordot(&a, &b, &[Axis(2), Axis(0)], &[Axis(0), Axis(2)]).unwrap();
+    assert_eq!(c.shape(), &[4, 2]);
+    for j in 0..4 {
+        for l in 0..2 {
+            let mut sum = 0;
+            for i in 0..3 {
+                for k in 0..5 {
+                    sum += a[[i, j, k]] * b[[k, l, i]];
+                }
+            }
+            assert_eq!(c[[j, l]], sum);
+        }
+    }
+
+    let m = range_array((4, 6));
+    let v = m.slice(s![..;2, ..;-1]);
+    let c = tensordot(&v, &m, &[Axis(1)], &[Axis(1)]).unwrap();
+    assert_eq!(c, v.dot(&m.t()).into_dyn());
+    let outer = tensordot(&m, &m.row(0), &[], &[]).unwrap();
+    assert_eq!(outer.shape(), &[4, 6, 6]);
+    assert_eq!(outer[[2, 3, 4]], m[[2, 3]] * m[[0, 4]]);
+}
+
+#[test]
+fn tensordot_errors() {
+    let a = range_array((3, 4));
+    let b = range_array((4, 5));
+    let kind =
+        |axes_a: &[Axis], axes_b: &[Axis]| tensordot(&a, &b, axes_a, axes_b).unwrap_err().kind();
+    assert_eq!(kind(&[Axis(1)], &[]), ErrorKind::IncompatibleShape);
+    assert_eq!(kind(&[Axis(0)], &[Axis(0)]), ErrorKind::IncompatibleShape);
+    assert_eq!(kind(&[Axis(2)], &[Axis(0)]), ErrorKind::OutOfBounds);
+    assert_eq!(
+        kind(&[Axis(1), Axis(1)], &[Axis(0), Axis(1)]),
+        ErrorKind::IncompatibleShape
+    );
+}
+
+#[test]
+fn einsum_products() {
+    let a = range_array((3, 4));
+    let b = range_array((4, 5));
+    let c = range_array((5, 2));
+    assert_eq!(
+        einsum("ij,jk->ik", &[&a, &b]).unwrap(),
+        a.dot(&b).into_dyn()
+    );
+    assert_eq!(einsum("ij,jk", &[&a, &b]).unwrap(), a.dot(&b).into_dyn());
+    assert_eq!(
+        einsum("ij,jk->ki", &[&a, &b]).unwrap(),
+        a.dot(&b).t().into_dyn()
+    );
+    assert_eq!(
+        einsum("ij,jk,kl->il", &[&a, &b, &c]).unwrap(),
+        a.dot(&b).dot(&c).into_dyn()
+    );
+    assert_eq!(
+        einsum(" ij , ij -> ij ", &[&a, &a]).unwrap(),
+        (&a * &a).into_dyn()
+    );
+    assert_eq!(einsum("ij,ij", &[&a, &a]).unwrap()[[]], (&a * &a).sum());
+    let v = a.row(1);
+    let w = b.column(2);
+    assert_eq!(
+        einsum("i,j->ij", &[&v, &w]).unwrap(),
+        Array::from_shape_fn((4, 4), |(i, j)| v[i] * w[j]).into_dyn()
+    );
+}
+
+#[test]
+fn einsum_batched() {
+    let a = range_array((6, 3, 4, 5));
+    let b = range_array((6, 5, 2));
+    let c = einsum(
+        "bmij,bjk->bmik",
+        &[&a.view().into_dyn(), &b.view().into_dyn()],
+    )
+    .unwrap();
+    assert_eq!(c.shape(), &[6, 3, 4, 2]);
+    for i in 0..6 {
+        for m in 0..3 {
+            let ai: ArrayView2<_> = a.slice(s![i, m, .., ..]);
+            let bi: ArrayView2<_> = b.slice(s![i, .., ..]);
+            assert_eq!(c.slice(s![i, m, .., ..]), ai.dot(&bi));
+        }
+    }
+    // batch axes in a different position in each operand
+    let (a, b) = (range_array((3, 2, 4)), range_array((4, 5, 3)));
+    let c = einsum("bij,jkb->kib", &[&a, &b]).unwrap();
+    assert_eq!(c.shape(), &[5, 2, 3]);
+    for i in 0..3 {
+        let ab = a.index_axis(Axis(0), i).dot(&b.index_axis(Axis(2), i));
+        assert_eq!(c.index_axis(Axis(2), i), ab.t().into_dyn());
+    }
+}
+
+#[test]
+fn einsum_traces_and_diagonals() {
+    let a = range_array((4, 4));
+    assert_eq!(einsum("ii", &[&a]).unwrap()[[]], a.diag().sum());
+    assert_eq!(einsum("ii->i", &[&a]).unwrap(), a.diag().into_dyn());
+    assert_eq!(einsum("ji", &[&a]).unwrap(), a.t().into_dyn());
+    assert_eq!(einsum("ij->", &[&a]).unwrap()[[]], a.sum());
+    assert_eq!(
+        einsum("ij->j", &[&a]).unwrap(),
+        a.sum_axis(Axis(0)).into_dyn()
+    );
+
+    let v = a.slice(s![..;-1, ..]);
+    assert_eq!(
+        einsum("ii->i", &[&v]).unwrap(),
+        arr1(&[v[(0, 0)], v[(1, 1)], v[(2, 2)], v[(3, 3)]]).into_dyn()
+    );
+
+    let t = range_array((3, 2, 3)).into_dyn();
+    let d = einsum("iji->j", &[&t]).unwrap();
+    for j in 0..2 {
+        assert_eq!(d[[j]], (0..3).map(|i| t[[i, j, i]]).sum::<i64>());
+    }
+    // a diagonal contracted with another operand
+    let b = range_array((4, 3));
+    assert_eq!(
+        einsum("ii,ik->k", &[&a, &b]).unwrap(),
+        a.diag().dot(&b).into_dyn()
+    );
+}
+
+#[test]
+fn einsum_errors() {
+    let a = range_array((3, 4));
+    let b = range_array((3, 4));
+    let kind = |s: &str| einsum(s, &[&a, &b]).unwrap_err().kind();
+    assert_eq!(kind("ij,jk->ik"), ErrorKind::IncompatibleShape);
+    assert_eq!(kind("ij->ij"), ErrorKind::IncompatibleShape);
+    assert_eq!(kind("ijk,ij"), ErrorKind::IncompatibleShape);
+    assert_eq!(kind("ij,ij->ii"), ErrorKind::IncompatibleShape);
+    assert_eq!(kind("ij,ij->z"), ErrorKind::IncompatibleShape);
+    assert_eq!(kind("i1,ij"), ErrorKind::IncompatibleShape);
+    assert_eq!(kind("...,ij"), ErrorKind::IncompatibleShape);
+    assert!(einsum("ii", &[&a]).is_err());
+}
@@ -0,0 +1,201 @@
+use ndarray::fft::{
+    blackman, fft, fft_inplace, fftfreq, fftn, fftshift, fftshift_axis, hamming, hann, ifft, ifftn,
+    ifftshift, irfft, rfft, rfftfreq, FftPlan,
+};
+use ndarray::prelude::*;
+use num_complex::Complex;
+use std::f64::consts::PI;
+
+type C64 = Complex<f64>;
+
+fn dft(x: &ArrayView1<'_, C64>, inverse: bool) -> Array1<C64> {
+    let n = x.len();
+    let sign = if inverse { 1. } else { -1. };
+    Array::from_shape_fn(n, |k| {
+        let mut sum = C64::new(0., 0.);
+        for (j, &xj) in x.iter().enumerate() {
+            let angle = sign * 2. * PI * ((j * k) % n) as f64 / n as f64;
+            sum += xj * C64::new(angle.cos(), angle.sin());
+        }
+        if inverse {
+            sum / n as f64
+        } else {
+            sum
+        }
+    })
+}
+
+fn signal<D: Dimension>(shape: D) -> Array<C64, D> {
+    let mut t = 0f64;
+    Array::from_shape_fn(shape, |_| {
+        t += 1.;
+        C64::new((t * 0.37).sin() + (0.1 * t) % 1.3, (t * 1.71).cos())
+    })
+}
+
+fn max_diff<D: Dimension>(a: &Array<C64, D>, b: &Array<C64, D>) -> f64 {
+    assert_eq!(a.shape(), b.shape());
+    a.iter()
+        .zip(b)
+        .map(|(x, y)| (x - y).norm())
+        .fold(0., f64::max)
+}
+
+#[test]
+fn matches_naive_dft() {
+    // powers of two, smooth lengths, and lengths with large prime factors
+    let lengths = (0..40).chain(vec![64, 97, 120, 243, 255, 289, 360, 1000, 1024, 1031]);
+    for n in lengths {
+        let x = signal(Ix1(n));
+        let expected = dft(&x.view(), false);
+        let tol = 1e-12 * (n.max(1) as f64);
+        let y = fft(&x, Axis(0));
+        assert!(max_diff(&y, &expected) < tol, "n = {}", n);
+        assert!(max_diff(&ifft(&y, Axis(0)), &x) < 1e-14 * (n.max(1) as f64));
+        assert!(max_diff(&ifft(&x, Axis(0)), &dft(&x.view(), true)) < tol);
+    }
+}
+
+#[test]
+fn along_axes() {
+    let a = signal(Ix3(6, 7, 17));
+    for axis in 0..3 {
+        let mut expected = a.clone();
+        for mut lane in expected.lanes_mut(Axis(axis)) {
+            let t = dft(&lane.view(), false);
+            lane.assign(&t);
+        }
+        assert!(max_diff(&fft(&a, Axis(axis)), &expected) < 1e-12);
+    }
+
+    // non-contiguous lanes, transformed in place in a view
+    let mut b = signal(Ix2(10, 12));
+    let expected = {
+        let v = b.slice(s![..;-2, 1..]);
+        let mut e = v.to_owned();
+        for mut lane in e.lanes_mut(Axis(0)) {
+            let t = dft(&lane.view(), false);
+            lane.assign(&t);
+        }
+        e
+    };
+    fft_inplace(&mut b.slice_mut(s![..;-2, 1..]), Axis(0));
+    assert!(max_diff(&b.slice(s![..;-2, 1..]).to_owned(), &expected) < 1e-12);
+}
+
+#[test]
+fn fftn_round_trip() {
+    let a = signal(Ix3(4, 6, 5));
+    let y = fftn(&a);
+    let mut expected = a.clone();
+    for axis in 0..3 {
+        expected = fft(&expected, Axis(axis));
+    }
+    assert!(max_diff(&y, &expected) < 1e-12);
+    assert!(max_diff(&ifftn(&y), &a) < 1e-13);
+    // the transform of a delta is constant
+    let mut delta = Array::zeros(IxDyn(&[3, 4]));
+    delta[[0, 0]] = C64::new(1., 0.);
+    assert!(fftn(&delta)
+        .iter()
+        .all(|&c| (c - C64::new(1., 0.)).norm() < 1e-15));
+    // Parseval
+    let energy: f64 = a.iter().map(|c| c.norm_sqr()).sum();
+    let spectral: f64 = y.iter().map(|c| c.norm_sqr()).sum::<f64>() / a.len() as f64;
+    assert!((energy - spectral).abs() < 1e-12 * energy);
+}
+
+#[test]
+fn real_transforms() {
+    for n in 1..20 {
+        let x = Array::from_shape_fn((3, n), |(i, j)| ((i + 2 * j) as f64 * 0.7).sin());
+        let spectrum = rfft(&x, Axis(1));
+        assert_eq!(spectrum.dim(), (3, n / 2 + 1));
+        let full = fft(&x.mapv(|v| C64::new(v, 0.)), Axis(1));
+        assert!(max_diff(&spectrum, &full.slice(s![.., ..n / 2 + 1]).to_owned()) < 1e-13);
+        let back = irfft(&spectrum, n, Axis(1));
+        assert!((&back - &x).iter().all(|d| d.abs() < 1e-14), "n = {}", n);
+    }
+    let x = Array::from_shape_fn((9, 2), |(i, j)| (i * j) as f64);
+    let back = irfft(&rfft(&x, Axis(0)), 9, Axis(0));
+    assert!((&back - &x).iter().all(|d| d.abs() < 1e-13));
+}
+
+#[test]
+#[should_panic]
+fn irfft_wrong_length() {
+    irfft(&Array1::<C64>::zeros(4), 8, Axis(0));
+}
+
+#[test]
+fn single_precision() {
+    let x = Array::from_shape_fn(50, |i| Complex::new((i as f32).sin(), 0.5));
+    let y = fft(&x, Axis(0));
+    let back = ifft(&y, Axis(0));
+    assert!(x.iter().zip(&back).all(|(a, b)| (a - b).norm() < 1e-5));
+    let expected = dft(
+        &x.mapv(|c| C64::new(c.re as f64, c.im as f64)).view(),
+        false,
+    );
+    assert!(y
+        .iter()
+        .zip(&expected)
+        .all(|(a, b)| (C64::new(a.re as f64, a.im as f64) - b).norm() < 1e-4));
+}
+
+#[test]
+fn plans() {
+    let plan = FftPlan::<f64>::new(12);
+    assert_eq!(plan.len(), 12);
+    let a = signal(Ix2(12, 3));
+    let mut b = a.clone();
+    plan.forward(&mut b, Axis(0));
+    assert!(max_diff(&b, &fft(&a, Axis(0))) < 1e-15);
+    plan.inverse(&mut b, Axis(0));
+    assert!(max_diff(&b, &a) < 1e-14);
+}
+
+#[test]
+#[should_panic]
+fn plan_length_mismatch() {
+    let plan = FftPlan::<f64>::new(8);
+    plan.forward(&mut signal(Ix1(7)), Axis(0));
+}
+
+#[test]
+fn frequencies() {
+    assert_eq!(
+        fftfreq(6, 0.5),
+        arr1(&[0., 1. / 3., 2. / 3., -1., -2. / 3., -1. / 3.])
+    );
+    assert_eq!(rfftfreq(6, 0.5), arr1(&[0., 1. / 3., 2. / 3., 1.]));
+    assert_eq!(fftfreq::<f64>(0, 1.).len(), 0);
+    assert_eq!(fftfreq(1, 1.), arr1(&[0.]));
+
+    let a = Array::from_shape_fn((4, 5), |(i, j)| i * 10 + j);
+    let shifted = fftshift(&a);
+    assert_eq!(shifted.row(0), arr1(&[23, 24, 20, 21, 22]));
+    assert_eq!(shifted.column(0), arr1(&[23, 33, 3, 13]));
+    assert_eq!(ifftshift(&shifted), a);
+    assert_eq!(fftshift_axis(&a, Axis(1)).row(0), arr1(&[3, 4, 0, 1, 2]));
+    assert_eq!(fftshift(&Array1::<f64>::zeros(0)).len(), 0);
+}
+
+#[test]
+fn windows() {
+    let close = |a: Array1<f64>, b: &[f64]| {
+        assert_eq!(a.len(), b.len());
+        assert!(
+            a.iter().zip(b).all(|(x, y)| (x - y).abs() < 1e-15),
+            "{:?}",
+            a
+        );
+    };
+    close(hann(5), &[0., 0.5, 1., 0.5, 0.]);
+    close(hamming(5), &[0.08, 0.54, 1., 0.54, 0.08]);
+    close(blackman(5), &[0., 0.34, 1., 0.34, 0.]);
+    close(hann(1), &[1.]);
+    close(hann(0), &[]);
+    let w = hamming::<f32>(64);
+    assert!(w.iter().zip(w.iter().rev()).all(|(a, b)| a == b));
+}
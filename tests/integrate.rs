@@ -0,0 +1,141 @@
+use ndarray::integrate::Spacing;
+use ndarray::prelude::*;
+
+fn assert_close<D: Dimension>(a: &Array<f64, D>, b: &Array<f64, D>, tol: f64) {
+    assert_eq!(a.shape(), b.shape());
+    assert!(
+        a.iter().zip(b).all(|(x, y)| (x - y).abs() <= tol),
+        "\n{:?}\nis not close to\n{:?}",
+        a,
+        b
+    );
+}
+
+#[test]
+fn trapezoidal() {
+    let x = arr1(&[0., 0.5, 2., 3.]);
+    let a = Array::from_shape_fn((2, 4, 3), |(i, j, k)| (i + k) as f64 * x[j] + 1.);
+    // the trapezoidal rule is exact for linear functions
+    let t = a.trapz(Axis(1), Spacing::Coords(x.view()));
+    let expected = Array::from_shape_fn((2, 3), |(i, k)| (i + k) as f64 * 4.5 + 3.);
+    assert_close(&t, &expected, 1e-14);
+
+    let u = a.trapz(Axis(2), Spacing::Uniform(0.25));
+    for ((i, j), &v) in u.indexed_iter() {
+        let lane = a.slice(s![i, j, ..]);
+        assert_eq!(v, 0.25 * (lane[0] / 2. + lane[1] + lane[2] / 2.));
+    }
+
+    // any storage and layout
+    let b = a.slice(s![.., ..;-1, ..]);
+    let xr = x.slice(s![..;-1]).mapv(|v| -v);
+    assert_close(
+        &b.trapz(Axis(1), Spacing::Coords(xr.view())),
+        &expected,
+        1e-14,
+    );
+    let t0 = a.t().trapz(Axis(1), Spacing::Coords(x.view()));
+    assert_close(&t0, &expected.t().to_owned(), 1e-14);
+
+    // decreasing coordinates flip the sign
+    let xd = x.mapv(|v| -v);
+    assert_close(
+        &a.trapz(Axis(1), Spacing::Coords(xd.view())),
+        &-&expected,
+        1e-14,
+    );
+
+    // degenerate lanes
+    assert_eq!(arr1(&[5.]).trapz(Axis(0), Spacing::Uniform(1.))[()], 0.);
+    assert_eq!(
+        Array1::<f64>::zeros(0).trapz(Axis(0), Spacing::Uniform(1.))[()],
+        0.
+    );
+    assert_eq!(
+        Array2::<f64>::zeros((0, 3)).trapz(Axis(0), Spacing::Uniform(1.)),
+        Array1::zeros(3)
+    );
+}
+
+#[test]
+fn cumulative() {
+    let x = arr1(&[0., 1., 1.5, 4.]);
+    let a = Array::from_shape_fn((4, 2), |(i, j)| x[i] * x[i] + j as f64);
+    let c = a.cumulative_trapz(Axis(0), Spacing::Coords(x.view()));
+    assert_eq!(c.dim(), (4, 2));
+    assert_eq!(c.row(0), arr1(&[0., 0.]));
+    for i in 1..4 {
+        let partial = a.slice(s![..i + 1, ..]);
+        let xp = x.slice(s![..i + 1]);
+        assert_close(
+            &c.row(i).to_owned(),
+            &partial.trapz(Axis(0), Spacing::Coords(xp)),
+            1e-14,
+        );
+    }
+    let c = a.cumulative_trapz(Axis(1), Spacing::Uniform(2.));
+    assert_eq!(c.column(1), a.sum_axis(Axis(1)));
+    assert_eq!(
+        Array1::<f64>::zeros(0)
+            .cumulative_trapz(Axis(0), Spacing::Uniform(1.))
+            .len(),
+        0
+    );
+}
+
+#[test]
+fn simpsons_rule() {
+    let cubic = |x: f64| 2. * x * x * x - x * x + 3. * x - 1.;
+    let integral = |x: f64| 0.5 * x.powi(4) - x.powi(3) / 3. + 1.5 * x * x - x;
+    // exact for cubics with an even number of uniform intervals
+    for x in vec![Array::linspace(0., 2., 7), Array::linspace(-1., 1.5, 5)] {
+        let f = x.mapv(cubic);
+        let s = f.simpson(Axis(0), Spacing::Coords(x.view())).into_scalar();
+        let exact = integral(x[x.len() - 1]) - integral(x[0]);
+        assert!((s - exact).abs() < 1e-12, "{} != {}", s, exact);
+    }
+    // exact for quadratics with any intervals
+    let quad = |x: f64| 3. * x * x - 2. * x + 1.;
+    for x in vec![
+        Array::linspace(0., 3., 4),
+        arr1(&[-1., -0.2, 0.3, 1.2, 1.5]),
+        arr1(&[0., 0.1, 0.7, 0.8, 2., 2.2]),
+    ] {
+        let s = x
+            .mapv(quad)
+            .simpson(Axis(0), Spacing::Coords(x.view()))
+            .into_scalar();
+        let (a, b) = (x[0], x[x.len() - 1]);
+        let exact = (b * b * b - b * b + b) - (a * a * a - a * a + a);
+        assert!((s - exact).abs() < 1e-12, "{} != {}", s, exact);
+    }
+    // more accurate than the trapezoidal rule for smooth functions
+    let x = Array::linspace(0., std::f64::consts::PI, 21);
+    let f = x.mapv(f64::sin);
+    let s = f
+        .simpson(Axis(0), Spacing::Uniform(x[1] - x[0]))
+        .into_scalar();
+    let t = f
+        .trapz(Axis(0), Spacing::Uniform(x[1] - x[0]))
+        .into_scalar();
+    assert!((s - 2.).abs() < 1e-5);
+    assert!((s - 2.).abs() < (t - 2.).abs() / 100.);
+
+    // along an axis of a 3-D array
+    let a = Array::from_shape_fn((3, 5, 2), |(i, j, k)| quad(j as f64 * 0.5) * (i + k) as f64);
+    let s = a.simpson(Axis(1), Spacing::Uniform(0.5));
+    let exact = 8. - 4. + 2.;
+    assert_eq!(
+        arr1(&[1., 3.]).simpson(Axis(0), Spacing::Uniform(2.))[()],
+        4.
+    );
+    let expected = Array::from_shape_fn((3, 2), |(i, k)| exact * (i + k) as f64);
+    assert_close(&s, &expected, 1e-12);
+}
+
+#[test]
+#[should_panic]
+fn coordinate_length_mismatch() {
+    let x = arr1(&[0., 1.]);
+    Array2::<f64>::zeros((3, 3)).trapz(Axis(0), Spacing::Coords(x.view()));
+}
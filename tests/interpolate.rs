@@ -0,0 +1,227 @@
+use ndarray::interpolate::{
+    interp, interp_axis, CubicSpline, Extrapolate, InterpolateError, RegularGridInterpolator,
+};
+use ndarray::prelude::*;
+use ndarray::ErrorKind;
+use std::f64;
+
+fn is_shape_error<T: std::fmt::Debug>(r: Result<T, InterpolateError>) -> bool {
+    match r {
+        Err(InterpolateError::Shape(e)) => e.kind() == ErrorKind::IncompatibleShape,
+        _ => false,
+    }
+}
+
+#[test]
+fn linear() {
+    let xp = arr1(&[1., 2., 4., 8.]);
+    let fp = arr1(&[0., 10., 30., -10.]);
+    let x = arr1(&[1., 1.5, 2., 3., 4., 6., 8.]);
+    let expected = arr1(&[0., 5., 10., 20., 30., 10., -10.]);
+    assert_eq!(interp(&x, &xp, &fp, Extrapolate::Error).unwrap(), expected);
+
+    // any shape of points
+    let x2 = x.slice(s![..6]).into_shape((2, 3)).unwrap();
+    let f2 = interp(&x2, &xp, &fp, Extrapolate::Error).unwrap();
+    assert_eq!(f2, expected.slice(s![..6]).into_shape((2, 3)).unwrap());
+
+    let outside = arr1(&[0., 9.]);
+    assert_eq!(
+        interp(&outside, &xp, &fp, Extrapolate::Clamp).unwrap(),
+        arr1(&[0., -10.])
+    );
+    assert_eq!(
+        interp(&outside, &xp, &fp, Extrapolate::Fill(7.)).unwrap(),
+        arr1(&[7., 7.])
+    );
+    assert_eq!(
+        interp(&outside, &xp, &fp, Extrapolate::Error),
+        Err(InterpolateError::OutOfRange)
+    );
+    let nan = interp(&arr1(&[f64::NAN]), &xp, &fp, Extrapolate::Error).unwrap();
+    assert!(nan[0].is_nan());
+
+    // a single data point
+    let one = interp(
+        &arr1(&[3., 5.]),
+        &arr1(&[5.]),
+        &arr1(&[2.]),
+        Extrapolate::Clamp,
+    );
+    assert_eq!(one.unwrap(), arr1(&[2., 2.]));
+}
+
+#[test]
+fn linear_errors() {
+    let x = arr1(&[1.]);
+    assert!(is_shape_error(interp(
+        &x,
+        &arr1(&[0., 1.]),
+        &arr1(&[0.]),
+        Extrapolate::Error
+    )));
+    assert!(is_shape_error(interp(
+        &x,
+        &Array1::zeros(0),
+        &Array1::zeros(0),
+        Extrapolate::Clamp
+    )));
+    assert_eq!(
+        interp(
+            &x,
+            &arr1(&[0., 2., 2.]),
+            &arr1(&[0., 1., 2.]),
+            Extrapolate::Error
+        ),
+        Err(InterpolateError::NotIncreasing)
+    );
+}
+
+#[test]
+fn linear_along_axis() {
+    let xp = arr1(&[0., 1., 3.]);
+    let fp = Array::from_shape_fn((2, 3, 4), |(i, j, k)| (i * 100 + k) as f64 * xp[j]);
+    let x = arr1(&[0.5, 2., 3., 5.]);
+    let f = interp_axis(&x, &xp, &fp, Axis(1), Extrapolate::Fill(-1.)).unwrap();
+    assert_eq!(f.dim(), (2, 4, 4));
+    for i in 0..2 {
+        for k in 0..4 {
+            let lane = fp.slice(s![i, .., k]);
+            let expected = interp(&x, &xp, &lane, Extrapolate::Fill(-1.)).unwrap();
+            assert_eq!(f.slice(s![i, .., k]), expected);
+        }
+    }
+    assert_eq!(f[[1, 1, 2]], 204.);
+    assert_eq!(f[[1, 3, 2]], -1.);
+    assert!(is_shape_error(interp_axis(
+        &x,
+        &xp,
+        &fp,
+        Axis(0),
+        Extrapolate::Error
+    )));
+    assert_eq!(
+        interp_axis(&x, &xp, &fp, Axis(1), Extrapolate::Error),
+        Err(InterpolateError::OutOfRange)
+    );
+}
+
+#[test]
+fn regular_grid() {
+    let cx = arr1(&[0., 1., 3.]);
+    let cy = arr1(&[-1., 0.]);
+    let cz = arr1(&[0., 2., 3., 4.]);
+    // a multilinear function is reproduced exactly
+    let f = |x: f64, y: f64, z: f64| 1. + 2. * x - y + 0.5 * z + x * y * z;
+    let values = Array::from_shape_fn((3, 2, 4), |(i, j, k)| f(cx[i], cy[j], cz[k]));
+    let interp = RegularGridInterpolator::new(vec![cx, cy, cz], values).unwrap();
+    for &p in &[
+        [0.5, -0.5, 1.],
+        [3., 0., 4.],
+        [0., -1., 0.],
+        [2.2, -0.1, 3.7],
+    ] {
+        let v = interp.eval(&p).unwrap();
+        assert!((v - f(p[0], p[1], p[2])).abs() < 1e-12, "{:?}", p);
+    }
+    let points = arr2(&[[0.5, -0.5, 1.], [1., 0., 2.]]);
+    let v = interp.eval_points(&points).unwrap();
+    assert!((v[1] - f(1., 0., 2.)).abs() < 1e-12);
+
+    assert_eq!(
+        interp.eval(&[4., 0., 0.]),
+        Err(InterpolateError::OutOfRange)
+    );
+    let clamped = interp.clone().extrapolate(Extrapolate::Clamp);
+    assert_eq!(
+        clamped.eval(&[4., 0.5, -1.]).unwrap(),
+        clamped.eval(&[3., 0., 0.]).unwrap()
+    );
+    let filled = interp.clone().extrapolate(Extrapolate::Fill(0.));
+    assert_eq!(filled.eval(&[0., 0., 9.]).unwrap(), 0.);
+    assert!(is_shape_error(interp.eval(&[0., 0.])));
+    assert!(is_shape_error(interp.eval_points(&Array2::zeros((2, 2)))));
+}
+
+#[test]
+fn regular_grid_degenerate_and_errors() {
+    // an axis with a single coordinate
+    let values = arr2(&[[1., 3.]]);
+    let g = RegularGridInterpolator::new(vec![arr1(&[5.]), arr1(&[0., 1.])], values).unwrap();
+    assert_eq!(g.eval(&[5., 0.25]).unwrap(), 1.5);
+    assert!(g.eval(&[5.5, 0.25]).is_err());
+
+    let values = Array2::<f64>::zeros((2, 3));
+    assert!(is_shape_error(RegularGridInterpolator::new(
+        vec![arr1(&[0., 1.])],
+        values.clone()
+    )));
+    assert!(is_shape_error(RegularGridInterpolator::new(
+        vec![arr1(&[0., 1.]), arr1(&[0., 1.])],
+        values.clone()
+    )));
+    match RegularGridInterpolator::new(vec![arr1(&[0., 1.]), arr1(&[0., 2., 1.])], values) {
+        Err(InterpolateError::NotIncreasing) => {}
+        other => panic!("unexpected {:?}", other.map(|_| ())),
+    }
+}
+
+#[test]
+fn natural_spline() {
+    let x = Array::linspace(0., 2. * std::f64::consts::PI, 40);
+    let spline = CubicSpline::natural(&x, &x.mapv(f64::sin)).unwrap();
+    let m = spline.second_derivatives();
+    assert_eq!(m[0], 0.);
+    assert_eq!(m[39], 0.);
+    // interpolates the data points
+    for (&xi, yi) in x.iter().zip(x.mapv(f64::sin).iter()) {
+        assert!((spline.eval(xi).unwrap() - yi).abs() < 1e-14);
+    }
+    let fine = Array::linspace(0., 6., 101);
+    let err = (&spline.eval_points(&fine).unwrap() - &fine.mapv(f64::sin))
+        .mapv(f64::abs)
+        .fold(0., |a: f64, &b| a.max(b));
+    assert!(err < 1e-4, "error {}", err);
+
+    // two points give a straight line
+    let line = CubicSpline::natural(&arr1(&[0f64, 2.]), &arr1(&[1., 5.])).unwrap();
+    assert!((line.eval(0.5).unwrap() - 2.).abs() < 1e-15);
+}
+
+#[test]
+fn clamped_spline() {
+    // a cubic is reproduced exactly with the right end slopes
+    let f = |x: f64| 2. * x * x * x - x * x + 3.;
+    let df = |x: f64| 6. * x * x - 2. * x;
+    let x = arr1(&[-1., -0.2, 0.5, 0.7, 2.]);
+    let spline = CubicSpline::clamped(&x, &x.mapv(f), df(-1.), df(2.)).unwrap();
+    for &t in &[-0.9, -0.5, 0., 0.6, 1.3, 1.99] {
+        assert!((spline.eval(t).unwrap() - f(t)).abs() < 1e-12);
+    }
+    let spline = CubicSpline::clamped(&arr1(&[0f64, 1.]), &arr1(&[0., 1.]), 0., 3.).unwrap();
+    // the cubic x³ through (0, 0) and (1, 1)
+    assert!((spline.eval(0.5).unwrap() - 0.125).abs() < 1e-15);
+
+    let spline = spline.extrapolate(Extrapolate::Clamp);
+    assert_eq!(spline.eval(3.).unwrap(), 1.);
+    let spline = spline.extrapolate(Extrapolate::Fill(f64::NAN));
+    assert!(spline.eval(-1.).unwrap().is_nan());
+    let spline = spline.extrapolate(Extrapolate::Error);
+    assert_eq!(spline.eval(-1.), Err(InterpolateError::OutOfRange));
+}
+
+#[test]
+fn spline_errors() {
+    assert!(is_shape_error(CubicSpline::natural(
+        &arr1(&[0.]),
+        &arr1(&[1.])
+    )));
+    assert!(is_shape_error(CubicSpline::natural(
+        &arr1(&[0., 1.]),
+        &arr1(&[1.])
+    )));
+    match CubicSpline::clamped(&arr1(&[1., 0.]), &arr1(&[1., 2.]), 0., 0.) {
+        Err(InterpolateError::NotIncreasing) => {}
+        other => panic!("unexpected {:?}", other),
+    }
+}
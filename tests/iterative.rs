@@ -0,0 +1,228 @@
+use ndarray::linalg::{
+    bicgstab, cg, gmres, IterativeSolution, Jacobi, LinalgError, LinearOperator, SolverOptions,
+};
+use ndarray::prelude::*;
+use ndarray::sparse::{CooMatrix, CsrMatrix};
+use num_complex::Complex;
+
+/// The 1-D Laplacian of order `n`, plus `shift` on the diagonal and a
+/// convection term `c` that makes it nonsymmetric.
+fn laplacian(n: usize, shift: f64, c: f64) -> CsrMatrix<f64> {
+    let mut a = CooMatrix::new((n, n));
+    for i in 0..n {
+        a.push(i, i, 2. + shift);
+        if i > 0 {
+            a.push(i, i - 1, -1. - c);
+            a.push(i - 1, i, -1. + c);
+        }
+    }
+    a.to_csr()
+}
+
+fn rhs(n: usize) -> Array1<f64> {
+    Array::from_shape_fn(n, |i| ((i * 7) % 5) as f64 - 2.)
+}
+
+fn check_solution<Op>(a: &Op, b: &Array1<f64>, sol: &IterativeSolution<f64>, tol: f64)
+where
+    Op: LinearOperator<f64> + ?Sized,
+{
+    assert!(sol.converged, "{:?}", sol.residual_history);
+    let r = b - &a.apply(&sol.solution.view());
+    let res = r.norm_l2();
+    assert!(res <= tol * b.norm_l2(), "residual {} too large", res);
+    assert!((sol.residual_norm - res).abs() <= 1e-12 * b.norm_l2());
+    assert_eq!(sol.residual_history.len(), sol.iterations + 1);
+}
+
+#[test]
+fn operators() {
+    let a = laplacian(6, 0.5, 0.3);
+    let dense = a.to_dense();
+    let x = Array::linspace(-1., 2., 6);
+    assert_eq!(a.apply(&x.view()), dense.dot(&x));
+    assert_eq!(a.apply_transpose(&x.view()), dense.t().dot(&x));
+    assert_eq!(dense.apply_transpose(&x.view()), dense.t().dot(&x));
+    assert_eq!(a.to_csc().apply_transpose(&x.view()), dense.t().dot(&x));
+    assert_eq!(x.dot(&a), dense.t().dot(&x));
+    assert_eq!(a.diagonal(), Array1::from_elem(6, 2.5));
+}
+
+#[test]
+fn cg_laplacian() {
+    let n = 50;
+    let a = laplacian(n, 0., 0.);
+    let b = rhs(n);
+    let opts = SolverOptions::new().rtol(1e-10);
+    let sol = cg(&a, &b, &opts).unwrap();
+    check_solution(&a, &b, &sol, 1e-10);
+    // exact arithmetic would take at most n iterations
+    assert!(sol.iterations <= n + 5);
+
+    let dense = a.to_dense();
+    let sol_dense = cg(&dense, &b, &opts).unwrap();
+    check_solution(&dense, &b, &sol_dense, 1e-10);
+    assert_eq!(sol_dense.iterations, sol.iterations);
+}
+
+#[test]
+fn jacobi_preconditioning() {
+    // a badly scaled SPD matrix: D L D for the Laplacian L
+    let n = 40;
+    let d = Array::from_shape_fn(n, |i| 10f64.powi((i % 4) as i32));
+    let l = laplacian(n, 0.1, 0.).to_dense();
+    let a = &l * &d.view().insert_axis(Axis(1)) * &d;
+    let b = rhs(n);
+    let plain = cg(&a, &b, &SolverOptions::new().rtol(1e-10).max_iter(1000)).unwrap();
+    let m = Jacobi::new(&a.diag()).unwrap();
+    let opts = SolverOptions::new().rtol(1e-10).preconditioner(&m);
+    let pre = cg(&a, &b, &opts).unwrap();
+    check_solution(&a, &b, &pre, 1e-10);
+    assert!(pre.iterations < plain.iterations);
+
+    let pre = bicgstab(&a, &b, &opts).unwrap();
+    check_solution(&a, &b, &pre, 1e-10);
+    let pre = gmres(&a, &b, &opts.clone().restart(n)).unwrap();
+    check_solution(&a, &b, &pre, 1e-10);
+
+    match Jacobi::new(&arr1(&[1., 0.])) {
+        Err(LinalgError::Singular) => {}
+        _ => panic!("expected a singular preconditioner"),
+    }
+}
+
+#[test]
+fn nonsymmetric() {
+    let n = 60;
+    let a = laplacian(n, 0.2, 0.4);
+    let b = rhs(n);
+    let opts = SolverOptions::new().rtol(1e-10);
+    let sol = bicgstab(&a, &b, &opts).unwrap();
+    check_solution(&a, &b, &sol, 1e-10);
+
+    let sol = gmres(&a, &b, &opts.clone().restart(n)).unwrap();
+    check_solution(&a, &b, &sol, 1e-10);
+    assert!(sol.iterations <= n);
+
+    let sol = gmres(&a, &b, &opts.clone().restart(8).max_iter(2000)).unwrap();
+    check_solution(&a, &b, &sol, 1e-10);
+    assert!(sol
+        .residual_history
+        .windows(2)
+        .all(|w| w[1] <= w[0] * (1. + 1e-12)));
+}
+
+#[test]
+fn complex_gmres_and_bicgstab() {
+    let n = 20;
+    let a = Array::from_shape_fn((n, n), |(i, j)| {
+        if i == j {
+            Complex::new(4., 1.)
+        } else if i + 1 == j {
+            Complex::new(-1., 0.5)
+        } else if j + 1 == i {
+            Complex::new(0., -1.)
+        } else {
+            Complex::new(0., 0.)
+        }
+    });
+    let x = Array::from_shape_fn(n, |i| Complex::new(i as f64, 1. - i as f64));
+    let b = a.dot(&x);
+    let opts = SolverOptions::new().rtol(1e-12);
+    for sol in &[
+        gmres(&a, &b, &opts).unwrap(),
+        bicgstab(&a, &b, &opts).unwrap(),
+    ] {
+        assert!(sol.converged);
+        assert!((&sol.solution - &x).norm_max() < 1e-9);
+    }
+
+    // Hermitian positive definite
+    let h = a.t().mapv(|z| z.conj()).dot(&a);
+    let b = h.dot(&x);
+    let sol = cg(&h, &b, &opts).unwrap();
+    assert!(sol.converged);
+    assert!((&sol.solution - &x).norm_max() < 1e-8);
+}
+
+/// The operator `x ↦ 3 x - shift(x)`, defined without a matrix.
+struct Stencil;
+
+impl LinearOperator<f64> for Stencil {
+    fn apply(&self, x: &ArrayView1<'_, f64>) -> Array1<f64> {
+        let mut y = x * 3.;
+        for i in 1..x.len() {
+            y[i] -= x[i - 1];
+        }
+        y
+    }
+
+    fn apply_transpose(&self, x: &ArrayView1<'_, f64>) -> Array1<f64> {
+        let mut y = x * 3.;
+        for i in 1..x.len() {
+            y[i - 1] -= x[i];
+        }
+        y
+    }
+}
+
+#[test]
+fn matrix_free() {
+    let b = rhs(30);
+    let opts = SolverOptions::new().rtol(1e-12);
+    let sol = gmres(&Stencil, &b, &opts).unwrap();
+    check_solution(&Stencil, &b, &sol, 1e-12);
+    let sol = bicgstab(&Stencil, &b, &opts).unwrap();
+    check_solution(&Stencil, &b, &sol, 1e-12);
+
+    let op: &dyn LinearOperator<f64> = &Stencil;
+    let sol = gmres(op, &b, &opts).unwrap();
+    check_solution(op, &b, &sol, 1e-12);
+}
+
+#[test]
+fn options_and_diagnostics() {
+    let n = 30;
+    let a = laplacian(n, 0., 0.);
+    let b = rhs(n);
+
+    let exact = cg(&a, &b, &SolverOptions::new().rtol(1e-14)).unwrap();
+    let opts = SolverOptions::new().initial_guess(&exact.solution);
+    let sol = cg(&a, &b, &opts).unwrap();
+    assert!(sol.converged);
+    assert_eq!(sol.iterations, 0);
+    assert_eq!(sol.residual_history.len(), 1);
+
+    for &solve in &[cg, bicgstab, gmres] {
+        let sol = solve(&a, &b, &SolverOptions::new().rtol(1e-14).max_iter(3)).unwrap();
+        assert!(!sol.converged);
+        assert_eq!(sol.iterations, 3);
+        let sol = solve(&a, &b, &SolverOptions::new().atol(1e3)).unwrap();
+        assert!(sol.converged);
+        assert_eq!(sol.iterations, 0);
+        assert_eq!(sol.solution, Array1::zeros(n));
+    }
+
+    let zero = Array1::zeros(n);
+    let sol = gmres(&a, &zero, &SolverOptions::new()).unwrap();
+    assert!(sol.converged);
+    assert_eq!(sol.solution, zero);
+}
+
+#[test]
+fn initial_guess_length() {
+    let a = laplacian(5, 0., 0.);
+    let b = Array1::ones(5);
+    let x0 = Array1::zeros(3);
+    let opts = SolverOptions::new().initial_guess(&x0);
+    assert!(cg(&a, &b, &opts).is_err());
+    assert!(bicgstab(&a, &b, &opts).is_err());
+    assert!(gmres(&a, &b, &opts).is_err());
+}
+
+#[test]
+#[should_panic]
+fn operator_shape_mismatch() {
+    let a = laplacian(5, 0., 0.);
+    let _ = cg(&a, &Array1::ones(4), &SolverOptions::new());
+}
@@ -7,7 +7,8 @@
 )]
 
 use ndarray::prelude::*;
-use ndarray::NdProducer;
+use ndarray::{NdProducer, Zip};
+use std::iter::FromIterator;
 
 #[test]
 fn chunks() {
@@ -98,3 +99,121 @@ fn chunks_different_size_3() {
     let mut a = Array::<f32, _>::zeros(vec![2, 3]);
     a.exact_chunks_mut(vec![2, 3, 4]);
 }
+
+#[test]
+fn chunks_with_partial_edges() {
+    let a = Array::from_shape_fn((7, 5, 4), |(i, j, k)| (i * 100 + j * 10 + k) as i32);
+    let (m, n, o) = a.dim();
+    for &(ci, cj, ck) in &[
+        (1, 1, 1),
+        (2, 2, 2),
+        (3, 2, 4),
+        (7, 5, 4),
+        (8, 6, 5),
+        (4, 1, 3),
+    ] {
+        let c = a.chunks((ci, cj, ck));
+        let grid = ((m + ci - 1) / ci, (n + cj - 1) / cj, (o + ck - 1) / ck);
+        assert_eq!(c.raw_dim(), Dim(grid));
+        assert_eq!(c.into_iter().len(), grid.0 * grid.1 * grid.2);
+
+        let mut count = 0;
+        Zip::indexed(a.chunks((ci, cj, ck))).apply(|(i, j, k), chunk| {
+            let (x, y, z) = ((i * ci) as isize, (j * cj) as isize, (k * ck) as isize);
+            let expected = a.slice(s![x.., y.., z..]);
+            let expected = expected.slice(s![
+                ..ci.min(m - x as usize) as isize,
+                ..cj.min(n - y as usize) as isize,
+                ..ck.min(o - z as usize) as isize
+            ]);
+            assert_eq!(chunk, expected);
+            count += chunk.len();
+        });
+        assert_eq!(count, a.len());
+
+        // iteration is in logical order of the chunks
+        let firsts: Vec<_> = a
+            .chunks((ci, cj, ck))
+            .into_iter()
+            .map(|c| c[[0, 0, 0]])
+            .collect();
+        let expected: Vec<_> = Zip::from(a.chunks((ci, cj, ck))).fold(Vec::new(), |mut v, c| {
+            v.push(c[[0, 0, 0]]);
+            v
+        });
+        assert_eq!(firsts, expected);
+    }
+    assert_eq!(
+        Array2::<f32>::zeros((0, 3))
+            .chunks((2, 2))
+            .into_iter()
+            .count(),
+        0
+    );
+}
+
+#[test]
+fn chunks_split() {
+    let a = Array::from_iter(0..23).into_shape((23, 1)).unwrap();
+    let c = a.chunks((5, 1));
+    assert_eq!(c.raw_dim(), Dim((5, 1)));
+    let (c1, c2) = c.split_at(Axis(0), 3);
+    itertools::assert_equal(c1, a.chunks((5, 1)).into_iter().take(3));
+    itertools::assert_equal(c2, a.chunks((5, 1)).into_iter().skip(3));
+    let (c1, c2) = a.chunks((5, 1)).split_at(Axis(0), 5);
+    assert_eq!(c1.into_iter().count(), 5);
+    assert_eq!(c2.into_iter().count(), 0);
+}
+
+#[test]
+fn chunks_mut_zip() {
+    let mut a = Array::zeros((5, 7));
+    let mut b = Array::<i32, _>::zeros((5, 7));
+    let mut n = 0;
+    Zip::from(a.chunks_mut((2, 3)))
+        .and(b.view_mut().reversed_axes().chunks_mut((3, 2)))
+        .apply(|mut x, mut y| {
+            n += 1;
+            x.fill(n);
+            y.fill(-n);
+        });
+    assert_eq!(n, 9);
+    assert_eq!(
+        a,
+        array![
+            [1, 1, 1, 2, 2, 2, 3],
+            [1, 1, 1, 2, 2, 2, 3],
+            [4, 4, 4, 5, 5, 5, 6],
+            [4, 4, 4, 5, 5, 5, 6],
+            [7, 7, 7, 8, 8, 8, 9]
+        ]
+    );
+    // the chunks of the transposed view are transposed chunks of b
+    for ((r, c), &v) in b.indexed_iter() {
+        assert_eq!(v, -((1 + (c / 3) * 3 + r / 2) as i32));
+    }
+    let mut total = 0;
+    for mut chunk in b.chunks_mut((4, 4)) {
+        chunk.fill(1);
+        total += chunk.len();
+    }
+    assert_eq!(total, 35);
+    assert_eq!(b, Array::ones((5, 7)));
+}
+
+#[test]
+fn chunks_dyn() {
+    let a = Array::from_iter(0..10).into_shape(vec![2, 5]).unwrap();
+    let shapes: Vec<_> = a
+        .chunks(vec![2, 2])
+        .into_iter()
+        .map(|c| c.shape().to_vec())
+        .collect();
+    assert_eq!(shapes, vec![vec![2, 2], vec![2, 2], vec![2, 1]]);
+}
+
+#[should_panic]
+#[test]
+fn chunks_zero_size() {
+    Array::<f32, _>::zeros((2, 3)).chunks((2, 0));
+}
@@ -0,0 +1,54 @@
+use ndarray::block;
+use ndarray::linalg::{kron, outer};
+use ndarray::prelude::*;
+
+#[test]
+fn outer_product() {
+    let a = arr1(&[1, -2, 3]);
+    let b = Array::range(0., 8., 1.).mapv(|x: f64| x as i32);
+    let b = b.slice(s![..;-3]);
+    let c = outer(&a, &b);
+    assert_eq!(c.dim(), (3, 3));
+    for ((i, j), &x) in c.indexed_iter() {
+        assert_eq!(x, a[i] * b[j]);
+    }
+    assert_eq!(
+        c,
+        a.view()
+            .insert_axis(Axis(1))
+            .dot(&b.view().insert_axis(Axis(0)))
+    );
+    assert_eq!(outer(&a, &Array1::zeros(0)).dim(), (3, 0));
+}
+
+#[test]
+fn kron_product() {
+    let a = arr2(&[[1, 2], [3, 4], [5, 6]]);
+    let b = arr2(&[[0, 1], [1, 0]]);
+    let c = kron(&a, &b);
+    assert_eq!(c.dim(), (6, 4));
+    for ((i, j), &x) in c.indexed_iter() {
+        assert_eq!(x, a[(i / 2, j / 2)] * b[(i % 2, j % 2)]);
+    }
+    assert_eq!(
+        kron(&Array2::eye(2), &b),
+        block![[b, Array2::zeros((2, 2))], [Array2::zeros((2, 2)), b]]
+    );
+
+    let v = kron(&arr1(&[1, 10]), &arr1(&[1, 2, 3]));
+    assert_eq!(v, arr1(&[1, 2, 3, 10, 20, 30]));
+
+    let a = Array::from_shape_fn((2, 3, 2), |(i, j, k)| (i * 6 + j * 2 + k) as i64);
+    let b = Array::from_shape_fn((3, 1, 2), |(i, j, k)| (i + j + k) as i64 - 1);
+    let c = kron(&a.t(), &b);
+    assert_eq!(c.dim(), (6, 3, 4));
+    for ((i, j, k), &x) in c.indexed_iter() {
+        assert_eq!(x, a.t()[(i / 3, j, k / 2)] * b[(i % 3, 0, k % 2)]);
+    }
+}
+
+#[test]
+#[should_panic]
+fn kron_dyn_mismatch() {
+    kron(&ArrayD::<f64>::zeros(vec![2, 2]), &ArrayD::zeros(vec![2]));
+}
@@ -0,0 +1,101 @@
+#![cfg(feature = "approx")]
+
+use ndarray::linalg::LinalgError;
+use ndarray::prelude::*;
+use num_complex::Complex;
+
+use approx::assert_abs_diff_eq;
+
+mod common;
+
+/// A reproducible, well-conditioned test matrix.
+fn test_matrix(n: usize) -> Array2<f64> {
+    let mut a = common::test_matrix(n, n);
+    for i in 0..n {
+        a[(i, i)] += n as f64;
+    }
+    a
+}
+
+#[test]
+fn lu_reconstruct() {
+    for &n in &[1, 2, 5, 31, 32, 33, 100] {
+        let a = test_matrix(n);
+        let lu = a.lu().unwrap();
+        assert!(!lu.is_singular());
+        assert_abs_diff_eq!(lu.p().dot(&a), lu.l().dot(&lu.u()), epsilon = 1e-10);
+    }
+}
+
+#[test]
+fn lu_pivoting() {
+    let a = arr2(&[[0., 1.], [1., 0.]]);
+    let lu = a.lu().unwrap();
+    assert_eq!(lu.pivots(), &[1, 1]);
+    assert_eq!(lu.det(), -1.);
+}
+
+#[test]
+fn solve_vector() {
+    let a = test_matrix(70);
+    let x = Array::linspace(-1., 1., 70);
+    let b = a.dot(&x);
+    assert_abs_diff_eq!(a.solve(&b).unwrap(), x, epsilon = 1e-12);
+}
+
+#[test]
+fn solve_many_f32_fortran_order() {
+    let a = test_matrix(40).mapv(|x| x as f32).reversed_axes();
+    let x = Array::from_shape_fn((40, 3), |(i, j)| (i + j) as f32);
+    let b = a.dot(&x);
+    assert_abs_diff_eq!(a.solve_many(&b).unwrap(), x, epsilon = 1e-3);
+}
+
+#[test]
+fn inv_and_det() {
+    let a = arr2(&[[4., 7.], [2., 6.]]);
+    assert_abs_diff_eq!(a.det().unwrap(), 10., epsilon = 1e-12);
+    let inv = a.inv().unwrap();
+    assert_abs_diff_eq!(inv, arr2(&[[0.6, -0.7], [-0.2, 0.4]]), epsilon = 1e-12);
+
+    let a = test_matrix(50);
+    assert_abs_diff_eq!(a.inv().unwrap().dot(&a), Array::eye(50), epsilon = 1e-12);
+}
+
+#[test]
+fn complex_solve() {
+    let c = Complex::new;
+    let a = arr2(&[[c(1., 1.), c(2., 0.)], [c(0., -1.), c(3., 1.)]]);
+    let x = arr1(&[c(1., 2.), c(-1., 0.5)]);
+    let b = a.dot(&x);
+    let y = a.solve(&b).unwrap();
+    for (y, x) in y.iter().zip(&x) {
+        assert!((y - x).norm() < 1e-12);
+    }
+    let det = a.det().unwrap();
+    assert!((det - (c(1., 1.) * c(3., 1.) - c(2., 0.) * c(0., -1.))).norm() < 1e-12);
+}
+
+#[test]
+fn singular() {
+    let a = arr2(&[[1., 2., 3.], [2., 4., 6.], [1., 0., 1.]]);
+    let lu = a.lu().unwrap();
+    assert!(lu.is_singular());
+    assert_eq!(lu.det(), 0.);
+    assert_eq!(a.inv(), Err(LinalgError::Singular));
+    assert_eq!(a.solve(&arr1(&[1., 2., 3.])), Err(LinalgError::Singular));
+}
+
+#[test]
+fn errors() {
+    let a = Array2::<f64>::zeros((2, 3));
+    assert_eq!(
+        a.lu().unwrap_err(),
+        LinalgError::NotSquare { rows: 2, cols: 3 }
+    );
+    let a = Array2::<f64>::eye(3);
+    match a.solve(&arr1(&[1., 2.])) {
+        Err(LinalgError::Shape(_)) => {}
+        r => panic!("unexpected result {:?}", r),
+    }
+}
@@ -0,0 +1,142 @@
+use ndarray::linalg::LinalgError;
+use ndarray::prelude::*;
+use ndarray::ErrorKind;
+use num_complex::Complex;
+
+fn assert_close(a: &Array2<f64>, b: &Array2<f64>, tol: f64) {
+    let scale = b.norm_max().max(1.);
+    assert!(
+        (a - b).norm_max() <= tol * scale,
+        "\n{:?}\nis not close to\n{:?}",
+        a,
+        b
+    );
+}
+
+#[test]
+fn trace() {
+    let a = Array::from_shape_fn((3, 3), |(i, j)| (i * 3 + j) as i32);
+    assert_eq!(a.trace().unwrap(), 12);
+    assert_eq!(a.t().trace().unwrap(), 12);
+    assert_eq!(a.slice(s![..;-1, ..]).trace().unwrap(), 12);
+    assert_eq!(Array2::<f64>::zeros((0, 0)).trace().unwrap(), 0.);
+    let err = a.slice(s![.., 1..]).trace().unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::IncompatibleShape);
+}
+
+#[test]
+fn powm() {
+    let a = arr2(&[[2, 1, 0], [0, 1, -1], [1, 0, 3]]);
+    assert_eq!(a.powm(0).unwrap(), Array2::eye(3));
+    assert_eq!(a.powm(1).unwrap(), a);
+    let mut p = Array2::eye(3);
+    for k in 1..12 {
+        p = p.dot(&a);
+        assert_eq!(a.powm(k).unwrap(), p);
+    }
+    assert_eq!(a.t().powm(5).unwrap(), a.powm(5).unwrap().t());
+    let f = arr2(&[[1., 0.5], [0.25, 0.]]);
+    assert_close(&f.powm(7).unwrap(), &f.dot(&f.powm(6).unwrap()), 1e-15);
+    assert_eq!(
+        arr2(&[[1, 2, 3]]).powm(2).unwrap_err(),
+        LinalgError::NotSquare { rows: 1, cols: 3 }
+    );
+}
+
+#[test]
+fn expm_small_and_special() {
+    assert_eq!(Array2::<f64>::zeros((3, 3)).expm().unwrap(), Array2::eye(3));
+    let nilpotent = arr2(&[[0., 1., 2.], [0., 0., 3.], [0., 0., 0.]]);
+    assert_close(
+        &nilpotent.expm().unwrap(),
+        &arr2(&[[1., 1., 3.5], [0., 1., 3.], [0., 0., 1.]]),
+        1e-15,
+    );
+    // diagonal matrices over the whole range of norms, hitting every degree
+    for &x in &[1e-3, 0.1, 0.5, 1.5, 3., 10., -20., 100.] {
+        let d = arr1(&[x, x / 2., -x]);
+        let e = Array2::from_diag(&d).expm().unwrap();
+        assert_close(&e, &Array2::from_diag(&d.mapv(f64::exp)), 1e-13);
+    }
+    assert_eq!(
+        Array2::<f64>::zeros((2, 3)).expm().unwrap_err(),
+        LinalgError::NotSquare { rows: 2, cols: 3 }
+    );
+}
+
+#[test]
+fn expm_rotation_and_inverse() {
+    for &t in &[0.3f64, 2., 25.] {
+        let a = arr2(&[[0., -t], [t, 0.]]);
+        let expected = arr2(&[[t.cos(), -t.sin()], [t.sin(), t.cos()]]);
+        assert_close(&a.expm().unwrap(), &expected, 1e-13);
+    }
+    let a = Array::from_shape_fn((6, 6), |(i, j)| ((i * 5 + j * 3) % 7) as f64 / 3. - 1.);
+    let e = a.expm().unwrap();
+    let e_neg = a.mapv(|x| -x).expm().unwrap();
+    assert_close(&e.dot(&e_neg), &Array2::eye(6), 1e-12);
+    // exp(A) commutes with A, and exp(2A) = exp(A)²
+    assert_close(&a.dot(&e), &e.dot(&a), 1e-12);
+    let e2 = a.mapv(|x| 2. * x).expm().unwrap();
+    assert_close(&e2, &e.dot(&e), 1e-12);
+    // the determinant is the exponential of the trace
+    let det = e.det().unwrap();
+    assert!((det / a.trace().unwrap().exp() - 1.).abs() < 1e-12);
+}
+
+#[test]
+fn expm_f32_and_complex() {
+    let a = arr2(&[[0f32, 1.], [-1., 0.]]);
+    let e = a.expm().unwrap();
+    let expected = arr2(&[[1f32.cos(), 1f32.sin()], [-(1f32.sin()), 1f32.cos()]]);
+    assert!((&e - &expected).norm_max() < 1e-6);
+
+    let i = Complex::new(0., 1.);
+    let theta = 0.7f64;
+    let z = Array2::from_diag(&arr1(&[i * theta, -i * theta]));
+    let e = z.expm().unwrap();
+    assert!((e[(0, 0)] - Complex::new(theta.cos(), theta.sin())).norm() < 1e-15);
+    assert!((e[(1, 1)] - Complex::new(theta.cos(), -theta.sin())).norm() < 1e-15);
+    assert!(e[(0, 1)].norm() < 1e-15);
+}
+
+#[test]
+fn sqrtm() {
+    // symmetric positive definite
+    let b = Array::from_shape_fn((5, 5), |(i, j)| ((i + 2 * j) % 5) as f64 - 2.);
+    let a = b.t().dot(&b) + Array2::<f64>::eye(5);
+    let x = a.sqrtm().unwrap();
+    assert_close(&x.dot(&x), &a, 1e-12);
+    assert_close(&x, &x.t().to_owned(), 1e-12);
+
+    // nonsymmetric, with positive eigenvalues
+    let a = arr2(&[[5., 1., 0.], [2., 6., 1.], [0., 1., 7.]]);
+    let x = a.sqrtm().unwrap();
+    assert_close(&x.dot(&x), &a, 1e-13);
+    assert_close(&a.sqrtm().unwrap().powm(2).unwrap(), &a, 1e-13);
+
+    // complex: the square root of 2i I is (1 + i) I
+    let c = Array2::from_diag(&arr1(&[Complex::new(0., 2.), Complex::new(0., 2.)]));
+    let x = c.sqrtm().unwrap();
+    assert!((x[(0, 0)] - Complex::new(1., 1.)).norm() < 1e-14);
+    assert!((x[(1, 1)] - Complex::new(1., 1.)).norm() < 1e-14);
+    assert!(x[(0, 1)].norm() < 1e-14);
+
+    assert_eq!(Array2::<f64>::zeros((0, 0)).sqrtm().unwrap().dim(), (0, 0));
+}
+
+#[test]
+fn sqrtm_errors() {
+    assert_eq!(
+        Array2::<f64>::zeros((2, 3)).sqrtm().unwrap_err(),
+        LinalgError::NotSquare { rows: 2, cols: 3 }
+    );
+    match arr2(&[[1., 2.], [2., 4.]]).sqrtm() {
+        Err(LinalgError::Singular) => {}
+        other => panic!("unexpected {:?}", other),
+    }
+    match arr2(&[[-1., 0.], [0., 4.]]).sqrtm() {
+        Err(LinalgError::NotConverged) => {}
+        other => panic!("unexpected {:?}", other),
+    }
+}
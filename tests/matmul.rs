@@ -0,0 +1,111 @@
+use ndarray::linalg::matmul;
+use ndarray::prelude::*;
+
+fn range_array<D: Dimension, Sh: ShapeBuilder<Dim = D>>(shape: Sh) -> Array<i64, D> {
+    let mut a = Array::zeros(shape);
+    for (i, x) in a.iter_mut().enumerate() {
+        *x = (i as i64 * 7) % 11 - 5;
+    }
+    a
+}
+
+#[test]
+fn matmul_stacks() {
+    let a = range_array((4, 3, 5));
+    let b = range_array((4, 5, 2));
+    let c = a.matmul(&b);
+    assert_eq!(c.dim(), (4, 3, 2));
+    for i in 0..4 {
+        assert_eq!(
+            c.index_axis(Axis(0), i),
+            a.index_axis(Axis(0), i).dot(&b.index_axis(Axis(0), i))
+        );
+    }
+    assert_eq!(matmul(&a, &b), c);
+}
+
+#[test]
+fn matmul_broadcast_2d() {
+    let a = range_array((4, 3, 5));
+    let m = range_array((5, 2));
+    let c = a.matmul(&m);
+    let d = m.t().matmul(&a.view().permuted_axes([0, 2, 1]));
+    for i in 0..4 {
+        let ai = a.index_axis(Axis(0), i);
+        assert_eq!(c.index_axis(Axis(0), i), ai.dot(&m));
+        assert_eq!(d.index_axis(Axis(0), i), m.t().dot(&ai.t()));
+    }
+    assert_eq!(m.matmul(&m.t()), m.dot(&m.t()));
+}
+
+#[test]
+fn matmul_strided_inputs() {
+    let a = range_array((6, 7, 8));
+    let b = range_array((3, 5, 8));
+    let av = a.slice(s![..;2, 1..;2, ..;2]);
+    let bv = b.slice(s![.., .., ..;2]).permuted_axes([0, 2, 1]);
+    let c = av.matmul(&bv);
+    assert_eq!(c.dim(), (3, 3, 5));
+    for i in 0..3 {
+        let expected = av.index_axis(Axis(0), i).dot(&bv.index_axis(Axis(0), i));
+        assert_eq!(c.index_axis(Axis(0), i), expected);
+    }
+}
+
+#[test]
+fn matmul_dyn_broadcasting() {
+    let a = range_array((2, 1, 3, 4));
+    let b = range_array((5, 4, 2));
+    let c = a.view().into_dyn().matmul(&b.view().into_dyn());
+    assert_eq!(c.shape(), &[2, 5, 3, 2]);
+    for i in 0..2usize {
+        for j in 0..5usize {
+            let ai: ArrayView2<_> = a.slice(s![i, 0, .., ..]);
+            let bj: ArrayView2<_> = b.slice(s![j, .., ..]);
+            assert_eq!(c.slice(s![i, j, .., ..]), ai.dot(&bj));
+        }
+    }
+}
+
+#[test]
+fn matmul_dyn_vectors() {
+    let a = range_array((2, 3, 4));
+    let v = range_array(4);
+    let w = range_array(3);
+    let av = a.view().into_dyn().matmul(&v.view().into_dyn());
+    assert_eq!(av.shape(), &[2, 3]);
+    let wa = w.view().into_dyn().matmul(&a.view().into_dyn());
+    assert_eq!(wa.shape(), &[2, 4]);
+    for i in 0..2usize {
+        let ai = a.slice(s![i, .., ..]);
+        assert_eq!(av.slice(s![i, ..]), ai.dot(&v));
+        assert_eq!(wa.slice(s![i, ..]), w.dot(&ai));
+    }
+    let vd = v.view().into_dyn();
+    let vv = vd.matmul(&vd);
+    assert_eq!(vv.ndim(), 0);
+    assert_eq!(vv[[]], v.dot(&v));
+}
+
+#[test]
+#[should_panic]
+fn matmul_incompatible_matrices() {
+    range_array((2, 3, 4)).matmul(&range_array((2, 3, 4)));
+}
+
+#[test]
+#[should_panic]
+fn matmul_incompatible_batches() {
+    range_array((2, 3, 4)).matmul(&range_array((3, 4, 3)));
+}
+
+#[test]
+#[cfg(feature = "rayon")]
+fn par_matmul() {
+    let a = range_array((3, 16, 20, 30)).into_dyn();
+    let b = range_array((16, 30, 10)).into_dyn();
+    assert_eq!(a.par_matmul(&b), a.matmul(&b));
+    let a = range_array((40, 20, 30));
+    let b = range_array((1, 30, 10));
+    assert_eq!(a.par_matmul(&b), a.matmul(&b));
+}
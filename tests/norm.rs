@@ -0,0 +1,155 @@
+#![cfg(feature = "approx")]
+
+use ndarray::linalg::Norm;
+use ndarray::prelude::*;
+use num_complex::Complex;
+
+use approx::assert_abs_diff_eq;
+use std::f64;
+
+#[test]
+fn vector_norms() {
+    let a = arr1(&[3., -4., 0., 12.]);
+    assert_eq!(a.norm_l1(), 19.);
+    assert_eq!(a.norm_l2(), 13.);
+    assert_eq!(a.norm_max(), 12.);
+    assert_abs_diff_eq!(a.norm_lp(3.), (27f64 + 64. + 1728.).cbrt(), epsilon = 1e-12);
+    assert_abs_diff_eq!(
+        a.norm_lp(0.5),
+        (3f64.sqrt() + 2. + 12f64.sqrt()).powi(2),
+        epsilon = 1e-12
+    );
+    assert_eq!(a.norm_lp(1.), a.norm_l1());
+    assert_eq!(a.norm_lp(f64::INFINITY), a.norm_max());
+    assert_eq!(a.norm(Norm::Lp(2.)), a.norm_l2());
+
+    // whole arrays and strided views
+    let m = Array::from_shape_fn((3, 4, 5), |(i, j, k)| (i * 20 + j * 5 + k) as f64 - 30.);
+    let flat = m.iter().cloned().collect::<Array1<_>>();
+    let v = m.slice(s![.., ..;-2, 1..;2]);
+    let vflat = v.iter().cloned().collect::<Array1<_>>();
+    for &norm in &[Norm::L1, Norm::L2, Norm::Max, Norm::Lp(3.)] {
+        assert_abs_diff_eq!(m.norm(norm), flat.norm(norm), epsilon = 1e-10);
+        assert_abs_diff_eq!(v.norm(norm), vflat.norm(norm), epsilon = 1e-10);
+    }
+    assert_abs_diff_eq!(m.norm_l2(), flat.dot(&flat).sqrt(), epsilon = 1e-10);
+
+    let empty = Array1::<f64>::zeros(0);
+    assert_eq!(empty.norm_l1(), 0.);
+    assert_eq!(empty.norm_l2(), 0.);
+    assert_eq!(empty.norm_max(), 0.);
+}
+
+#[test]
+fn l2_norm_scaling() {
+    let big = Array1::from_elem(100, 1e300);
+    assert_abs_diff_eq!(big.norm_l2() / 1e301, 1., epsilon = 1e-14);
+    let small = Array1::from_elem(100, 1e-300);
+    assert_abs_diff_eq!(small.norm_l2() / 1e-299, 1., epsilon = 1e-14);
+    let big32 = Array1::from_elem(4, 1e30f32);
+    assert_abs_diff_eq!(big32.norm_l2() / 2e30, 1., epsilon = 1e-6);
+    assert_abs_diff_eq!(
+        big32.norm_lp(4.) / 4f32.powf(0.25) / 1e30,
+        1.,
+        epsilon = 1e-6
+    );
+
+    assert!(arr1(&[1., f64::NAN, 2.]).norm_l2().is_nan());
+    assert!(arr1(&[f64::NAN, 1.]).norm_max().is_nan());
+    assert_eq!(arr1(&[1., f64::INFINITY]).norm_l2(), f64::INFINITY);
+}
+
+#[test]
+fn complex_norms() {
+    let a = arr1(&[
+        Complex::new(3., 4.),
+        Complex::new(0., -12.),
+        Complex::new(0., 0.),
+    ]);
+    assert_eq!(a.norm_l1(), 17.);
+    assert_eq!(a.norm_l2(), 13.);
+    assert_eq!(a.norm_max(), 12.);
+    let big = a.mapv(|x| x * 1e300);
+    assert_abs_diff_eq!(big.norm_l2() / 1.3e301, 1., epsilon = 1e-14);
+
+    let m = Array::from_shape_fn((40, 3), |(i, j)| Complex::new(i as f64, j as f64 - 1.));
+    let expected = m.iter().map(|x| x.norm_sqr()).sum::<f64>().sqrt();
+    assert_abs_diff_eq!(m.norm_l2(), expected, epsilon = 1e-10);
+    assert_abs_diff_eq!(
+        m.column(2).norm_l2(),
+        m.column(2).mapv(|x| x.norm_sqr()).sum().sqrt(),
+        epsilon = 1e-10
+    );
+}
+
+#[test]
+fn axis_norms() {
+    let a = arr2(&[[3., 0., -1.], [-4., 2., 1.]]);
+    assert_eq!(a.norm_l1_axis(Axis(0)), arr1(&[7., 2., 2.]));
+    assert_eq!(a.norm_l1_axis(Axis(1)), arr1(&[4., 7.]));
+    assert_abs_diff_eq!(
+        a.norm_l2_axis(Axis(0)),
+        arr1(&[5., 2., 2f64.sqrt()]),
+        epsilon = 1e-15
+    );
+    assert_eq!(a.norm_max_axis(Axis(1)), arr1(&[3., 4.]));
+    assert_abs_diff_eq!(
+        a.norm_lp_axis(Axis(0), 3.),
+        arr1(&[91f64.cbrt(), 2., 2f64.cbrt()]),
+        epsilon = 1e-12
+    );
+}
+
+#[test]
+fn matrix_norms() {
+    let a = arr2(&[[1., -2., 3.], [-4., 5., -6.]]);
+    assert_eq!(a.norm_1(), 9.);
+    assert_eq!(a.norm_inf(), 15.);
+    assert_abs_diff_eq!(a.norm_fro(), 91f64.sqrt(), epsilon = 1e-14);
+    assert_eq!(a.t().norm_1(), a.norm_inf());
+
+    let d = arr2(&[[3., 0.], [0., -4.], [0., 0.]]);
+    assert_abs_diff_eq!(d.norm_nuc().unwrap(), 7., epsilon = 1e-14);
+    let b = Array::from_shape_fn((5, 4), |(i, j)| ((i * 3 + j * 7) % 11) as f64 - 5.);
+    let (_, s, _) = b.svd(ndarray::linalg::SVDMode::Thin).unwrap();
+    assert_abs_diff_eq!(b.norm_nuc().unwrap(), s.sum(), epsilon = 1e-12);
+    assert_abs_diff_eq!(b.norm_fro(), s.norm_l2(), epsilon = 1e-12);
+    assert!(b.norm_2().unwrap() <= b.norm_fro());
+}
+
+#[test]
+fn normalize() {
+    let mut a = Array::from_shape_fn((4, 3), |(i, j)| (i as f64 - 1.5) * (j as f64 + 1.));
+    a.column_mut(1).fill(0.);
+    let mut b = a.clone();
+    b.normalize(Axis(0), Norm::L2);
+    for (j, col) in b.axis_iter(Axis(1)).enumerate() {
+        if j == 1 {
+            assert_eq!(col, Array1::<f64>::zeros(4));
+        } else {
+            assert_abs_diff_eq!(col.norm_l2(), 1., epsilon = 1e-15);
+        }
+    }
+    let mut c = a.clone();
+    c.normalize(Axis(1), Norm::L1);
+    for row in c.genrows() {
+        assert_abs_diff_eq!(row.norm_l1(), 1., epsilon = 1e-15);
+    }
+    let mut d = a.clone();
+    d.normalize(Axis(1), Norm::Lp(3.));
+    assert_abs_diff_eq!(
+        d.norm_lp_axis(Axis(1), 3.),
+        Array1::ones(4),
+        epsilon = 1e-14
+    );
+
+    let mut z = arr1(&[Complex::new(3., 4.), Complex::new(0., 0.)]);
+    z.normalize(Axis(0), Norm::Max);
+    assert_eq!(z, arr1(&[Complex::new(0.6, 0.8), Complex::new(0., 0.)]));
+}
+
+#[test]
+#[should_panic]
+fn norm_lp_nonpositive() {
+    arr1(&[1., 2.]).norm_lp(0.);
+}
@@ -0,0 +1,165 @@
+use ndarray::ode::{Dopri5, OdeError, Rk4};
+use ndarray::prelude::*;
+
+fn oscillator(_t: f64, y: ArrayView1<'_, f64>, mut dy: ArrayViewMut1<'_, f64>) {
+    dy[0] = y[1];
+    dy[1] = -y[0];
+}
+
+#[test]
+fn rk4_fixed_steps() {
+    let y0 = arr1(&[0., 1.]);
+    let sol = Rk4::new(50).solve(oscillator, (0., 5.), &y0);
+    assert_eq!(sol.t().len(), 51);
+    assert_eq!(sol.y().dim(), (51, 2));
+    assert_eq!(sol.t()[0], 0.);
+    assert_eq!(sol.t()[50], 5.);
+    assert_eq!(sol.state(0), y0);
+    for (i, &t) in sol.t().iter().enumerate() {
+        assert!((t - 0.1 * i as f64).abs() < 1e-14);
+        assert!((sol.y()[[i, 0]] - t.sin()).abs() < 1e-5);
+    }
+
+    // fourth order convergence
+    let err = |n| {
+        let end = Rk4::new(n)
+            .solve(oscillator, (0., 5.), &y0)
+            .state(n)
+            .to_owned();
+        (end - arr1(&[5f64.sin(), 5f64.cos()])).mapv(f64::abs).sum()
+    };
+    let ratio = err(40) / err(80);
+    assert!(ratio > 14. && ratio < 18., "ratio {}", ratio);
+}
+
+#[test]
+fn rk4_dense_output() {
+    // y = t³ is reproduced exactly by the steps and the cubic interpolant
+    let sol = Rk4::new(3).solve(|t, _y, mut dy| dy[0] = 3. * t * t, (0., 1.5), &arr1(&[0.]));
+    for &t in &[0f64, 0.1, 0.37, 0.5, 0.99, 1.5] {
+        assert!(
+            (sol.eval(t).unwrap()[0] - t * t * t).abs() < 1e-14,
+            "t = {}",
+            t
+        );
+    }
+    assert_eq!(sol.eval(1.6), Err(OdeError::OutOfRange));
+    assert_eq!(sol.eval(-0.1), Err(OdeError::OutOfRange));
+}
+
+#[test]
+fn dopri5_accuracy() {
+    let y0 = arr1(&[0., 1.]);
+    let sol = Dopri5::new()
+        .rtol(1e-10)
+        .atol(1e-12)
+        .solve(oscillator, (0., 10.), &y0)
+        .unwrap();
+    let n = sol.t().len();
+    assert_eq!(sol.t()[n - 1], 10.);
+    assert!(sol.t().windows(2).into_iter().all(|w| w[0] < w[1]));
+    for (&t, y) in sol.t().iter().zip(sol.y().outer_iter()) {
+        assert!((y[0] - t.sin()).abs() < 1e-8);
+        assert!((y[1] - t.cos()).abs() < 1e-8);
+    }
+    // dense output between the steps
+    let ts = Array::linspace(0., 10., 97);
+    let ys = sol.eval_points(&ts).unwrap();
+    for (&t, y) in ts.iter().zip(ys.outer_iter()) {
+        assert!((y[0] - t.sin()).abs() < 1e-8, "t = {}", t);
+    }
+
+    // looser tolerances take fewer steps
+    let coarse = Dopri5::new().solve(oscillator, (0., 10.), &y0).unwrap();
+    assert!(coarse.t().len() < n);
+    assert!((coarse.state(coarse.t().len() - 1)[0] - 10f64.sin()).abs() < 1e-2);
+
+    // a largest step size
+    let fine = Dopri5::new()
+        .max_step(0.1)
+        .solve(oscillator, (0., 10.), &y0)
+        .unwrap();
+    assert!(fine.t().len() > 100);
+}
+
+#[test]
+fn dopri5_array_state() {
+    // the state is a matrix that decays towards zero, in Fortran layout
+    let y0 = Array::from_shape_fn((2, 3).f(), |(i, j)| (i * 3 + j) as f64);
+    let sol = Dopri5::new()
+        .rtol(1e-9)
+        .atol(1e-12)
+        .solve(|_t, y, mut dy| dy.assign(&(&y * -2.)), (0., 1.), &y0)
+        .unwrap();
+    let n = sol.t().len();
+    assert_eq!(sol.y().cols(), 6);
+    assert_eq!(sol.state(0), y0);
+    assert_eq!(sol.y().row(0), arr1(&[0., 1., 2., 3., 4., 5.]));
+    let end = sol.state(n - 1);
+    assert!((&end - &(&y0 * (-2f64).exp()))
+        .iter()
+        .all(|d| d.abs() < 1e-9));
+    let mid = sol.eval(0.5).unwrap();
+    assert_eq!(mid.dim(), (2, 3));
+    assert!((&mid - &(&y0 * (-1f64).exp()))
+        .iter()
+        .all(|d| d.abs() < 1e-9));
+}
+
+#[test]
+fn backwards_in_time() {
+    let sol = Dopri5::new()
+        .rtol(1e-9)
+        .atol(1e-12)
+        .solve(|_t, y, mut dy| dy[0] = y[0], (1., -1.), &arr1(&[1.]))
+        .unwrap();
+    assert_eq!(sol.t()[sol.t().len() - 1], -1.);
+    assert!(sol.t().windows(2).into_iter().all(|w| w[0] > w[1]));
+    assert!((sol.eval(0.).unwrap()[0] - (-1f64).exp()).abs() < 1e-9);
+    assert_eq!(sol.eval(1.5), Err(OdeError::OutOfRange));
+
+    let sol = Rk4::new(10).solve(|_t, y, mut dy| dy[0] = y[0], (1., -1.), &arr1(&[1.]));
+    assert!((sol.state(10)[0] - (-2f64).exp()).abs() < 1e-5);
+}
+
+#[test]
+fn empty_interval() {
+    let sol = Dopri5::new()
+        .solve(oscillator, (2., 2.), &arr1(&[1., 2.]))
+        .unwrap();
+    assert_eq!(sol.t(), &arr1(&[2.]));
+    assert_eq!(sol.eval(2.).unwrap(), arr1(&[1., 2.]));
+}
+
+#[test]
+fn rk4_empty_interval() {
+    let sol = Rk4::new(10).solve(oscillator, (2., 2.), &arr1(&[1., 2.]));
+    assert_eq!(sol.t(), &arr1(&[2.]));
+    assert_eq!(sol.eval(2.).unwrap(), arr1(&[1., 2.]));
+}
+
+#[test]
+fn solver_errors() {
+    // y' = y² blows up at t = 1
+    let blow_up =
+        |_t: f64, y: ArrayView1<'_, f64>, mut dy: ArrayViewMut1<'_, f64>| dy[0] = y[0] * y[0];
+    assert_eq!(
+        Dopri5::new()
+            .solve(blow_up, (0., 2.), &arr1(&[1.]))
+            .unwrap_err(),
+        OdeError::StepSizeTooSmall
+    );
+    assert_eq!(
+        Dopri5::new()
+            .max_steps(3)
+            .solve(oscillator, (0., 100.), &arr1(&[0., 1.]))
+            .unwrap_err(),
+        OdeError::TooManySteps
+    );
+}
+
+#[test]
+#[should_panic]
+fn rk4_zero_steps() {
+    Rk4::new(0);
+}
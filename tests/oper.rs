@@ -5,7 +5,9 @@
     clippy::many_single_char_names
 )]
 
-use ndarray::linalg::general_mat_mul;
+use ndarray::linalg::{
+    general_mat_mul, general_mat_mul_trans, general_mat_mul_widening, Transpose,
+};
 use ndarray::prelude::*;
 use ndarray::{rcarr1, rcarr2};
 use ndarray::{Data, LinalgScalar};
@@ -14,6 +16,7 @@ use std::iter::FromIterator;
 
 use approx::assert_abs_diff_eq;
 use defmac::defmac;
+use num_complex::Complex;
 use std::ops::Neg;
 
 fn test_oper(op: &str, a: &[f32], b: &[f32], c: &[f32]) {
@@ -651,6 +654,171 @@ fn gen_mat_mul() {
     }
 }
 
+fn range_mat_complex(m: Ix, n: Ix) -> Array2<Complex<f64>> {
+    Array::from_shape_fn((m, n), |(i, j)| {
+        let x = (i * n + j) as f64;
+        Complex::new(x % 7. - 3., x % 5. - 2.)
+    })
+}
+
+#[test]
+fn gen_mat_mul_complex() {
+    let sizes = vec![
+        (4, 4, 4),
+        (17, 15, 16),
+        (4, 17, 3),
+        (19, 18, 2),
+        (33, 31, 34),
+    ];
+    let scalars = [
+        (Complex::new(1., 0.), Complex::new(0., 0.)),
+        (Complex::new(-2., 0.), Complex::new(3., 0.)),
+        (Complex::new(2., -1.), Complex::new(0.5, 1.5)),
+    ];
+    // test different strides
+    for &s1 in &[1, 2, -1, -2] {
+        for &s2 in &[1, 2, -1, -2] {
+            for &(m, k, n) in &sizes {
+                for &(alpha, beta) in &scalars {
+                    let a = range_mat_complex(m, k);
+                    let b = range_mat_complex(k, n);
+                    let mut c = range_mat_complex(m, n);
+                    let mut answer = c.clone();
+
+                    {
+                        let a = a.slice(s![..;s1, ..;s2]);
+                        let b = b.slice(s![..;s2, ..;s2]);
+                        let mut cv = c.slice_mut(s![..;s1, ..;s2]);
+
+                        let answer_part =
+                            reference_mat_mul(&a, &b).mapv(|x| alpha * x) + cv.mapv(|x| beta * x);
+                        answer.slice_mut(s![..;s1, ..;s2]).assign(&answer_part);
+
+                        general_mat_mul(alpha, &a, &b, beta, &mut cv);
+                    }
+                    assert_eq!(c, answer);
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn gen_mat_mul_trans() {
+    let conj_t = |a: &Array2<Complex<f64>>| a.t().mapv(|x| x.conj());
+    let (alpha, beta) = (Complex::new(1., 2.), Complex::new(-1., 0.));
+    for &(m, k, n) in &[(3, 4, 5), (20, 17, 18), (16, 16, 16)] {
+        let a = range_mat_complex(k, m);
+        let b = range_mat_complex(n, k);
+        let c0 = range_mat_complex(m, n);
+        let cases = [
+            (
+                Transpose::Trans,
+                Transpose::Trans,
+                a.t().to_owned(),
+                b.t().to_owned(),
+            ),
+            (
+                Transpose::ConjTrans,
+                Transpose::Trans,
+                conj_t(&a),
+                b.t().to_owned(),
+            ),
+            (
+                Transpose::Trans,
+                Transpose::ConjTrans,
+                a.t().to_owned(),
+                conj_t(&b),
+            ),
+            (
+                Transpose::ConjTrans,
+                Transpose::ConjTrans,
+                conj_t(&a),
+                conj_t(&b),
+            ),
+        ];
+        for &(ta, tb, ref opa, ref opb) in &cases {
+            let mut c = c0.clone();
+            general_mat_mul_trans(alpha, &a, ta, &b, tb, beta, &mut c);
+            let answer = reference_mat_mul(opa, opb).mapv(|x| alpha * x) + c0.mapv(|x| beta * x);
+            assert_eq!(c, answer);
+        }
+        // mixed with an operand used as it is
+        let a = range_mat_complex(m, k);
+        let mut c = c0.clone();
+        general_mat_mul_trans(
+            alpha,
+            &a,
+            Transpose::NoTrans,
+            &b,
+            Transpose::ConjTrans,
+            beta,
+            &mut c,
+        );
+        let answer = reference_mat_mul(&a, &conj_t(&b)).mapv(|x| alpha * x) + c0.mapv(|x| beta * x);
+        assert_eq!(c, answer);
+    }
+    // real elements: a conjugate transpose is a transpose
+    let a = range_mat64(5, 3);
+    let mut c = Array2::zeros((3, 3));
+    general_mat_mul_trans(
+        1.,
+        &a,
+        Transpose::ConjTrans,
+        &a,
+        Transpose::NoTrans,
+        0.,
+        &mut c,
+    );
+    assert_eq!(c, a.t().dot(&a));
+}
+
+#[test]
+fn dot_and_vdot_complex() {
+    for &n in &[0, 3, 40] {
+        let a = range_mat_complex(1, n).into_shape(n).unwrap();
+        let b = range_mat_complex(n, 1)
+            .into_shape(n)
+            .unwrap()
+            .mapv(|x| x * 2.);
+        let dot = a
+            .iter()
+            .zip(&b)
+            .fold(Complex::new(0., 0.), |acc, (x, y)| acc + x * y);
+        let vdot = a
+            .iter()
+            .zip(&b)
+            .fold(Complex::new(0., 0.), |acc, (x, y)| acc + x.conj() * y);
+        assert_eq!(a.dot(&b), dot);
+        assert_eq!(a.vdot(&b), vdot);
+        let rev = a.slice(s![..;-1]);
+        let vdot_rev = rev
+            .iter()
+            .zip(&b)
+            .fold(Complex::new(0., 0.), |acc, (x, y)| acc + x.conj() * y);
+        assert_eq!(rev.vdot(&b), vdot_rev);
+    }
+    let x = range_mat64(1, 10).into_shape(10).unwrap();
+    assert_eq!(x.vdot(&x), x.dot(&x));
+}
+
+#[test]
+fn gen_mat_vec_mul_complex() {
+    use ndarray::linalg::general_mat_vec_mul;
+    let (alpha, beta) = (Complex::new(0., 1.), Complex::new(2., 0.));
+    for &(m, k) in &[(4, 5), (30, 20)] {
+        let a = range_mat_complex(m, k);
+        let x = range_mat_complex(k, 1);
+        let y0 = range_mat_complex(m, 1);
+        for a in &[a.view(), a.slice(s![..;-1, ..])] {
+            let mut y = y0.column(0).to_owned();
+            general_mat_vec_mul(alpha, a, &x.column(0), beta, &mut y);
+            let answer = reference_mat_mul(a, &x).mapv(|v| alpha * v) + y0.mapv(|v| beta * v);
+            assert_eq!(y, answer.column(0));
+        }
+    }
+}
+
 // Test y = A x where A is f-order
 #[cfg(feature = "approx")]
 #[test]
@@ -691,6 +859,82 @@ fn gen_mat_mul_i32() {
     }
 }
 
+#[test]
+fn gen_mat_mul_int_blocked() {
+    // sizes that span several blocks of the integer kernel
+    for &(m, k, n) in &[(70, 130, 260), (5, 300, 3), (1, 1, 513)] {
+        let a = Array::from_shape_fn((m, k), |(i, j)| ((i * 7 + j * 3) % 11) as i64 - 5);
+        let b = Array::from_shape_fn((n, k), |(i, j)| ((i * 5 + j) % 13) as i64 - 6);
+        let b = b.t();
+        let mut c = Array::from_shape_fn((m, n), |(i, j)| (i + j) as i64);
+        let answer = 3 * reference_mat_mul(&a, &b) - &c;
+        general_mat_mul(3, &a, &b, -1, &mut c);
+        assert_eq!(c, answer);
+
+        let av = a.slice(s![..;2, ..;-1]);
+        let bv = b.slice(s![..;-1, ..;3]);
+        assert_eq!(av.dot(&bv), reference_mat_mul(&av, &bv));
+    }
+    let a = Array::from_shape_fn((9, 33), |(i, j)| ((i + 2 * j) % 5) as u16);
+    let b = Array::from_shape_fn((33, 7), |(i, j)| ((3 * i + j) % 4) as u16);
+    assert_eq!(a.dot(&b), reference_mat_mul(&a, &b));
+}
+
+#[test]
+fn gen_mat_mul_int_wrapping() {
+    let a = arr2(&[[200u8, 3], [17, 255]]);
+    let b = arr2(&[[2u8, 100], [90, 1]]);
+    let mut answer = Array2::<u8>::zeros((2, 2));
+    for ((i, j), x) in answer.indexed_iter_mut() {
+        for p in 0..2 {
+            *x = x.wrapping_add(a[(i, p)].wrapping_mul(b[(p, j)]));
+        }
+    }
+    assert_eq!(a.dot(&b), answer);
+
+    let a = Array2::from_elem((3, 3), i32::max_value());
+    let mut c = Array2::from_elem((3, 3), 1);
+    general_mat_mul(2, &a, &a, 5, &mut c);
+    let x = i32::max_value();
+    let expected = x
+        .wrapping_mul(x)
+        .wrapping_mul(3)
+        .wrapping_mul(2)
+        .wrapping_add(5);
+    assert_eq!(c, Array2::from_elem((3, 3), expected));
+}
+
+#[test]
+fn gen_mat_mul_int_widening() {
+    let a = Array::from_shape_fn((20, 150), |(i, j)| {
+        (((i * 31 + j * 17) % 256) as i32 - 128) as i8
+    });
+    let b = Array::from_shape_fn((150, 9), |(i, j)| {
+        (((i * 13 + j * 29) % 256) as i32 - 128) as i8
+    });
+    let wide = |x: &i8| i32::from(*x);
+    let answer = reference_mat_mul(&a.map(wide), &b.map(wide));
+    let mut c = Array2::<i32>::ones((20, 9));
+    general_mat_mul_widening(1, &a, &b, 0, &mut c);
+    assert_eq!(c, answer);
+    general_mat_mul_widening(
+        -2,
+        &a.slice(s![..;2, ..]),
+        &b,
+        1,
+        &mut c.slice_mut(s![..;2, ..]),
+    );
+    assert_eq!(
+        c.slice(s![..;2, ..]),
+        answer.slice(s![..;2, ..]).mapv(|x| -x)
+    );
+
+    let a = Array2::from_elem((2, 300), 255u8);
+    let mut c = Array2::<u32>::zeros((2, 2));
+    general_mat_mul_widening(1, &a, &a.t(), 0, &mut c);
+    assert_eq!(c, Array2::from_elem((2, 2), 255 * 255 * 300));
+}
+
 #[cfg(feature = "approx")]
 #[test]
 fn gen_mat_vec_mul() {
@@ -2,6 +2,7 @@
 
 use ndarray::parallel::prelude::*;
 use ndarray::prelude::*;
+use ndarray::Zip;
 
 const M: usize = 1024 * 10;
 const N: usize = 100;
@@ -53,3 +54,229 @@ fn test_regular_iter_collect() {
     let v = a.view().into_par_iter().map(|&x| x).collect::<Vec<_>>();
     assert_eq!(v.len(), a.len());
 }
+
+#[test]
+fn test_par_convolve() {
+    use ndarray::convolution::Mode;
+    let a = Array::from_shape_fn((64, 80), |(i, j)| ((i * 7 + j * 3) % 11) as i64 - 5);
+    let k = Array::from_shape_fn((5, 3), |(i, j)| (i * 3 + j) as i64 - 7);
+    for &mode in &[Mode::Full, Mode::Same, Mode::Valid] {
+        assert_eq!(a.par_convolve(&k, mode), a.convolve(&k, mode));
+        assert_eq!(a.par_correlate(&k, mode), a.correlate(&k, mode));
+    }
+}
+
+#[test]
+fn test_par_windows() {
+    let a = Array::from_shape_fn((64, 48), |(i, j)| (i * 48 + j) as f64);
+    let w = a.windows_with_stride((3, 4), (2, 3));
+    let seq: f64 = w.clone().into_iter().map(|w| w.sum()).sum();
+    let par: f64 = w.clone().into_par_iter().map(|w| w.sum()).sum();
+    assert_eq!(par, seq);
+    assert_eq!(w.into_par_iter().count(), 31 * 15);
+
+    let rows: Vec<_> = a
+        .axis_windows(Axis(0), 5)
+        .into_par_iter()
+        .map(|w| w.sum())
+        .collect();
+    assert_eq!(rows.len(), 60);
+    assert_eq!(rows[7], a.slice(s![7..12, ..]).sum());
+}
+
+#[test]
+fn test_par_chunks() {
+    let a = Array::from_shape_fn((100, 37), |(i, j)| (i * 37 + j) as f64);
+    let sums: Vec<f64> = a.chunks((8, 5)).into_par_iter().map(|c| c.sum()).collect();
+    assert_eq!(sums.len(), 13 * 8);
+    assert_eq!(sums.iter().sum::<f64>(), a.sum());
+
+    let mut b = Array2::<f64>::zeros((100, 37));
+    b.chunks_mut((8, 5))
+        .into_par_iter()
+        .for_each(|mut c| c.fill(c.len() as f64));
+    assert_eq!(b[[99, 36]], 4. * 2.);
+    assert_eq!(b[[0, 0]], 40.);
+
+    // chunks of several arrays in lock step
+    let mut c = Array2::<f64>::zeros((100, 37));
+    Zip::from(c.chunks_mut((8, 5)))
+        .and(a.chunks((8, 5)))
+        .and(b.chunks((8, 5)))
+        .par_apply(|mut c, a, b| c.assign(&(&a + &b)));
+    assert_eq!(c, &a + &b);
+}
+
+#[test]
+fn test_par_sum_mean() {
+    let a = Array::from_shape_fn((M, N), |(i, j)| ((i * N + j) % 1000) as i64);
+    assert_eq!(a.par_sum(), a.sum());
+    assert_eq!(a.t().par_sum(), a.sum());
+    assert_eq!(
+        a.slice(s![..;-3, 1..;2]).par_sum(),
+        a.slice(s![..;-3, 1..;2]).sum()
+    );
+    assert_eq!(Array2::<f64>::zeros((0, 3)).par_sum(), 0.);
+
+    let b = Array::linspace(0f64, 1., M);
+    assert!((b.par_mean().unwrap() - 0.5).abs() < 1e-12);
+    assert_eq!(Array1::<f64>::zeros(0).par_mean(), None);
+}
+
+#[test]
+fn test_par_fold_axis() {
+    let a = Array::from_shape_fn((M, 7), |(i, j)| (i + j) as i64);
+    for &axis in &[Axis(0), Axis(1)] {
+        let fold = |acc: &i64, &x: &i64| acc * 3 % 1_000_003 + x;
+        assert_eq!(a.par_fold_axis(axis, 1, fold), a.fold_axis(axis, 1, fold));
+    }
+    let e = Array2::<i64>::zeros((0, 4));
+    assert_eq!(e.par_fold_axis(Axis(0), 5, |a, b| a + b), arr1(&[5; 4]));
+}
+
+#[test]
+fn test_par_map_axis() {
+    let a = Array::from_shape_fn((M, 3, 4), |(i, j, k)| (i * 12 + j * 4 + k) as f64);
+    for axis in 0..3 {
+        let axis = Axis(axis);
+        assert_eq!(
+            a.par_map_axis(axis, |lane| lane.sum()),
+            a.map_axis(axis, |lane| lane.sum())
+        );
+    }
+    let e = Array2::<f64>::zeros((3, 0));
+    assert_eq!(e.par_map_axis(Axis(1), |lane| lane.len()), arr1(&[0; 3]));
+}
+
+#[test]
+fn test_par_sum_reproducible() {
+    let a = Array::from_shape_fn((M, N), |(i, j)| ((i * N + j) as f64 * 0.37).sin() * 1e3);
+    let views = [a.view(), a.t(), a.slice(s![..;-3, 1..;2])];
+    for v in &views {
+        let sums: Vec<_> = [1, 2, 3, 8]
+            .iter()
+            .map(|&n| {
+                let pool = rayon::ThreadPoolBuilder::new()
+                    .num_threads(n)
+                    .build()
+                    .unwrap();
+                pool.install(|| (0..4).map(|_| v.par_sum_reproducible()).collect::<Vec<_>>())
+            })
+            .flatten()
+            .collect();
+        assert!(sums.iter().all(|s| s.to_bits() == sums[0].to_bits()));
+        assert!((sums[0] - v.sum()).abs() < 1e-6 * v.sum().abs().max(1.));
+    }
+    assert_eq!(Array2::<f64>::zeros((0, 3)).par_sum_reproducible(), 0.);
+    assert_eq!(Array1::<f64>::zeros(0).par_mean_reproducible(), None);
+    let b = Array::linspace(0f64, 1., M);
+    assert!((b.par_mean_reproducible().unwrap() - 0.5).abs() < 1e-12);
+}
+
+/// Return the lengths of the pieces that `iter` is split into.
+fn piece_lengths<I: ParallelIterator>(iter: I) -> Vec<usize> {
+    iter.fold(|| 0, |n, _| n + 1).collect()
+}
+
+#[test]
+fn test_par_split_len() {
+    let a = Array2::<f64>::zeros((M, N));
+    let pool = rayon::ThreadPoolBuilder::new()
+        .num_threads(4)
+        .build()
+        .unwrap();
+    pool.install(|| {
+        let lens = piece_lengths(a.view().into_par_iter().with_min_len(M * N / 4));
+        assert!(lens.len() <= 4);
+        assert_eq!(lens.iter().sum::<usize>(), M * N);
+
+        let lens = piece_lengths(a.view().into_par_iter().with_max_len(1000));
+        assert!(lens.iter().all(|&n| n <= 1000));
+        assert_eq!(lens.iter().sum::<usize>(), M * N);
+
+        let lens = piece_lengths(a.axis_iter(Axis(0)).into_par_iter().with_max_len(7));
+        assert!(lens.iter().all(|&n| n <= 7));
+        assert_eq!(lens.iter().sum::<usize>(), M);
+
+        let lens = piece_lengths(a.chunks((10, 10)).into_par_iter().with_min_len(8));
+        assert!(lens.iter().all(|&n| n >= 8));
+        assert_eq!(lens.iter().sum::<usize>(), M / 10 * N / 10);
+    });
+    // the maximum length splits even when there is a single thread
+    let pool = rayon::ThreadPoolBuilder::new()
+        .num_threads(1)
+        .build()
+        .unwrap();
+    pool.install(|| {
+        let mut b = a.clone();
+        let lens = piece_lengths(b.view_mut().into_par_iter().with_max_len(M));
+        assert!(lens.iter().all(|&n| n <= M));
+        assert!(lens.len() >= N);
+    });
+}
+
+#[test]
+fn test_par_small_array_sequential() {
+    use std::collections::HashSet;
+    use std::sync::Mutex;
+
+    let pool = rayon::ThreadPoolBuilder::new()
+        .num_threads(4)
+        .build()
+        .unwrap();
+    let mut a = Array::linspace(0., 1., 1000);
+    let threads = Mutex::new(HashSet::new());
+    pool.install(|| {
+        a.par_map_inplace(|x| {
+            threads
+                .lock()
+                .unwrap()
+                .insert(rayon::current_thread_index());
+            *x *= 2.;
+        })
+    });
+    assert_eq!(threads.into_inner().unwrap().len(), 1);
+    assert_eq!(a[999], 2.);
+}
+
+#[test]
+fn test_par_zip_few_heavy_items() {
+    use std::collections::HashSet;
+    use std::sync::Mutex;
+    use std::thread::sleep;
+    use std::time::Duration;
+
+    let pool = rayon::ThreadPoolBuilder::new()
+        .num_threads(4)
+        .build()
+        .unwrap();
+    let a = Array2::<f64>::ones((8, 10_000));
+    let mut sums = Array1::<f64>::zeros(8);
+    let threads = Mutex::new(HashSet::new());
+    pool.install(|| {
+        Zip::from(&mut sums).and(a.genrows()).par_apply(|s, row| {
+            threads
+                .lock()
+                .unwrap()
+                .insert(rayon::current_thread_index());
+            sleep(Duration::from_millis(20));
+            *s = row.sum();
+        })
+    });
+    assert!(threads.into_inner().unwrap().len() > 1);
+    assert_eq!(sums, Array1::from_elem(8, 10_000.));
+
+    let threads = Mutex::new(HashSet::new());
+    let sums = pool.install(|| {
+        a.par_map_axis(Axis(1), |row| {
+            threads
+                .lock()
+                .unwrap()
+                .insert(rayon::current_thread_index());
+            sleep(Duration::from_millis(20));
+            row.sum()
+        })
+    });
+    assert!(threads.into_inner().unwrap().len() > 1);
+    assert_eq!(sums, Array1::from_elem(8, 10_000.));
+}
@@ -1,5 +1,6 @@
 #![cfg(feature = "rayon")]
 
+use ndarray::parallel::prelude::*;
 use ndarray::prelude::*;
 
 use ndarray::Zip;
@@ -70,3 +71,147 @@ fn test_zip_index_4() {
         assert_eq!(*elt, j);
     }
 }
+
+#[test]
+fn test_zip_map_collect() {
+    let a = Array::from_shape_fn((M, N), |(i, j)| (i * N + j) as f64);
+    let b = Array::from_shape_fn((M, N), |(i, j)| (i + j) as f64);
+    let c = Zip::from(&a).and(&b).par_map_collect(|&a, &b| a + b);
+    assert_eq!(c, &a + &b);
+
+    let ct = Zip::from(a.t()).and(b.t()).par_map_collect(|&a, &b| a + b);
+    assert_eq!(ct, c.t());
+    assert!(ct.t().is_standard_layout());
+
+    let s = Zip::indexed(&a).par_map_collect(|(i, j), x| format!("{} {} {}", i, j, x));
+    assert_eq!(s[[M - 1, 3]], format!("{} 3 {}", M - 1, a[[M - 1, 3]]));
+}
+
+#[test]
+fn test_zip_map_collect2() {
+    let a = Array::from_shape_fn((M, N), |(i, j)| (i * N + j) as i64);
+    let (q, r) = Zip::from(&a).par_map_collect2(|&a| (a / 7, a % 7));
+    assert_eq!(q, Zip::from(&a).map_collect(|&a| a / 7));
+    assert_eq!(r, Zip::from(&a).map_collect(|&a| a % 7));
+}
+
+#[test]
+fn test_zip_par_fold() {
+    let a = Array::from_shape_fn((M, N), |(i, j)| (i + j) as i64);
+    let b = Array::from_shape_fn((M, N), |(i, j)| (i % 7 + j) as i64);
+    let dot = Zip::from(&a)
+        .and(&b)
+        .par_fold(|| 0, |acc, &a, &b| acc + a * b, |x, y| x + y);
+    assert_eq!(
+        dot,
+        Zip::from(&a).and(&b).fold(0, |acc, &a, &b| acc + a * b)
+    );
+
+    let count = Zip::indexed(&a).par_fold(
+        Vec::new,
+        |mut v, (i, j), &x| {
+            if x == 5 {
+                v.push((i, j));
+            }
+            v
+        },
+        |mut v, w| {
+            v.extend(w);
+            v
+        },
+    );
+    assert_eq!(count.len(), 6);
+
+    let e = Array2::<i64>::zeros((0, N));
+    assert_eq!(
+        Zip::from(&e).par_fold(|| 0, |acc, &x| acc + x, |x, y| x + y),
+        0
+    );
+}
+
+#[test]
+fn test_zip_par_all_any() {
+    let a = Array::from_shape_fn((M, N), |(i, j)| (i * N + j) as i64);
+    assert!(Zip::from(&a).par_all(|&x| x >= 0));
+    assert!(!Zip::from(&a).par_all(|&x| x != 4321));
+    assert!(Zip::from(&a).and(a.t().t()).par_all(|x, y| x == y));
+    assert!(Zip::from(&a).par_any(|&x| x == 4321));
+    assert!(!Zip::from(&a).par_any(|&x| x < 0));
+
+    let e = Array2::<i64>::zeros((0, N));
+    assert!(Zip::from(&e).par_all(|_| false));
+    assert!(!Zip::from(&e).par_any(|_| true));
+}
+
+#[test]
+fn test_zip_par_fold_reproducible() {
+    let a = Array::from_shape_fn((M, N), |(i, j)| ((i * N + j) as f64).cos());
+    let b = Array::from_shape_fn((M, N).f(), |(i, j)| ((i + 7 * j) as f64).sin());
+    let dot = |n| {
+        let pool = rayon::ThreadPoolBuilder::new()
+            .num_threads(n)
+            .build()
+            .unwrap();
+        pool.install(|| {
+            Zip::from(&a).and(&b).par_fold_reproducible(
+                || 0.,
+                |acc, &a, &b| acc + a * b,
+                |x, y| x + y,
+            )
+        })
+    };
+    let expected = dot(1);
+    for &n in &[1, 2, 4, 7] {
+        assert_eq!(dot(n).to_bits(), expected.to_bits());
+    }
+    let seq = Zip::from(&a).and(&b).fold(0., |acc, &a, &b| acc + a * b);
+    assert!((expected - seq).abs() < 1e-6);
+
+    let e = Array2::<f64>::zeros((0, N));
+    let sum = Zip::from(&e).par_fold_reproducible(|| 0., |acc, &x| acc + x, |x, y| x + y);
+    assert_eq!(sum, 0.);
+}
+
+#[test]
+fn test_zip_par_split_len() {
+    let a = Array2::<f64>::zeros((M, N));
+    let b = Array2::<f64>::zeros((M, N));
+    let pieces = |min_len, max_len| -> Vec<usize> {
+        Zip::from(&a)
+            .and(&b)
+            .into_par_iter()
+            .with_min_len(min_len)
+            .with_max_len(max_len)
+            .fold(|| 0, |n, _| n + 1)
+            .collect()
+    };
+    let lens = pieces(M * N / 8, usize::max_value());
+    assert!(lens.len() <= 8);
+    assert!(lens.iter().all(|&n| n >= M * N / 8));
+    let lens = pieces(1, 2 * N);
+    assert!(lens.iter().all(|&n| n <= 2 * N));
+    assert_eq!(lens.iter().sum::<usize>(), M * N);
+}
+
+#[test]
+fn test_zip_par_apply_in_pool() {
+    use std::sync::atomic::{AtomicBool, Ordering};
+
+    let pool = rayon::ThreadPoolBuilder::new()
+        .num_threads(3)
+        .build()
+        .unwrap();
+    let mut a = Array2::<usize>::zeros((M, N));
+    let outside = AtomicBool::new(false);
+    pool.install(|| {
+        Zip::indexed(&mut a).par_apply(|(i, _), x| {
+            match rayon::current_thread_index() {
+                Some(t) if t < 3 => {}
+                _ => outside.store(true, Ordering::Relaxed),
+            }
+            *x = i;
+        })
+    });
+    assert!(!outside.load(Ordering::Relaxed));
+    assert_eq!(a[[M - 1, 0]], M - 1);
+}
@@ -0,0 +1,119 @@
+use ndarray::linalg::LinalgError;
+use ndarray::polynomial::{polyder, polyfit, polyint, polyval, vander};
+use ndarray::prelude::*;
+use ndarray::stack;
+use num_complex::Complex;
+
+#[test]
+fn vandermonde() {
+    let x = arr1(&[2., -1., 0.5]);
+    let v = vander(&x, 4);
+    assert_eq!(
+        v,
+        arr2(&[[8., 4., 2., 1.], [-1., 1., -1., 1.], [0.125, 0.25, 0.5, 1.]])
+    );
+    assert_eq!(vander(&x, 0).dim(), (3, 0));
+    assert_eq!(vander(&x.slice(s![..;-1]), 1), Array2::ones((3, 1)));
+    // the product with the coefficients evaluates the polynomial
+    let p = arr1(&[1., 0., -2., 3.]);
+    assert_eq!(v.dot(&p), polyval(&p, &x));
+}
+
+#[test]
+fn evaluate() {
+    let p = arr1(&[3, -2, 0, 5]);
+    let x = Array::from_shape_fn((2, 3, 2), |(i, j, k)| {
+        i as i64 * 6 + j as i64 * 2 + k as i64 - 5
+    });
+    let v = polyval(&p, &x);
+    for (&xi, &vi) in x.iter().zip(&v) {
+        assert_eq!(vi, 3 * xi * xi * xi - 2 * xi * xi + 5);
+    }
+    assert_eq!(
+        polyval(&Array1::<i64>::zeros(0), &arr1(&[1, 2])),
+        arr1(&[0, 0])
+    );
+    assert_eq!(polyval(&arr1(&[7.]), &arr1(&[1., 2.]).t()), arr1(&[7., 7.]));
+
+    let i = Complex::new(0., 1.);
+    let p = arr1(&[
+        Complex::new(1., 0.),
+        Complex::new(0., 0.),
+        Complex::new(1., 0.),
+    ]);
+    assert_eq!(polyval(&p, &arr1(&[i]))[0], Complex::new(0., 0.));
+}
+
+#[test]
+fn derivative_and_antiderivative() {
+    let p = arr1(&[4f64, 3., -2., 1., 7.]);
+    assert_eq!(polyder(&p, 0), p);
+    assert_eq!(polyder(&p, 1), arr1(&[16., 9., -4., 1.]));
+    assert_eq!(polyder(&p, 2), arr1(&[48., 18., -4.]));
+    assert_eq!(polyder(&p, 4), arr1(&[96.]));
+    assert_eq!(polyder(&p, 5), arr1(&[0.]));
+    assert_eq!(polyder(&p, 9), arr1(&[0.]));
+    assert_eq!(polyder(&arr1(&[1, 1, 1, 1]), 2), arr1(&[6, 2]));
+
+    assert_eq!(polyint(&p, 0), p);
+    assert_eq!(polyint(&arr1(&[3., 2., 1.]), 1), arr1(&[1., 1., 1., 0.]));
+    assert_eq!(polyint(&arr1(&[6.]), 2), arr1(&[3., 0., 0.]));
+    assert_eq!(polyint(&Array1::<f64>::zeros(0), 1), arr1(&[0.]));
+    let i = Complex::new(0., 1.);
+    let zero = Complex::new(0., 0.);
+    assert_eq!(polyint(&arr1(&[2. * i, i]), 1), arr1(&[i, i, zero]));
+    for m in 0..4 {
+        let q = polyder(&polyint(&p, m), m);
+        assert!((&q - &p).iter().all(|d| d.abs() < 1e-12), "m = {}", m);
+    }
+}
+
+#[test]
+fn fit() {
+    let p = arr1(&[0.5f64, -1., 2., 3.]);
+    let x = Array::linspace(-2., 3., 20);
+    let y = polyval(&p, &x);
+    let fit = polyfit(&x, &y, 3).unwrap();
+    assert!((&fit - &p).iter().all(|d| d.abs() < 1e-10), "{}", fit);
+
+    // a least squares line through noisy points is the regression line
+    let x = arr1(&[0f64, 1., 2., 3.]);
+    let y = arr1(&[1., 3., 2., 5.]);
+    let line = polyfit(&x, &y, 1).unwrap();
+    assert!((line[0] - 1.1).abs() < 1e-12 && (line[1] - 1.1).abs() < 1e-12);
+
+    // several data sets at once
+    let ys = stack![
+        Axis(1),
+        y.view().insert_axis(Axis(1)),
+        (&y * 2.).insert_axis(Axis(1))
+    ];
+    let lines = polyfit(&x, &ys, 1).unwrap();
+    assert_eq!(lines.dim(), (2, 2));
+    assert!((&lines.column(1) - &(&line * 2.))
+        .iter()
+        .all(|d| d.abs() < 1e-12));
+
+    // badly scaled coordinates
+    let x = Array::linspace(1000f64, 1010., 11);
+    let p = arr1(&[1e-3, -2., 5.]);
+    let fit = polyfit(&x, &polyval(&p, &x), 2).unwrap();
+    assert!((polyval(&fit, &x) - polyval(&p, &x))
+        .iter()
+        .all(|d| d.abs() < 1e-6));
+
+    // more coefficients than points interpolates
+    let x = arr1(&[0f64, 1.]);
+    let fit = polyfit(&x, &arr1(&[1., 2.]), 3).unwrap();
+    assert!((polyval(&fit, &x) - arr1(&[1., 2.]))
+        .iter()
+        .all(|d| d.abs() < 1e-12));
+}
+
+#[test]
+fn fit_errors() {
+    match polyfit(&arr1(&[0., 1., 2.]), &arr1(&[0., 1.]), 1) {
+        Err(LinalgError::Shape(_)) => {}
+        other => panic!("unexpected {:?}", other),
+    }
+}
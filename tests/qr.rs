@@ -0,0 +1,126 @@
+#![cfg(feature = "approx")]
+
+use ndarray::linalg::QRMode;
+use ndarray::prelude::*;
+use num_complex::Complex;
+
+use approx::assert_abs_diff_eq;
+
+mod common;
+use common::test_matrix;
+
+fn assert_orthonormal_columns(q: &Array2<f64>) {
+    let n = q.cols();
+    assert_abs_diff_eq!(q.t().dot(q), Array::eye(n), epsilon = 1e-12);
+}
+
+fn assert_upper_triangular(r: &Array2<f64>) {
+    for ((i, j), &x) in r.indexed_iter() {
+        if j < i {
+            assert_eq!(x, 0.);
+        }
+    }
+}
+
+#[test]
+fn qr_reduced_and_complete() {
+    for &(m, n) in &[(1, 1), (5, 3), (3, 5), (40, 40), (100, 70), (70, 100)] {
+        let a = test_matrix(m, n);
+        let qr = a.qr();
+        let k = m.min(n);
+
+        let (q, r) = qr.q_r(QRMode::Reduced);
+        assert_eq!(q.dim(), (m, k));
+        assert_eq!(r.dim(), (k, n));
+        assert_orthonormal_columns(&q);
+        assert_upper_triangular(&r);
+        assert_abs_diff_eq!(q.dot(&r), a, epsilon = 1e-12);
+
+        let (q, r) = qr.q_r(QRMode::Complete);
+        assert_eq!(q.dim(), (m, m));
+        assert_eq!(r.dim(), (m, n));
+        assert_orthonormal_columns(&q);
+        assert_upper_triangular(&r);
+        assert_abs_diff_eq!(q.dot(&r), a, epsilon = 1e-12);
+    }
+}
+
+#[test]
+fn qr_complex() {
+    let a = Array::from_shape_fn((6, 4), |(i, j)| {
+        Complex::new((i + 2 * j) as f64 % 5. - 2., (i * j) as f64 % 3. - 1.)
+    });
+    let (q, r) = a.qr().q_r(QRMode::Reduced);
+    let qh = q.t().mapv(|x| x.conj());
+    let eye = qh.dot(&q);
+    for ((i, j), x) in eye.indexed_iter() {
+        let expected = if i == j { 1. } else { 0. };
+        assert!((x - expected).norm() < 1e-12);
+    }
+    for (x, y) in q.dot(&r).iter().zip(&a) {
+        assert!((x - y).norm() < 1e-12);
+    }
+}
+
+#[test]
+fn lstsq_overdetermined() {
+    let a = test_matrix(30, 4);
+    let x = arr1(&[1., -2., 0.5, 3.]);
+    let b = a.dot(&x);
+    let fit = a.lstsq(&b).unwrap();
+    assert_eq!(fit.rank, 4);
+    assert_abs_diff_eq!(fit.solution, x, epsilon = 1e-10);
+    assert_abs_diff_eq!(fit.residuals.unwrap().into_scalar(), 0., epsilon = 1e-20);
+
+    // the residual is orthogonal to the columns of A
+    let mut b = b;
+    b[0] += 1.;
+    let fit = a.lstsq(&b).unwrap();
+    let res = &b - &a.dot(&fit.solution);
+    assert_abs_diff_eq!(a.t().dot(&res), Array::zeros(4), epsilon = 1e-12);
+    assert_abs_diff_eq!(
+        fit.residuals.unwrap().into_scalar(),
+        res.dot(&res),
+        epsilon = 1e-12
+    );
+}
+
+#[test]
+fn lstsq_many_rhs() {
+    let a = test_matrix(20, 5);
+    let x = Array::from_shape_fn((5, 3), |(i, j)| (i * 3 + j) as f64);
+    let b = a.dot(&x);
+    let fit = a.lstsq(&b).unwrap();
+    assert_abs_diff_eq!(fit.solution, x, epsilon = 1e-10);
+    assert_eq!(fit.residuals.unwrap().dim(), 3);
+}
+
+#[test]
+fn lstsq_underdetermined_minimum_norm() {
+    let a = arr2(&[[1., 1.]]);
+    let fit = a.lstsq(&arr1(&[2.])).unwrap();
+    assert_eq!(fit.rank, 1);
+    assert_abs_diff_eq!(fit.solution, arr1(&[1., 1.]), epsilon = 1e-12);
+    assert!(fit.residuals.is_none());
+}
+
+#[test]
+fn lstsq_rank_deficient() {
+    // third column is the sum of the first two
+    let mut a = test_matrix(10, 3);
+    let c = &a.column(0) + &a.column(1);
+    a.column_mut(2).assign(&c);
+    let b = a.dot(&arr1(&[1., 1., 0.]));
+    let fit = a.lstsq(&b).unwrap();
+    assert_eq!(fit.rank, 2);
+    assert!(fit.residuals.is_none());
+    // minimum norm solution among x + t (1, 1, -1)
+    assert_abs_diff_eq!(fit.solution, arr1(&[1., 1., 2.]) / 3., epsilon = 1e-10);
+}
+
+#[test]
+fn lstsq_errors() {
+    let a = test_matrix(4, 2);
+    assert!(a.lstsq(&Array1::zeros(3)).is_err());
+    assert!(a.lstsq(&Array3::zeros((4, 1, 1))).is_err());
+}
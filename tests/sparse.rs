@@ -0,0 +1,194 @@
+use ndarray::linalg::Dot;
+use ndarray::prelude::*;
+use ndarray::sparse::{CooMatrix, CscMatrix, CsrMatrix};
+use ndarray::ErrorKind;
+
+fn example() -> Array2<i32> {
+    arr2(&[[1, 0, 0, 2], [0, 0, 0, 0], [0, 3, 4, 0], [5, 0, 6, 7]])
+}
+
+fn laplacian(n: usize) -> CooMatrix<f64> {
+    let mut a = CooMatrix::with_capacity((n, n), 3 * n);
+    for i in 0..n {
+        a.push(i, i, 2.);
+        if i > 0 {
+            a.push(i, i - 1, -1.);
+            a.push(i - 1, i, -1.);
+        }
+    }
+    a
+}
+
+#[test]
+fn coo_assembly() {
+    let mut a = CooMatrix::new((3, 2));
+    a.push(2, 1, 1);
+    a.push(0, 0, 2);
+    a.push(2, 1, 3);
+    a.push(1, 0, 4);
+    assert_eq!(a.nnz(), 4);
+    assert_eq!(a.row_indices(), arr1(&[2, 0, 2, 1]));
+    let dense = arr2(&[[2, 0], [4, 0], [0, 4]]);
+    assert_eq!(a.to_dense(), dense);
+
+    let csr = a.to_csr();
+    assert_eq!(csr.nnz(), 3);
+    assert_eq!(csr.indptr(), arr1(&[0, 1, 2, 3]));
+    assert_eq!(csr.indices(), arr1(&[0, 0, 1]));
+    assert_eq!(csr.data(), arr1(&[2, 4, 4]));
+    assert_eq!(csr.to_dense(), dense);
+
+    let csc = a.to_csc();
+    assert_eq!(csc.indptr(), arr1(&[0, 2, 3]));
+    assert_eq!(csc.indices(), arr1(&[0, 1, 2]));
+    assert_eq!(csc.to_dense(), dense);
+
+    let b = CooMatrix::from_triplets((3, 2), vec![2, 0, 2, 1], vec![1, 0, 1, 0], vec![1, 2, 3, 4])
+        .unwrap();
+    assert_eq!(a, b);
+    assert_eq!(CooMatrix::from_dense(&dense).to_dense(), dense);
+}
+
+#[test]
+fn coo_errors() {
+    let err = CooMatrix::from_triplets((2, 2), vec![0], vec![0, 1], vec![1.]).unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::IncompatibleShape);
+    let err = CooMatrix::from_triplets((2, 2), vec![0, 2], vec![0, 1], vec![1., 2.]).unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::OutOfBounds);
+}
+
+#[test]
+#[should_panic]
+fn coo_push_out_of_bounds() {
+    let mut a = CooMatrix::new((2, 2));
+    a.push(0, 2, 1.);
+}
+
+#[test]
+fn csr_new() {
+    let a = CsrMatrix::new((2, 3), vec![0, 2, 3], vec![0, 2, 1], vec![1, 2, 3]).unwrap();
+    assert_eq!(a.to_dense(), arr2(&[[1, 0, 2], [0, 3, 0]]));
+
+    let err = CsrMatrix::new((2, 3), vec![0, 2], vec![0, 2], vec![1, 2]).unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::IncompatibleShape);
+    let err = CsrMatrix::new((2, 3), vec![0, 2, 3], vec![0, 2, 1], vec![1, 2]).unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::IncompatibleShape);
+    let err = CsrMatrix::new((2, 3), vec![0, 2, 3], vec![0, 3, 1], vec![1, 2, 3]).unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::OutOfBounds);
+    let err = CsrMatrix::new((2, 3), vec![0, 2, 3], vec![2, 0, 1], vec![1, 2, 3]).unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::IncompatibleLayout);
+    let err = CscMatrix::new((2, 3), vec![0, 1, 1, 1], vec![0, 1], vec![1, 2]).unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::IncompatibleShape);
+}
+
+#[test]
+fn rows_columns_and_get() {
+    let dense = example();
+    let csr = CsrMatrix::from_dense(&dense);
+    let csc = CscMatrix::from_dense(&dense);
+    assert_eq!(csr.shape(), (4, 4));
+    assert_eq!(csr.nnz(), 7);
+    let (cols, values) = csr.row(3);
+    assert_eq!(cols, arr1(&[0, 2, 3]));
+    assert_eq!(values, arr1(&[5, 6, 7]));
+    assert_eq!(csr.row(1).0.len(), 0);
+    let (rows, values) = csc.column(2);
+    assert_eq!(rows, arr1(&[2, 3]));
+    assert_eq!(values, arr1(&[4, 6]));
+    for ((i, j), &x) in dense.indexed_iter() {
+        let expected = if x == 0 { None } else { Some(&x) };
+        assert_eq!(csr.get((i, j)), expected);
+        assert_eq!(csc.get((i, j)), expected);
+    }
+    assert_eq!(csr.get((4, 0)), None);
+}
+
+#[test]
+fn conversions() {
+    let dense = example();
+    let csr = CsrMatrix::from_dense(&dense);
+    let csc = csr.to_csc();
+    assert_eq!(csc, CscMatrix::from_dense(&dense));
+    assert_eq!(csc.to_csr(), csr);
+    assert_eq!(csr.to_coo().to_csr(), csr);
+    assert_eq!(csc.to_coo().to_dense(), dense);
+
+    assert_eq!(csr.transpose().to_dense(), dense.t());
+    assert_eq!(csc.transpose().to_dense(), dense.t());
+    assert_eq!(csr.clone().into_transpose().to_csr().to_dense(), dense.t());
+
+    // from a transposed view
+    assert_eq!(CsrMatrix::from_dense(&dense.t()).to_dense(), dense.t());
+    assert_eq!(CscMatrix::from_dense(&dense.t()).to_dense(), dense.t());
+
+    let f = csr.map(|&x| x as f64 / 2.);
+    assert_eq!(f.to_dense(), dense.mapv(|x| x as f64 / 2.));
+}
+
+#[test]
+fn dot_dense() {
+    let n = 7;
+    let a = laplacian(n);
+    let dense = a.to_dense();
+    let csr = a.to_csr();
+    let csc = a.to_csc();
+
+    let x = Array::linspace(1., 7., n);
+    assert_eq!(csr.dot(&x), dense.dot(&x));
+    assert_eq!(csc.dot(&x), dense.dot(&x));
+    let xs = Array::linspace(1., 14., 2 * n);
+    let xs = xs.slice(s![..;-2]);
+    assert_eq!(csr.dot(&xs), dense.dot(&xs));
+    assert_eq!(csc.dot(&xs), dense.dot(&xs));
+
+    let b = Array::from_shape_fn((n, 3), |(i, j)| (i * 3 + j) as f64);
+    assert_eq!(csr.dot(&b), dense.dot(&b));
+    assert_eq!(csc.dot(&b), dense.dot(&b));
+    let bt = Array::from_shape_fn((5, n), |(i, j)| (i + 2 * j) as f64);
+    assert_eq!(csr.dot(&bt.t()), dense.dot(&bt.t()));
+    assert_eq!(csc.dot(&bt.t()), dense.dot(&bt.t()));
+
+    let r = CsrMatrix::from_dense(&example());
+    let v = arr1(&[1, -1, 2, 3]);
+    assert_eq!(r.dot(&v), example().dot(&v));
+    assert_eq!(r.transpose().dot(&v), example().t().dot(&v));
+}
+
+#[test]
+#[should_panic]
+fn dot_shape_mismatch() {
+    let a = laplacian(3).to_csr();
+    a.dot(&Array1::<f64>::zeros(4));
+}
+
+#[test]
+fn elementwise() {
+    let x = example();
+    let y = arr2(&[[-1, 1, 0, 0], [0, 0, 0, 0], [0, 3, 0, 1], [0, 0, 6, 0]]);
+    let a = CsrMatrix::from_dense(&x);
+    let b = CsrMatrix::from_dense(&y);
+    let sum = &a + &b;
+    assert_eq!(sum.to_dense(), &x + &y);
+    // explicit zeros are not stored
+    assert_eq!(sum.nnz(), 8);
+    assert_eq!((&a - &b).to_dense(), &x - &y);
+    let prod = &a * &b;
+    assert_eq!(prod.to_dense(), &x * &y);
+    assert_eq!(prod.nnz(), 3);
+    assert_eq!((&a * 3).to_dense(), &x * 3);
+    assert_eq!((-&a).to_dense(), -&x);
+    assert_eq!((&a - &a).nnz(), 0);
+
+    let c = CscMatrix::from_dense(&x);
+    let d = CscMatrix::from_dense(&y);
+    assert_eq!((&c + &d).to_dense(), &x + &y);
+    assert_eq!((&c * &d).to_dense(), &x * &y);
+}
+
+#[test]
+#[should_panic]
+fn elementwise_shape_mismatch() {
+    let a = laplacian(3).to_csr();
+    let b = laplacian(4).to_csr();
+    let _ = &a + &b;
+}
@@ -1,4 +1,5 @@
-use ndarray::{arr2, aview1, stack, Array2, Axis, ErrorKind};
+use ndarray::prelude::*;
+use ndarray::{block, stack, ErrorKind};
 
 #[test]
 fn stacking() {
@@ -24,3 +25,67 @@ fn stacking() {
     let res: Result<Array2<f64>, _> = ndarray::stack(Axis(0), &[]);
     assert_eq!(res.unwrap_err().kind(), ErrorKind::Unsupported);
 }
+
+#[test]
+fn block_2d() {
+    let a = arr2(&[[1, 2], [3, 4]]);
+    let b = arr2(&[[5], [6]]);
+    let c = arr2(&[[7, 8, 9]]);
+    let m = block![[a, b], [c.slice(s![.., ..2]), c.slice(s![.., 2..])]];
+    assert_eq!(m, arr2(&[[1, 2, 5], [3, 4, 6], [7, 8, 9]]));
+    assert_eq!(block![a, a.t()], stack![Axis(0), a, a.t()]);
+    assert_eq!(block![[a], [b.t()]], arr2(&[[1, 2], [3, 4], [5, 6]]));
+}
+
+#[test]
+fn block_nd() {
+    let a = Array::from_shape_fn((2, 1, 3), |(i, j, k)| (i * 100 + j * 10 + k) as i32);
+    let b = a.mapv(|x| -x);
+    let m = block![[[a, b]], [[b, a]]];
+    assert_eq!(m.dim(), (4, 1, 6));
+    assert_eq!(m.slice(s![..2, .., ..3]), a);
+    assert_eq!(m.slice(s![..2, .., 3..]), b);
+    assert_eq!(m.slice(s![2.., .., ..3]), b);
+    assert_eq!(m.slice(s![2.., .., 3..]), a);
+    // a 2-D grid of 3-D blocks, stacked along the first two axes
+    let m = block![[a, a], [b, b]];
+    assert_eq!(m.dim(), (4, 2, 3));
+}
+
+#[test]
+#[should_panic]
+fn block_misaligned_columns() {
+    let a = arr2(&[[1, 2], [3, 4]]);
+    let b = arr2(&[[5], [6]]);
+    block![[a, b], [b, a]];
+}
+
+#[test]
+#[should_panic]
+fn block_ragged_grid() {
+    let a = arr2(&[[1, 2], [3, 4]]);
+    let b = arr2(&[[5], [6]]);
+    block![[a, b], [a]];
+}
+
+#[test]
+#[should_panic]
+fn block_mixed_depth() {
+    let a = arr2(&[[1, 2], [3, 4]]);
+    block![[a], [[a]]];
+}
+
+#[test]
+#[should_panic]
+fn block_too_deep() {
+    let a = arr1(&[1, 2]);
+    block![[a, a], [a, a]];
+}
+
+#[test]
+#[should_panic]
+fn block_inconsistent_rows() {
+    let a = arr2(&[[1, 2], [3, 4]]);
+    let c = arr2(&[[7, 8, 9]]);
+    block![[a, c]];
+}
@@ -0,0 +1,105 @@
+#![cfg(feature = "approx")]
+
+use ndarray::linalg::SVDMode;
+use ndarray::prelude::*;
+use num_complex::Complex;
+
+use approx::assert_abs_diff_eq;
+
+mod common;
+use common::test_matrix;
+
+fn check_svd(a: &Array2<f64>) {
+    let (m, n) = a.dim();
+    let k = m.min(n);
+    let (u, s, vt) = a.svd(SVDMode::Thin).unwrap();
+    assert_eq!(u.dim(), (m, k));
+    assert_eq!(s.len(), k);
+    assert_eq!(vt.dim(), (k, n));
+    for i in 1..k {
+        assert!(s[i - 1] >= s[i]);
+    }
+    assert!(s.iter().all(|&x| x >= 0.));
+    assert_abs_diff_eq!(u.t().dot(&u), Array2::eye(k), epsilon = 1e-12);
+    assert_abs_diff_eq!(vt.dot(&vt.t()), Array2::eye(k), epsilon = 1e-12);
+    assert_abs_diff_eq!((&u * &s).dot(&vt), a, epsilon = 1e-12);
+
+    let (uf, sf, vtf) = a.svd(SVDMode::Full).unwrap();
+    assert_eq!(uf.dim(), (m, m));
+    assert_eq!(vtf.dim(), (n, n));
+    assert_abs_diff_eq!(sf, s, epsilon = 1e-12);
+    assert_abs_diff_eq!(uf.t().dot(&uf), Array2::eye(m), epsilon = 1e-12);
+    assert_abs_diff_eq!(vtf.dot(&vtf.t()), Array2::eye(n), epsilon = 1e-12);
+    let mut sigma = Array2::zeros((m, n));
+    sigma.diag_mut().assign(&sf);
+    assert_abs_diff_eq!(uf.dot(&sigma).dot(&vtf), a, epsilon = 1e-12);
+}
+
+#[test]
+fn svd_shapes() {
+    for &(m, n) in &[(1, 1), (4, 4), (7, 3), (3, 7), (30, 12), (5, 0), (0, 5)] {
+        check_svd(&test_matrix(m, n));
+    }
+}
+
+#[test]
+fn svd_rank_deficient() {
+    let b = test_matrix(8, 2);
+    let a = b.dot(&test_matrix(2, 6));
+    check_svd(&a);
+    check_svd(&a.t().to_owned());
+    check_svd(&Array2::zeros((3, 4)));
+    assert_eq!(a.matrix_rank().unwrap(), 2);
+    assert_eq!(Array2::<f64>::zeros((3, 3)).matrix_rank().unwrap(), 0);
+    assert_eq!(test_matrix(5, 9).matrix_rank().unwrap(), 5);
+}
+
+#[test]
+fn svd_strided_view() {
+    let a = test_matrix(10, 12);
+    let v = a.slice(s![..;2, 1..;3]);
+    let (u, s, vt) = v.svd(SVDMode::Thin).unwrap();
+    assert_abs_diff_eq!((&u * &s).dot(&vt), v, epsilon = 1e-12);
+}
+
+#[test]
+fn svd_complex() {
+    let a = Array::from_shape_fn((6, 4), |(i, j)| {
+        Complex::new((i + 2 * j) as f64, (i * j % 3) as f64 - 1.)
+    });
+    let (u, s, vt) = a.svd(SVDMode::Thin).unwrap();
+    let us = &u * &s.mapv(|x| Complex::new(x, 0.));
+    let err = us.dot(&vt) - &a;
+    assert!(err.iter().all(|x| x.norm() < 1e-12));
+    let uh = u.t().mapv(|x| x.conj());
+    let err = uh.dot(&u) - Array2::<Complex<f64>>::eye(4);
+    assert!(err.iter().all(|x| x.norm() < 1e-12));
+}
+
+#[test]
+fn pinv() {
+    for &(m, n) in &[(5, 5), (7, 3), (3, 7)] {
+        let a = test_matrix(m, n);
+        let p = a.pinv().unwrap();
+        assert_eq!(p.dim(), (n, m));
+        assert_abs_diff_eq!(a.dot(&p).dot(&a), a, epsilon = 1e-10);
+        assert_abs_diff_eq!(p.dot(&a).dot(&p), p, epsilon = 1e-10);
+    }
+    let a = arr2(&[[2., 0.], [0., 4.], [0., 0.]]);
+    assert_abs_diff_eq!(
+        a.pinv().unwrap(),
+        arr2(&[[0.5, 0., 0.], [0., 0.25, 0.]]),
+        epsilon = 1e-15
+    );
+}
+
+#[test]
+fn cond_and_norm_2() {
+    let a = arr2(&[[3., 0.], [0., -0.5]]);
+    assert_abs_diff_eq!(a.norm_2().unwrap(), 3., epsilon = 1e-15);
+    assert_abs_diff_eq!(a.cond().unwrap(), 6., epsilon = 1e-14);
+    let singular = arr2(&[[1., 2.], [2., 4.]]);
+    assert!(singular.cond().unwrap() > 1e15);
+    assert_abs_diff_eq!(singular.norm_2().unwrap(), 5., epsilon = 1e-14);
+    assert_eq!(Array2::<f64>::eye(3).cond().unwrap(), 1.);
+}
@@ -117,3 +117,110 @@ fn test_window_zip() {
         }
     }
 }
+
+#[test]
+fn windows_with_stride() {
+    let a = Array::from_iter(0..30).into_shape((5, 6)).unwrap();
+    itertools::assert_equal(
+        a.windows_with_stride((2, 3), (2, 2)),
+        vec![
+            arr2(&[[0, 1, 2], [6, 7, 8]]),
+            arr2(&[[2, 3, 4], [8, 9, 10]]),
+            arr2(&[[12, 13, 14], [18, 19, 20]]),
+            arr2(&[[14, 15, 16], [20, 21, 22]]),
+        ],
+    );
+    // a stride of one is the same as `.windows()`
+    itertools::assert_equal(a.windows_with_stride((2, 2), (1, 1)), a.windows((2, 2)));
+    // strides longer than the windows skip elements
+    let b = Array::from_iter(0..10);
+    itertools::assert_equal(
+        b.windows_with_stride(2, 4),
+        vec![arr1(&[0, 1]), arr1(&[4, 5]), arr1(&[8, 9])],
+    );
+    assert_eq!(b.windows_with_stride(11, 1).into_iter().count(), 0);
+}
+
+#[test]
+fn windows_with_stride_dilation() {
+    let a = Array::from_iter(0..64).into_shape((4, 4, 4)).unwrap();
+    for &stride in &[(1, 1, 1), (2, 1, 3), (1, 3, 2)] {
+        for &dilation in &[(1, 1, 1), (2, 1, 1), (1, 3, 2), (3, 3, 3)] {
+            let (si, sj, sk) = stride;
+            let (di, dj, dk) = dilation;
+            Zip::indexed(a.windows_with_stride_dilation((2, 2, 1), stride, dilation)).apply(
+                |(i, j, k), window| {
+                    let (i, j, k) = ((i * si) as isize, (j * sj) as isize, (k * sk) as isize);
+                    let (di, dj, dk) = (di as isize, dj as isize, dk as isize);
+                    let expected = a.slice(s![
+                        i..i + di + 1;di,
+                        j..j + dj + 1;dj,
+                        k..k + 1;dk
+                    ]);
+                    assert_eq!(window, expected);
+                },
+            );
+        }
+    }
+    assert_eq!(
+        a.windows_with_stride_dilation((2, 2, 2), (1, 1, 1), (3, 3, 3))
+            .into_iter()
+            .count(),
+        1
+    );
+    assert_eq!(
+        a.windows_with_stride_dilation((2, 2, 2), (1, 1, 1), (4, 1, 1))
+            .into_iter()
+            .count(),
+        0
+    );
+
+    // reversed axes
+    let b = Array::from_iter(0..8);
+    let r = b.slice(s![..;-1]);
+    itertools::assert_equal(
+        r.windows_with_stride_dilation(2, 3, 2),
+        vec![arr1(&[7, 5]), arr1(&[4, 2])],
+    );
+}
+
+#[test]
+#[should_panic]
+fn windows_zero_stride() {
+    Array::<f64, _>::zeros(4).windows_with_stride(2, 0);
+}
+
+#[test]
+#[should_panic]
+fn windows_zero_dilation() {
+    Array::<f64, _>::zeros(4).windows_with_stride_dilation(2, 1, 0);
+}
+
+#[test]
+fn axis_windows() {
+    let a = Array::from_iter(0..24).into_shape((2, 4, 3)).unwrap();
+    let w = a.axis_windows(Axis(1), 2);
+    assert_eq!(w.clone().into_iter().count(), 3);
+    for (i, window) in w.into_iter().enumerate() {
+        assert_eq!(window, a.slice(s![.., i..i + 2, ..]));
+    }
+    let mut sums = Array::zeros(2);
+    Zip::indexed(&mut sums)
+        .and(a.axis_windows(Axis(0), 1))
+        .apply(|i, s, w| {
+            assert_eq!(w, a.slice(s![i..i + 1, .., ..]));
+            *s = w.sum();
+        });
+    assert_eq!(sums, arr1(&[66, 210]));
+    assert_eq!(a.axis_windows(Axis(2), 4).into_iter().count(), 0);
+
+    // the whole axis, and a transposed array
+    let t = a.t();
+    itertools::assert_equal(t.axis_windows(Axis(2), 2), vec![t.view()]);
+}
+
+#[test]
+#[should_panic]
+fn axis_windows_zero_size() {
+    Array::<f64, _>::zeros((3, 3)).axis_windows(Axis(0), 0);
+}